@@ -25,6 +25,7 @@ pub enum MetadataAction {
     SelectPrefix(SasPrefix),
     SetFolderBaseName(String),
     SetPsuFileBaseName(String),
+    StripRedundantPrefix,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -32,6 +33,7 @@ pub enum TimestampStrategyAction {
     None,
     InheritSource,
     SasRules,
+    NewestFile,
     Manual,
 }
 
@@ -46,6 +48,8 @@ pub enum TimestampRulesAction {
         alias: String,
         selected: bool,
     },
+    SelectAllAliases(usize),
+    ClearAllAliases(usize),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -55,6 +59,7 @@ pub enum TimestampAction {
     SyncAfterSourceUpdate,
     ApplyPlannedTimestamp,
     ResetRulesToDefault,
+    ApplyRulesPreset(String),
     SetManualTimestamp(Option<NaiveDateTime>),
     Rules(TimestampRulesAction),
 }
@@ -71,6 +76,8 @@ pub enum FileListAction {
     ManualAdd(FileListKind),
     RemoveSelected(FileListKind),
     SelectEntry(FileListKind, Option<usize>),
+    Undo,
+    Redo,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -88,9 +95,20 @@ pub enum IconSysAction {
 pub enum Action {
     OpenProject,
     SelectProjectFolder,
+    ReloadProject,
+    /// Reload the project files after the user agreed to discard unsaved editor changes.
+    ConfirmReloadProject,
     PackPsu,
+    /// Run the pre-pack checks and report the result without packing.
+    ValidateProject,
     UpdatePsu,
     ExportPsuToFolder,
+    ExportEntryListCsv,
+    /// Open the folder holding the packed PSU in the system file manager.
+    RevealOutput,
+    RenameLoadedPsuRoot,
+    /// Rewrite the loaded PSU with the renamed root after the user agreed to overwrite it.
+    ConfirmRenameLoadedPsuRoot,
     ChooseOutputDestination,
     AddFiles,
     SaveFile,
@@ -1,14 +1,14 @@
 use std::path::PathBuf;
 
-use crate::actions::{FileListKind, MetadataTarget};
-use crate::state::{MissingRequiredFile, VirtualFile};
+use crate::actions::{Action, FileListKind, MetadataTarget};
+use crate::state::{PackerState, SasPrefix, VirtualFile};
 
 /// High-level side effects requested by the GUI state machine.
 ///
 /// Each variant corresponds to a platform specific operation that cannot be performed inside the
 /// pure state layer. Downstream handlers should match on the event, perform the requested side
 /// effect, and feed any results back into the state through the relevant APIs.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum AppEvent {
     /// Prompt the user to select the root project folder.
     OpenFolder,
@@ -76,13 +76,126 @@ pub enum AppEvent {
         template: String,
         destination: Option<PathBuf>,
     },
-    /// Ask the user to confirm packing despite missing required files.
+    /// Ask the user to confirm an operation before it runs.
     ///
-    /// The `missing_required_files` payload lists the files that were not found so the UI can show a
-    /// meaningful warning. If the user confirms, the handler should call
-    /// [`crate::state::PackerState::confirm_pending_pack_action`] to retrieve the prepared pack
-    /// inputs.
-    ShowPackConfirmation {
-        missing_required_files: Vec<MissingRequiredFile>,
+    /// * `id` - Identifies the request. The answer is reported back through
+    ///   [`crate::state::PackerState::resolve_confirmation`] using the same id.
+    /// * `message` - Explanation shown in the dialog.
+    /// * `on_confirm_action` - Action the frontend should dispatch when the user accepts.
+    ///
+    /// Packing despite missing required files or over an existing output is confirmed this way
+    /// too, through [`crate::state::PackerState::request_pack_confirmation`].
+    Confirm {
+        id: String,
+        message: String,
+        on_confirm_action: Action,
     },
+    /// A pack job finished and wrote `output_path`.
+    PackFinished { output_path: PathBuf },
+    /// A pack job stopped before writing its output.
+    ///
+    /// * `error` - Description of the failure, suitable for display.
+    PackFailed { error: String },
+    /// Use `path` as the packed PSU output, typically the answer to
+    /// [`AppEvent::ChooseOutputDestination`]. Handled by [`apply_event`].
+    SetOutput(PathBuf),
+    /// Change the folder base name the PSU name is derived from. Handled by [`apply_event`].
+    SetFolderBaseName(String),
+    /// Change the SAS prefix the PSU name is derived from. Handled by [`apply_event`].
+    SelectPrefix(SasPrefix),
+    /// Append `entry` to the include or exclude list. Handled by [`apply_event`].
+    AddFileListEntry { kind: FileListKind, entry: String },
+    /// Remove the entry at `index` from the include or exclude list. Handled by
+    /// [`apply_event`].
+    RemoveFileListEntry { kind: FileListKind, index: usize },
+}
+
+/// What [`apply_event`] did with an event.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventResult {
+    /// The event only touched the state and has been applied. `changed` is `false` when the
+    /// state already matched.
+    Applied { changed: bool },
+    /// The event needs the frontend, for example to show a dialog or touch the file system. It
+    /// is handed back unchanged.
+    PassThrough(AppEvent),
+}
+
+/// Applies the events that only update `state`, so every frontend handles them the same way.
+/// Everything else is returned as [`EventResult::PassThrough`] for the frontend to perform.
+pub fn apply_event(state: &mut PackerState, event: AppEvent) -> EventResult {
+    let changed = match event {
+        AppEvent::SetOutput(path) => {
+            let output = path.display().to_string();
+            let changed = state.output != output;
+            state.output = output;
+            changed
+        }
+        AppEvent::SetFolderBaseName(base_name) => state.set_folder_base_name(base_name),
+        AppEvent::SelectPrefix(prefix) => state.set_selected_prefix(prefix),
+        AppEvent::AddFileListEntry { kind, entry } => {
+            state.add_file_list_entry(kind, entry);
+            true
+        }
+        AppEvent::RemoveFileListEntry { kind, index } => {
+            state.remove_file_list_entry(kind, index).is_some()
+        }
+        event => return EventResult::PassThrough(event),
+    };
+    EventResult::Applied { changed }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folder_base_name_event_updates_the_state() {
+        let mut state = PackerState::default();
+
+        let result = apply_event(&mut state, AppEvent::SetFolderBaseName("Game".to_string()));
+        assert_eq!(result, EventResult::Applied { changed: true });
+        assert_eq!(state.folder_base_name, "Game");
+
+        let result = apply_event(&mut state, AppEvent::SetFolderBaseName("Game".to_string()));
+        assert_eq!(result, EventResult::Applied { changed: false });
+    }
+
+    #[test]
+    fn file_list_and_output_events_update_the_state() {
+        let mut state = PackerState::default();
+
+        apply_event(
+            &mut state,
+            AppEvent::AddFileListEntry {
+                kind: FileListKind::Include,
+                entry: "BOOT.ELF".to_string(),
+            },
+        );
+        assert_eq!(state.include_files, vec!["BOOT.ELF".to_string()]);
+        let result = apply_event(
+            &mut state,
+            AppEvent::RemoveFileListEntry {
+                kind: FileListKind::Include,
+                index: 3,
+            },
+        );
+        assert_eq!(result, EventResult::Applied { changed: false });
+
+        apply_event(&mut state, AppEvent::SetOutput(PathBuf::from("SAVE.psu")));
+        assert_eq!(state.output, "SAVE.psu");
+    }
+
+    #[test]
+    fn output_destination_event_is_passed_through() {
+        let mut state = PackerState::default();
+        let event = AppEvent::ChooseOutputDestination {
+            default_directory: Some(PathBuf::from("/saves")),
+            default_file_name: Some("APP_Game.psu".to_string()),
+        };
+
+        let result = apply_event(&mut state, event.clone());
+        assert_eq!(result, EventResult::PassThrough(event));
+        assert!(state.output.is_empty());
+    }
 }
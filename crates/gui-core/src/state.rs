@@ -1,7 +1,8 @@
 use std::{
     collections::HashSet,
     ffi::OsStr,
-    fs, io,
+    fs,
+    io::{self, Read, Write},
     ops::Index,
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
@@ -15,18 +16,89 @@ use crate::actions::{
 use crate::commands::AppEvent;
 use crate::validation::{sanitize_seconds_between_items, timestamp_rules_equal};
 use psu_packer::sas::{
-    canonical_aliases_for_category, planned_timestamp_for_folder, planned_timestamp_for_name,
-    CategoryRule, TimestampRules,
+    canonical_aliases_for_category, find_timestamp_rules_preset, planned_timestamp_for_folder,
+    planned_timestamp_for_name, CategoryRule, TimestampRules,
 };
 use tempfile::{tempdir, TempDir};
 
-use chrono::NaiveDateTime;
-use ps2_filetypes::{templates, PSUEntryKind, PSU};
+use chrono::{Local, NaiveDateTime};
+use ps2_filetypes::templates;
 
 pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 pub const TIMESTAMP_RULES_FILE: &str = "timestamp_rules.json";
 pub const REQUIRED_PROJECT_FILES: &[&str] =
     &["list.icn", "copy.icn", "del.icn", "title.cfg", "icon.sys"];
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Whether `path` names a PSU archive, either plain (`.psu`) or gzip-compressed (`.psu.gz`).
+pub fn is_psu_path(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(OsStr::to_str) else {
+        return false;
+    };
+    let name = name.to_ascii_lowercase();
+    name.ends_with(".psu") || name.ends_with(".psu.gz")
+}
+
+/// Whether `path` names a gzip-compressed PSU archive (`.psu.gz`).
+pub fn is_gzip_psu_path(path: &Path) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .is_some_and(|name| name.to_ascii_lowercase().ends_with(".psu.gz"))
+}
+
+/// The base name of a PSU file: `SAVE` for both `SAVE.psu` and `SAVE.psu.gz`.
+pub fn psu_file_stem(path: &Path) -> Option<&str> {
+    let stem = path.file_stem()?.to_str()?;
+    if !is_gzip_psu_path(path) {
+        return Some(stem);
+    }
+    Some(&stem[..stem.len() - ".psu".len()])
+}
+
+/// Names of the folders directly inside `parent`, sorted.
+fn sibling_folder_names(parent: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(parent) else {
+        return Vec::new();
+    };
+    let mut names = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Reads the PSU at `path`, decompressing it first when it starts with the gzip magic bytes.
+pub fn read_psu_bytes(path: &Path) -> Result<Vec<u8>, StateError> {
+    let data = fs::read(path)
+        .map_err(|err| StateError::Io(format!("Failed to read {}: {err}", path.display())))?;
+    if !data.starts_with(&GZIP_MAGIC) {
+        return Ok(data);
+    }
+
+    let mut decompressed = Vec::new();
+    flate2::read::GzDecoder::new(data.as_slice())
+        .read_to_end(&mut decompressed)
+        .map_err(|err| {
+            StateError::Parse(format!("Failed to decompress {}: {err}", path.display()))
+        })?;
+    Ok(decompressed)
+}
+
+/// Writes PSU `bytes` to `path`, gzip-compressing them when `path` names a `.psu.gz` archive.
+pub fn write_psu_bytes(path: &Path, bytes: &[u8]) -> Result<(), StateError> {
+    let write_error =
+        |err: io::Error| StateError::Io(format!("Failed to write {}: {err}", path.display()));
+    if !is_gzip_psu_path(path) {
+        return fs::write(path, bytes).map_err(write_error);
+    }
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(bytes).map_err(write_error)?;
+    let compressed = encoder.finish().map_err(write_error)?;
+    fs::write(path, compressed).map_err(write_error)
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MissingFileReason {
@@ -80,6 +152,60 @@ pub struct ProjectRequirementStatus {
     pub satisfied: bool,
 }
 
+/// Whether a pack, update, or export can start, as reported by [`PackerState::pack_readiness`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackReadiness {
+    /// A project folder is selected or a PSU is loaded.
+    pub source_selected: bool,
+    pub name_set: bool,
+    /// An output path is set. The actions ask for one when it is not, so this never blocks them.
+    pub output_set: bool,
+    /// Required project files that are not in the folder yet.
+    pub missing_required_files: Vec<MissingRequiredFile>,
+    pub pack_running: bool,
+}
+
+impl PackReadiness {
+    pub fn required_files_present(&self) -> bool {
+        self.missing_required_files.is_empty()
+    }
+
+    /// Whether the pack actions should be enabled: no job is running and there is a source,
+    /// a name, and every required file.
+    pub fn can_start(&self) -> bool {
+        !self.pack_running && self.source_selected && self.name_set && self.required_files_present()
+    }
+
+    /// Explains why an action described by `verb` (e.g. "packing") cannot start yet.
+    pub fn blocking_reason(&self, verb: &str) -> Option<String> {
+        if self.pack_running {
+            return Some("Packing in progress…".to_string());
+        }
+        if !self.source_selected {
+            return Some(format!(
+                "Select a project folder or open a PSU before {verb}."
+            ));
+        }
+        if !self.name_set {
+            return Some(format!("Enter a save name before {verb}."));
+        }
+        if !self.required_files_present() {
+            let names = self
+                .missing_required_files
+                .iter()
+                .map(|file| file.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Some(format!(
+                "Add the missing project assets before {verb}: {names}."
+            ));
+        }
+        None
+    }
+}
+
+/// A save name prefix from [`psu_packer::SAVE_NAME_PREFIXES`]. The variants after `None`
+/// follow the order of that list.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SasPrefix {
     None,
@@ -98,7 +224,7 @@ pub enum SasPrefix {
     Zzz,
 }
 
-const SAS_PREFIXES: [SasPrefix; 13] = [
+const SAS_PREFIXES: [SasPrefix; psu_packer::SAVE_NAME_PREFIXES.len()] = [
     SasPrefix::App,
     SasPrefix::Apps,
     SasPrefix::Ps1,
@@ -124,19 +250,7 @@ impl SasPrefix {
     pub const fn as_str(self) -> &'static str {
         match self {
             SasPrefix::None => "",
-            SasPrefix::App => "APP_",
-            SasPrefix::Apps => "APPS",
-            SasPrefix::Ps1 => "PS1_",
-            SasPrefix::Emu => "EMU_",
-            SasPrefix::Gme => "GME_",
-            SasPrefix::Dst => "DST_",
-            SasPrefix::Dbg => "DBG_",
-            SasPrefix::Raa => "RAA_",
-            SasPrefix::Rte => "RTE_",
-            SasPrefix::Default => "DEFAULT",
-            SasPrefix::Sys => "SYS_",
-            SasPrefix::Zzy => "ZZY_",
-            SasPrefix::Zzz => "ZZZ_",
+            prefix => psu_packer::SAVE_NAME_PREFIXES[prefix as usize - 1],
         }
     }
 
@@ -157,18 +271,10 @@ impl SasPrefix {
     }
 
     pub fn split_from_name(name: &str) -> (SasPrefix, &str) {
-        for prefix in SAS_PREFIXES {
-            let remainder = match prefix {
-                SasPrefix::Default => name
-                    .strip_prefix("DEFAULT_")
-                    .or_else(|| name.strip_prefix(prefix.as_str())),
-                _ => name.strip_prefix(prefix.as_str()),
-            };
-            if let Some(remainder) = remainder {
-                return (prefix, remainder);
-            }
+        match psu_packer::split_save_name_prefix(name) {
+            Some((index, remainder)) => (SAS_PREFIXES[index], remainder),
+            None => (SasPrefix::None, name),
         }
-        (SasPrefix::None, name)
     }
 }
 
@@ -177,6 +283,8 @@ pub enum TimestampStrategy {
     None,
     InheritSource,
     SasRules,
+    /// Newest modification time among the files that will be packed.
+    NewestFile,
     Manual,
 }
 
@@ -209,10 +317,8 @@ impl TimestampRulesUiState {
             .categories
             .iter()
             .map(|category| {
-                let available_aliases = canonical_aliases_for_category(category.key.as_str())
-                    .iter()
-                    .map(|alias| (*alias).to_string())
-                    .collect::<Vec<_>>();
+                let available_aliases =
+                    canonical_aliases_for_category(category.key.as_str()).to_vec();
                 let selected_aliases = category
                     .aliases
                     .iter()
@@ -303,6 +409,18 @@ impl TimestampRulesUiState {
             .unwrap_or(false)
     }
 
+    pub fn select_all_aliases(&mut self, index: usize) -> bool {
+        self.category_mut(index)
+            .map(CategoryUiState::select_all_aliases)
+            .unwrap_or(false)
+    }
+
+    pub fn clear_all_aliases(&mut self, index: usize) -> bool {
+        self.category_mut(index)
+            .map(CategoryUiState::clear_all_aliases)
+            .unwrap_or(false)
+    }
+
     pub fn alias_warning(&self, index: usize) -> Option<String> {
         self.category(index).and_then(|category| category.warning())
     }
@@ -388,6 +506,21 @@ impl CategoryUiState {
         false
     }
 
+    /// Selects every available alias. Returns `true` if the selection changed.
+    pub fn select_all_aliases(&mut self) -> bool {
+        let before = self.selected_aliases.len();
+        self.selected_aliases
+            .extend(self.available_aliases.iter().cloned());
+        self.selected_aliases.len() != before
+    }
+
+    /// Deselects every alias. Returns `true` if the selection changed.
+    pub fn clear_all_aliases(&mut self) -> bool {
+        let changed = !self.selected_aliases.is_empty();
+        self.selected_aliases.clear();
+        changed
+    }
+
     fn sorted_aliases(&self) -> Vec<String> {
         self.available_aliases
             .iter()
@@ -408,6 +541,62 @@ impl CategoryUiState {
     }
 }
 
+/// Why a [`PackerState`] operation failed. The message is the one shown to the user; the
+/// variant lets callers tell recoverable file system errors from bad input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateError {
+    /// Reading or writing a file failed, or a file that should exist does not.
+    Io(String),
+    /// A file could not be parsed or serialized.
+    Parse(String),
+    /// The current settings do not allow the operation.
+    Validation(String),
+    /// No project folder or PSU is loaded, or no output is set, to act on.
+    MissingSource(String),
+}
+
+impl StateError {
+    pub fn message(&self) -> &str {
+        match self {
+            StateError::Io(message)
+            | StateError::Parse(message)
+            | StateError::Validation(message)
+            | StateError::MissingSource(message) => message,
+        }
+    }
+
+    /// Prefixes the message with `context`, keeping the category.
+    fn context(self, context: &str) -> Self {
+        let wrap = |message: String| format!("{context}: {message}");
+        match self {
+            StateError::Io(message) => StateError::Io(wrap(message)),
+            StateError::Parse(message) => StateError::Parse(wrap(message)),
+            StateError::Validation(message) => StateError::Validation(wrap(message)),
+            StateError::MissingSource(message) => StateError::MissingSource(wrap(message)),
+        }
+    }
+}
+
+impl std::fmt::Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for StateError {}
+
+impl From<StateError> for String {
+    fn from(err: StateError) -> Self {
+        err.to_string()
+    }
+}
+
+impl From<StateError> for PackErrorMessage {
+    fn from(err: StateError) -> Self {
+        err.to_string().into()
+    }
+}
+
 #[derive(Debug)]
 pub struct PackErrorMessage {
     message: String,
@@ -450,7 +639,8 @@ pub struct PackJob {
 }
 
 pub enum PackProgress {
-    InProgress,
+    /// Fraction of files packed so far.
+    InProgress(f32),
     Finished(PackOutcome),
 }
 
@@ -463,6 +653,10 @@ pub struct PackPreparation {
 pub enum PackOutcome {
     Success {
         output_path: PathBuf,
+        /// Result of writing psu.toml back to the project folder, when that was requested.
+        saved_config: Option<Result<PathBuf, String>>,
+        /// Result of marking the output read-only, when that was requested.
+        read_only: Option<Result<(), String>>,
     },
     Error {
         folder: PathBuf,
@@ -477,6 +671,8 @@ pub enum PendingPackAction {
         output_path: PathBuf,
         config: psu_packer::Config,
         missing_required_files: Vec<MissingRequiredFile>,
+        /// `output_path` already exists and packing will replace it.
+        overwrites_output: bool,
     },
 }
 
@@ -489,8 +685,101 @@ impl PendingPackAction {
             } => missing_required_files,
         }
     }
+
+    /// The existing file packing would replace, if any.
+    pub fn overwritten_output(&self) -> Option<&Path> {
+        match self {
+            PendingPackAction::Pack {
+                output_path,
+                overwrites_output: true,
+                ..
+            } => Some(output_path),
+            PendingPackAction::Pack { .. } => None,
+        }
+    }
+
+    /// The text shown when asking the user to confirm this pack.
+    pub fn confirmation_message(&self) -> String {
+        let mut lines = Vec::new();
+        let missing = self.missing_files();
+        if !missing.is_empty() {
+            lines.push(crate::validation::format_missing_required_files_message(
+                missing,
+            ));
+        }
+        if let Some(path) = self.overwritten_output() {
+            lines.push(format!(
+                "{} already exists and will be overwritten.",
+                path.display()
+            ));
+        }
+        lines.join("\n\n")
+    }
+}
+
+/// A confirmation prompt awaiting the user's answer. See [`AppEvent::Confirm`].
+#[derive(Clone, Debug)]
+pub struct PendingConfirmation {
+    pub id: String,
+    pub message: String,
+    pub on_confirm_action: Action,
+    /// Action to dispatch when the user declines, if declining needs to undo anything.
+    pub on_cancel_action: Option<Action>,
+}
+
+/// Id of the confirmation [`PackerState::request_pack_confirmation`] asks for.
+pub const PACK_CONFIRMATION_ID: &str = "pack";
+
+/// Whether `entry` passes a file list `filter`: a case-insensitive substring match, where an
+/// empty or blank filter matches everything.
+pub fn file_list_filter_matches(entry: &str, filter: &str) -> bool {
+    let filter = filter.trim();
+    filter.is_empty() || entry.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// Number of include/exclude list edits that can be undone.
+pub const FILE_LIST_HISTORY_LIMIT: usize = 50;
+
+/// The include and exclude lists as they stood before an edit.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct FileListSnapshot {
+    include_files: Vec<String>,
+    exclude_files: Vec<String>,
+}
+
+/// Bounded undo/redo history for the include and exclude lists.
+#[derive(Clone, Debug, Default)]
+pub struct FileListHistory {
+    undo: Vec<FileListSnapshot>,
+    redo: Vec<FileListSnapshot>,
+}
+
+impl FileListHistory {
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn record(&mut self, snapshot: FileListSnapshot) {
+        if self.undo.len() == FILE_LIST_HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+        self.undo.push(snapshot);
+        self.redo.clear();
+    }
 }
 
+/// Two sibling project folders the timestamp rules schedule at the same time, with that time.
+pub type TimestampCollision = (String, String, NaiveDateTime);
+
 pub struct PackerState {
     pub folder: Option<PathBuf>,
     pub output: String,
@@ -501,6 +790,9 @@ pub struct PackerState {
     pub psu_file_base_name: String,
     pub timestamp: Option<NaiveDateTime>,
     pub timestamp_strategy: TimestampStrategy,
+    /// psu.toml asked for no timestamp, so [`TimestampStrategy::None`] is kept instead of being
+    /// replaced by a suggested strategy.
+    pub no_timestamp_requested: bool,
     pub timestamp_from_rules: bool,
     pub source_timestamp: Option<NaiveDateTime>,
     pub manual_timestamp: Option<NaiveDateTime>,
@@ -508,6 +800,8 @@ pub struct PackerState {
     pub timestamp_rules_loaded_from_file: bool,
     pub timestamp_rules_modified: bool,
     pub timestamp_rules_error: Option<String>,
+    /// Adjustments made while sanitizing the loaded timestamp rules.
+    pub timestamp_rules_warnings: Vec<String>,
     pub timestamp_rules_ui: TimestampRulesUiState,
     pub include_files: Vec<String>,
     pub exclude_files: Vec<String>,
@@ -515,14 +809,41 @@ pub struct PackerState {
     pub exclude_manual_entry: String,
     pub selected_include: Option<usize>,
     pub selected_exclude: Option<usize>,
+    /// Case-insensitive substring filters that hide non-matching list entries in the UI.
+    pub include_filter: String,
+    pub exclude_filter: String,
+    pub file_list_history: FileListHistory,
     pub missing_required_project_files: Vec<MissingRequiredFile>,
     pub pending_pack_action: Option<PendingPackAction>,
+    pub pending_confirmation: Option<PendingConfirmation>,
+    /// Write the packed configuration to psu.toml in the project folder after a successful pack.
+    pub write_config_on_pack: bool,
+    /// Mark the output file read-only after a successful pack so a stray repack cannot
+    /// overwrite it. Updating a PSU makes it writable again first.
+    pub make_output_read_only: bool,
+    /// Pack timestamp_rules.json into the PSU when its timestamp comes from the SAS rules, so
+    /// the recipient can see how it was scheduled. Otherwise the rules file is left out.
+    pub include_timestamp_rules_in_psu: bool,
+    /// Additional folders whose files are packed alongside the project folder.
+    pub merge_folders: Vec<PathBuf>,
+    pub merge_conflict_policy: psu_packer::MergeConflictPolicy,
     pub loaded_psu_path: Option<PathBuf>,
     pub loaded_psu_files: Vec<String>,
+    pub loaded_psu_root_name: Option<String>,
+    pub config_warnings: Vec<psu_packer::ConfigWarning>,
     pub source_present_last_frame: bool,
     pub pack_job: Option<PackJob>,
     pub temp_workspace: Option<TempDir>,
+    /// Debug setting: keep the extracted workspace of a loaded PSU when updating it fails.
+    pub keep_temp_workspace_on_failure: bool,
+    /// Location of the workspace kept after the last failed pack, if any.
+    pub retained_temp_workspace: Option<PathBuf>,
     pub events: Vec<AppEvent>,
+    /// Folder names beside the project folder, with the parent they were read from, scanned
+    /// once per parent for [`PackerState::timestamp_collisions`].
+    pub sibling_folder_names: Option<(PathBuf, Vec<String>)>,
+    /// The timestamp rules the cached collisions were computed with, and those collisions.
+    pub timestamp_collision_cache: Option<(TimestampRules, Vec<TimestampCollision>)>,
 }
 
 impl Default for PackerState {
@@ -539,6 +860,7 @@ impl Default for PackerState {
             psu_file_base_name: String::new(),
             timestamp: None,
             timestamp_strategy: TimestampStrategy::default(),
+            no_timestamp_requested: false,
             timestamp_from_rules: false,
             source_timestamp: None,
             manual_timestamp: None,
@@ -546,6 +868,7 @@ impl Default for PackerState {
             timestamp_rules_loaded_from_file: false,
             timestamp_rules_modified: false,
             timestamp_rules_error: None,
+            timestamp_rules_warnings: Vec::new(),
             timestamp_rules_ui,
             include_files: Vec::new(),
             exclude_files: Vec::new(),
@@ -553,14 +876,29 @@ impl Default for PackerState {
             exclude_manual_entry: String::new(),
             selected_include: None,
             selected_exclude: None,
+            include_filter: String::new(),
+            exclude_filter: String::new(),
+            file_list_history: FileListHistory::default(),
             missing_required_project_files: Vec::new(),
             pending_pack_action: None,
+            pending_confirmation: None,
+            write_config_on_pack: false,
+            make_output_read_only: false,
+            include_timestamp_rules_in_psu: false,
+            merge_folders: Vec::new(),
+            merge_conflict_policy: psu_packer::MergeConflictPolicy::default(),
             loaded_psu_path: None,
             loaded_psu_files: Vec::new(),
+            loaded_psu_root_name: None,
+            config_warnings: Vec::new(),
             source_present_last_frame: false,
             pack_job: None,
             temp_workspace: None,
+            keep_temp_workspace_on_failure: false,
+            retained_temp_workspace: None,
             events: Vec::new(),
+            sibling_folder_names: None,
+            timestamp_collision_cache: None,
         }
     }
 }
@@ -625,6 +963,16 @@ impl PackerState {
         }
     }
 
+    pub fn pack_readiness(&self) -> PackReadiness {
+        PackReadiness {
+            source_selected: self.folder.is_some() || self.loaded_psu_path.is_some(),
+            name_set: !self.folder_base_name.trim().is_empty(),
+            output_set: !self.output.trim().is_empty(),
+            missing_required_files: self.missing_required_project_files.clone(),
+            pack_running: self.is_pack_running(),
+        }
+    }
+
     pub fn project_requirement_statuses(&self) -> Option<Vec<ProjectRequirementStatus>> {
         self.folder.as_ref()?;
 
@@ -697,13 +1045,17 @@ impl PackerState {
         }
     }
 
+    /// Asks the user to confirm the pending pack action, naming the missing required files
+    /// and the output it would overwrite. Accepting dispatches [`Action::ConfirmPack`] and
+    /// declining [`Action::CancelPack`].
     pub fn request_pack_confirmation(&mut self) {
-        if let Some(missing_required_files) = self
-            .pending_pack_missing_files()
-            .map(|files| files.to_vec())
-        {
-            self.events.push(AppEvent::ShowPackConfirmation {
-                missing_required_files,
+        if let Some(action) = &self.pending_pack_action {
+            let message = format!("{}\n\nPack anyway?", action.confirmation_message());
+            self.push_confirmation(PendingConfirmation {
+                id: PACK_CONFIRMATION_ID.to_string(),
+                message,
+                on_confirm_action: Action::ConfirmPack,
+                on_cancel_action: Some(Action::CancelPack),
             });
         }
     }
@@ -711,6 +1063,7 @@ impl PackerState {
     pub fn confirm_pending_pack_action(
         &mut self,
     ) -> Option<(PathBuf, PathBuf, psu_packer::Config)> {
+        self.close_confirmation(PACK_CONFIRMATION_ID);
         let action = self.pending_pack_action.take()?;
         match action {
             PendingPackAction::Pack {
@@ -723,15 +1076,73 @@ impl PackerState {
     }
 
     pub fn cancel_pending_pack_action(&mut self) {
+        self.close_confirmation(PACK_CONFIRMATION_ID);
         self.pending_pack_action = None;
     }
 
+    /// Asks the frontend to confirm `on_confirm_action` before it runs. A confirmation that is
+    /// still open is replaced.
+    pub fn request_confirmation(
+        &mut self,
+        id: impl Into<String>,
+        message: impl Into<String>,
+        on_confirm_action: Action,
+    ) {
+        self.push_confirmation(PendingConfirmation {
+            id: id.into(),
+            message: message.into(),
+            on_confirm_action,
+            on_cancel_action: None,
+        });
+    }
+
+    fn push_confirmation(&mut self, confirmation: PendingConfirmation) {
+        self.events.push(AppEvent::Confirm {
+            id: confirmation.id.clone(),
+            message: confirmation.message.clone(),
+            on_confirm_action: confirmation.on_confirm_action.clone(),
+        });
+        self.pending_confirmation = Some(confirmation);
+    }
+
+    /// Closes the confirmation `id` without an answer, if it is still open.
+    fn close_confirmation(&mut self, id: &str) {
+        if self
+            .pending_confirmation
+            .as_ref()
+            .is_some_and(|pending| pending.id == id)
+        {
+            self.pending_confirmation = None;
+        }
+    }
+
+    /// Records the user's answer to the confirmation `id` and closes it.
+    ///
+    /// Returns the action to dispatch: the confirmed action when the user accepted, otherwise
+    /// the cancel action, if any. Answers for an id that is no longer pending are ignored.
+    pub fn resolve_confirmation(&mut self, id: &str, accepted: bool) -> Option<Action> {
+        if self
+            .pending_confirmation
+            .as_ref()
+            .is_none_or(|pending| pending.id != id)
+        {
+            return None;
+        }
+        let pending = self.pending_confirmation.take()?;
+        if accepted {
+            Some(pending.on_confirm_action)
+        } else {
+            pending.on_cancel_action
+        }
+    }
+
     pub fn load_timestamp_rules_from_folder(&mut self, folder: &Path) {
         let path = Self::timestamp_rules_path_from(folder);
+        self.timestamp_rules_warnings.clear();
         match fs::read_to_string(&path) {
             Ok(content) => match serde_json::from_str::<TimestampRules>(&content) {
                 Ok(mut rules) => {
-                    rules.sanitize();
+                    self.timestamp_rules_warnings = rules.sanitize();
                     self.timestamp_rules = rules;
                     self.timestamp_rules_error = None;
                     self.timestamp_rules_loaded_from_file = true;
@@ -763,21 +1174,22 @@ impl PackerState {
         self.timestamp_rules_modified = false;
     }
 
-    pub fn save_timestamp_rules(&mut self) -> Result<PathBuf, String> {
+    pub fn save_timestamp_rules(&mut self) -> Result<PathBuf, StateError> {
         let Some(folder) = self.folder.as_ref() else {
-            return Err("Select a folder before saving timestamp rules.".to_string());
+            return Err(StateError::MissingSource(
+                "Select a folder before saving timestamp rules.".to_string(),
+            ));
         };
 
         self.timestamp_rules_ui
             .apply_to_rules(&mut self.timestamp_rules);
-        let serialized = self
-            .timestamp_rules_ui
-            .serialize()
-            .map_err(|err| format!("Failed to serialize timestamp rules: {err}"))?;
+        let serialized = self.timestamp_rules_ui.serialize().map_err(|err| {
+            StateError::Parse(format!("Failed to serialize timestamp rules: {err}"))
+        })?;
 
         let path = Self::timestamp_rules_path_from(folder);
         fs::write(&path, serialized)
-            .map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+            .map_err(|err| StateError::Io(format!("Failed to write {}: {err}", path.display())))?;
 
         self.timestamp_rules_ui = TimestampRulesUiState::from_rules(&self.timestamp_rules);
         self.timestamp_rules_modified = false;
@@ -806,11 +1218,75 @@ impl PackerState {
         self.refresh_timestamp_from_strategy()
     }
 
+    /// Applies a strategy the user picked. Choosing [`TimestampStrategy::None`] asks for
+    /// `timestamp = false` in psu.toml; any other strategy drops that request.
+    pub fn select_timestamp_strategy(&mut self, strategy: TimestampStrategy) -> bool {
+        let no_timestamp = strategy == TimestampStrategy::None;
+        let request_changed = self.no_timestamp_requested != no_timestamp;
+        self.no_timestamp_requested = no_timestamp;
+        self.set_timestamp_strategy(strategy) || request_changed
+    }
+
+    /// Whether timestamp_rules.json is packed into the PSU; see
+    /// [`Self::include_timestamp_rules_in_psu`].
+    pub fn packs_timestamp_rules(&self) -> bool {
+        self.include_timestamp_rules_in_psu
+            && matches!(self.timestamp_strategy, TimestampStrategy::SasRules)
+    }
+
+    /// Include and exclude lists for packing the current folder. psu.toml is always excluded.
+    /// When the folder has a timestamp_rules.json, it is excluded unless
+    /// [`Self::packs_timestamp_rules`] holds, in which case it is added to an explicit include
+    /// list.
+    pub fn pack_file_lists(&self) -> (Option<Vec<String>>, Vec<String>) {
+        let mut include = (!self.include_files.is_empty()).then(|| self.include_files.clone());
+        let mut exclude = self.exclude_files.clone();
+        if !exclude.iter().any(|entry| entry == "psu.toml") {
+            exclude.push("psu.toml".to_string());
+        }
+
+        let has_rules_file = self
+            .timestamp_rules_path()
+            .is_some_and(|path| path.is_file());
+        if !has_rules_file {
+            return (include, exclude);
+        }
+
+        if self.packs_timestamp_rules() {
+            if let Some(include) = include.as_mut() {
+                if !include.iter().any(|entry| entry == TIMESTAMP_RULES_FILE) {
+                    include.push(TIMESTAMP_RULES_FILE.to_string());
+                }
+            }
+        } else if !exclude.iter().any(|entry| entry == TIMESTAMP_RULES_FILE) {
+            exclude.push(TIMESTAMP_RULES_FILE.to_string());
+        }
+
+        (include, exclude)
+    }
+
+    /// Newest modification time among the files the current folder and filters would pack.
+    pub fn newest_file_timestamp(&self) -> Option<NaiveDateTime> {
+        self.newest_file_timestamp_with(&psu_packer::FsMetadataReader)
+    }
+
+    pub fn newest_file_timestamp_with<M: psu_packer::MetadataReader>(
+        &self,
+        metadata_reader: &M,
+    ) -> Option<NaiveDateTime> {
+        let folder = self.folder.as_ref()?;
+        let (include, exclude) = self.pack_file_lists();
+        psu_packer::newest_file_timestamp(folder, include, Some(exclude), metadata_reader)
+            .ok()
+            .flatten()
+    }
+
     pub fn refresh_timestamp_from_strategy(&mut self) -> bool {
         let new_timestamp = match self.timestamp_strategy {
             TimestampStrategy::None => None,
             TimestampStrategy::InheritSource => self.source_timestamp,
             TimestampStrategy::SasRules => self.planned_timestamp_for_current_source(),
+            TimestampStrategy::NewestFile => self.newest_file_timestamp(),
             TimestampStrategy::Manual => self.manual_timestamp,
         };
 
@@ -824,7 +1300,9 @@ impl PackerState {
     pub fn sync_timestamp_after_source_update(&mut self) -> bool {
         let planned = self.planned_timestamp_for_current_source();
 
-        if matches!(self.timestamp_strategy, TimestampStrategy::None) {
+        if matches!(self.timestamp_strategy, TimestampStrategy::None)
+            && !self.no_timestamp_requested
+        {
             if self.source_timestamp.is_some() {
                 self.timestamp_strategy = TimestampStrategy::InheritSource;
             } else if planned.is_some() {
@@ -864,6 +1342,43 @@ impl PackerState {
         self.set_timestamp_strategy(TimestampStrategy::SasRules);
     }
 
+    /// Pairs of project folders next to the current one that the timestamp rules being edited
+    /// schedule at the same time, with that timestamp. The parent folder is only rescanned when
+    /// it changes, and the collisions are only recomputed when the rules do.
+    pub fn timestamp_collisions(&mut self) -> &[TimestampCollision] {
+        let Some(parent) = self.folder.as_deref().and_then(Path::parent) else {
+            return &[];
+        };
+        if self
+            .sibling_folder_names
+            .as_ref()
+            .is_none_or(|(scanned, _)| scanned != parent)
+        {
+            let names = sibling_folder_names(parent);
+            self.sibling_folder_names = Some((parent.to_path_buf(), names));
+            self.timestamp_collision_cache = None;
+        }
+
+        let rules = self.timestamp_rules_ui.to_rules();
+        let stale = self
+            .timestamp_collision_cache
+            .as_ref()
+            .is_none_or(|(cached, _)| !timestamp_rules_equal(cached, &rules));
+        if stale {
+            let names = self
+                .sibling_folder_names
+                .as_ref()
+                .map(|(_, names)| names.as_slice())
+                .unwrap_or_default();
+            let collisions = rules.find_collisions(names);
+            self.timestamp_collision_cache = Some((rules, collisions));
+        }
+        self.timestamp_collision_cache
+            .as_ref()
+            .map(|(_, collisions)| collisions.as_slice())
+            .unwrap_or_default()
+    }
+
     pub fn planned_timestamp_for_current_source(&self) -> Option<NaiveDateTime> {
         if let Some(folder) = self.folder.as_ref() {
             return planned_timestamp_for_folder(folder.as_path(), &self.timestamp_rules);
@@ -880,6 +1395,7 @@ impl PackerState {
     pub fn reset_timestamp_rules_to_default(&mut self) {
         self.timestamp_rules = TimestampRules::default();
         self.timestamp_rules_error = None;
+        self.timestamp_rules_warnings.clear();
         self.timestamp_rules_ui = TimestampRulesUiState::from_rules(&self.timestamp_rules);
         self.timestamp_rules_ui
             .apply_to_rules(&mut self.timestamp_rules);
@@ -887,6 +1403,19 @@ impl PackerState {
         self.mark_timestamp_rules_modified();
     }
 
+    pub fn apply_timestamp_rules_preset(&mut self, preset_id: &str) -> bool {
+        let Some(preset) = find_timestamp_rules_preset(preset_id) else {
+            return false;
+        };
+
+        self.timestamp_rules = preset.rules();
+        self.timestamp_rules_error = None;
+        self.timestamp_rules_warnings.clear();
+        self.timestamp_rules_ui = TimestampRulesUiState::from_rules(&self.timestamp_rules);
+        self.mark_timestamp_rules_modified();
+        true
+    }
+
     pub fn set_error_message<M>(&mut self, message: M)
     where
         M: Into<PackErrorMessage>,
@@ -918,6 +1447,7 @@ impl PackerState {
         self.psu_file_base_name.clear();
         self.timestamp = None;
         self.timestamp_strategy = TimestampStrategy::None;
+        self.no_timestamp_requested = false;
         self.timestamp_from_rules = false;
         self.source_timestamp = None;
         self.manual_timestamp = None;
@@ -927,6 +1457,9 @@ impl PackerState {
         self.exclude_manual_entry.clear();
         self.selected_include = None;
         self.selected_exclude = None;
+        self.include_filter.clear();
+        self.exclude_filter.clear();
+        self.file_list_history.clear();
     }
 
     pub fn folder_name(&self) -> String {
@@ -961,6 +1494,21 @@ impl PackerState {
             .map(|parent| parent.to_path_buf())
     }
 
+    /// Describes a loaded PSU whose on-disk file name differs from the root directory
+    /// name stored inside the archive.
+    pub fn loaded_psu_name_mismatch(&self) -> Option<String> {
+        let root_name = self.loaded_psu_root_name.as_deref()?;
+        let file_stem = psu_file_stem(self.loaded_psu_path.as_ref()?)?;
+
+        if file_stem == root_name {
+            return None;
+        }
+
+        Some(format!(
+            "The file is named {file_stem} but installs as {root_name}; the internal name decides the save folder on the memory card."
+        ))
+    }
+
     fn loaded_psu_directory(&self) -> Option<PathBuf> {
         self.loaded_psu_path
             .as_ref()
@@ -1072,6 +1620,51 @@ impl PackerState {
         true
     }
 
+    /// Detects a base name that already starts with a SAS prefix token, such as `APP_Game`
+    /// under the `APP_` prefix, which would otherwise produce `APP_APP_Game`.
+    ///
+    /// Only tokens ending in `_` are considered so names like `APPSTORE` are not flagged.
+    pub fn redundant_prefix(&self) -> Option<SasPrefix> {
+        let (prefix, token_len) = self.leading_prefix_token()?;
+        (token_len < self.folder_base_name.len()).then_some(prefix)
+    }
+
+    pub fn double_prefix_warning(&self) -> Option<String> {
+        let prefix = self.redundant_prefix()?;
+        Some(format!(
+            "The base name already starts with {}, so the folder will be named {}. Strip the prefix from the base name?",
+            prefix.label(),
+            self.folder_name()
+        ))
+    }
+
+    /// Removes the prefix token detected by [`Self::redundant_prefix`] from the base name and
+    /// selects that prefix, so the folder name keeps the category the user typed.
+    pub fn strip_redundant_prefix(&mut self) -> bool {
+        let Some((prefix, token_len)) = self.leading_prefix_token() else {
+            return false;
+        };
+        if token_len >= self.folder_base_name.len() {
+            return false;
+        }
+
+        let base_name = self.folder_base_name[token_len..].to_string();
+        let prefix_changed = self.set_selected_prefix(prefix);
+        self.set_folder_base_name(base_name) || prefix_changed
+    }
+
+    fn leading_prefix_token(&self) -> Option<(SasPrefix, usize)> {
+        if self.selected_prefix == SasPrefix::None {
+            return None;
+        }
+
+        let upper = self.folder_base_name.to_ascii_uppercase();
+        let (prefix, remainder) = SasPrefix::split_from_name(&upper);
+        let token_len = upper.len() - remainder.len();
+        (prefix != SasPrefix::None && upper[..token_len].ends_with('_'))
+            .then_some((prefix, token_len))
+    }
+
     pub fn set_psu_file_base_name<S>(&mut self, base_name: S) -> bool
     where
         S: Into<String>,
@@ -1099,6 +1692,24 @@ impl PackerState {
         true
     }
 
+    /// Returns a non-blocking warning when the resolved timestamp lies in the future
+    /// relative to the local clock.
+    pub fn future_timestamp_warning(&self) -> Option<String> {
+        self.future_timestamp_warning_at(Local::now().naive_local())
+    }
+
+    pub fn future_timestamp_warning_at(&self, now: NaiveDateTime) -> Option<String> {
+        let timestamp = self.timestamp?;
+        if timestamp <= now {
+            return None;
+        }
+
+        Some(format!(
+            "Timestamp {} is in the future. Some PS2 browsers misbehave with saves dated after the console clock.",
+            timestamp.format(TIMESTAMP_FORMAT)
+        ))
+    }
+
     pub fn ensure_manual_timestamp(&mut self, default: NaiveDateTime) -> bool {
         if self.manual_timestamp.is_some() {
             return false;
@@ -1139,52 +1750,139 @@ impl PackerState {
         }
     }
 
-    pub fn select_file_list_entry(&mut self, kind: FileListKind, selection: Option<usize>) {
-        let (files, selected, _) = self.file_list_parts_mut(kind);
-        if let Some(index) = selection {
-            if index < files.len() {
-                *selected = Some(index);
-            } else if files.is_empty() {
-                *selected = None;
-            } else {
-                *selected = Some(files.len() - 1);
+    pub fn file_list_filter(&self, kind: FileListKind) -> &str {
+        match kind {
+            FileListKind::Include => &self.include_filter,
+            FileListKind::Exclude => &self.exclude_filter,
+        }
+    }
+
+    /// Updates the filter for `kind`, dropping the selection if it no longer matches.
+    pub fn set_file_list_filter(&mut self, kind: FileListKind, filter: String) {
+        match kind {
+            FileListKind::Include => self.include_filter = filter,
+            FileListKind::Exclude => self.exclude_filter = filter,
+        }
+        if let Some(index) = self.file_list_selection(kind) {
+            if !self.file_list_entry_visible(kind, index) {
+                self.clear_file_list_selection(kind);
             }
-        } else {
-            *selected = None;
         }
     }
 
+    /// The entries of `kind` that pass its filter, paired with their index in the full list.
+    pub fn filtered_file_list(&self, kind: FileListKind) -> Vec<(usize, &str)> {
+        let filter = self.file_list_filter(kind);
+        self.file_list_entries(kind)
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| file_list_filter_matches(entry, filter))
+            .map(|(index, entry)| (index, entry.as_str()))
+            .collect()
+    }
+
+    fn file_list_entry_visible(&self, kind: FileListKind, index: usize) -> bool {
+        self.file_list_entries(kind)
+            .get(index)
+            .is_some_and(|entry| file_list_filter_matches(entry, self.file_list_filter(kind)))
+    }
+
+    /// Selects `selection` in the list of `kind`. Out-of-range indices are clamped, and an
+    /// entry hidden by the list's filter cannot be selected.
+    pub fn select_file_list_entry(&mut self, kind: FileListKind, selection: Option<usize>) {
+        let len = self.file_list_entries(kind).len();
+        let selection = selection
+            .and_then(|index| (len > 0).then(|| index.min(len - 1)))
+            .filter(|index| self.file_list_entry_visible(kind, *index));
+
+        let (_, selected, _) = self.file_list_parts_mut(kind);
+        *selected = selection;
+    }
+
     pub fn set_file_list_entries(&mut self, kind: FileListKind, entries: Vec<String>) {
         let (files, selected, _) = self.file_list_parts_mut(kind);
         *files = entries;
         *selected = None;
+        self.file_list_history.clear();
     }
 
     pub fn add_file_list_entry(&mut self, kind: FileListKind, entry: String) -> usize {
+        self.record_file_list_snapshot();
         let (files, selected, _) = self.file_list_parts_mut(kind);
         files.push(entry);
         let index = files.len() - 1;
-        *selected = Some(index);
+        *selected = None;
+        self.select_file_list_entry(kind, Some(index));
         index
     }
 
     pub fn remove_file_list_entry(&mut self, kind: FileListKind, index: usize) -> Option<String> {
-        let (files, selected, _) = self.file_list_parts_mut(kind);
-        if index >= files.len() {
+        if index >= self.file_list_entries(kind).len() {
             return None;
         }
 
+        self.record_file_list_snapshot();
+        let (files, selected, _) = self.file_list_parts_mut(kind);
         let removed = files.remove(index);
-        if files.is_empty() {
-            *selected = None;
-        } else if index >= files.len() {
-            *selected = Some(files.len() - 1);
-        } else {
-            *selected = Some(index);
-        }
+        *selected = None;
+        let nearest = self.nearest_visible_file_list_entry(kind, index);
+        self.select_file_list_entry(kind, nearest);
         Some(removed)
     }
 
+    /// The first entry at or after `index` that passes the filter, falling back to the
+    /// last visible entry before it.
+    fn nearest_visible_file_list_entry(&self, kind: FileListKind, index: usize) -> Option<usize> {
+        let visible = self.filtered_file_list(kind);
+        visible
+            .iter()
+            .find(|(candidate, _)| *candidate >= index)
+            .or_else(|| visible.last())
+            .map(|(candidate, _)| *candidate)
+    }
+
+    /// Restores the include and exclude lists from before the last add or remove.
+    /// Returns `false` when there is nothing to undo.
+    pub fn undo_file_list(&mut self) -> bool {
+        let Some(snapshot) = self.file_list_history.undo.pop() else {
+            return false;
+        };
+        let current = self.replace_file_lists(snapshot);
+        self.file_list_history.redo.push(current);
+        true
+    }
+
+    /// Reapplies the last edit reverted by [`Self::undo_file_list`].
+    /// Returns `false` when there is nothing to redo.
+    pub fn redo_file_list(&mut self) -> bool {
+        let Some(snapshot) = self.file_list_history.redo.pop() else {
+            return false;
+        };
+        let current = self.replace_file_lists(snapshot);
+        self.file_list_history.undo.push(current);
+        true
+    }
+
+    fn record_file_list_snapshot(&mut self) {
+        let snapshot = FileListSnapshot {
+            include_files: self.include_files.clone(),
+            exclude_files: self.exclude_files.clone(),
+        };
+        self.file_list_history.record(snapshot);
+    }
+
+    fn replace_file_lists(&mut self, snapshot: FileListSnapshot) -> FileListSnapshot {
+        let previous = FileListSnapshot {
+            include_files: std::mem::replace(&mut self.include_files, snapshot.include_files),
+            exclude_files: std::mem::replace(&mut self.exclude_files, snapshot.exclude_files),
+        };
+        for kind in [FileListKind::Include, FileListKind::Exclude] {
+            let selection = self.file_list_selection(kind);
+            self.select_file_list_entry(kind, selection);
+        }
+        previous
+    }
+
     pub fn clear_file_list_selection(&mut self, kind: FileListKind) {
         let (_, selected, _) = self.file_list_parts_mut(kind);
         *selected = None;
@@ -1200,7 +1898,9 @@ impl PackerState {
     }
 
     fn ensure_timestamp_strategy_default(&mut self) {
-        if !matches!(self.timestamp_strategy, TimestampStrategy::None) {
+        if !matches!(self.timestamp_strategy, TimestampStrategy::None)
+            || self.no_timestamp_requested
+        {
             return;
         }
 
@@ -1264,6 +1964,21 @@ impl PackerState {
             psu_packer::Error::ConfigError(message) => {
                 format!("Configuration error: {message}")
             }
+            psu_packer::Error::ReservedEntryName(name) => {
+                format!("{name} cannot be packed because the PSU reserves that name for a directory entry.")
+            }
+            psu_packer::Error::FileError { path, source } => match source.kind() {
+                io::ErrorKind::NotFound => {
+                    format!("{} disappeared before it could be packed.", path.display())
+                }
+                io::ErrorKind::PermissionDenied => {
+                    format!(
+                        "Permission denied while reading {}: {source}",
+                        path.display()
+                    )
+                }
+                _ => format!("Could not read {}: {source}", path.display()),
+            },
             psu_packer::Error::IOError(io_err) => {
                 let missing_files = self.missing_include_files(folder);
                 if !missing_files.is_empty() {
@@ -1300,27 +2015,79 @@ impl PackerState {
         }
     }
 
-    pub fn determine_update_destination(&self) -> Result<PathBuf, String> {
+    pub fn determine_update_destination(&self) -> Result<PathBuf, StateError> {
         if let Some(path) = &self.loaded_psu_path {
+            if is_gzip_psu_path(path) {
+                return Err(StateError::Validation(format!(
+                    "Cannot update {} in place because it is gzip-compressed. \
+                     Export its contents and pack them to a new PSU instead.",
+                    path.display()
+                )));
+            }
             return Ok(path.clone());
         }
 
         let trimmed = self.output.trim();
         if trimmed.is_empty() {
-            Err("Load a PSU file or set the output path before updating.".to_string())
+            Err(StateError::MissingSource(
+                "Load a PSU file or set the output path before updating.".to_string(),
+            ))
         } else {
             Ok(PathBuf::from(trimmed))
         }
     }
 
-    pub fn determine_export_source_path(&self) -> Result<PathBuf, String> {
-        if let Some(path) = &self.loaded_psu_path {
-            return Ok(path.clone());
+    /// Rejects update destinations that live in a temporary workspace, either the one held for
+    /// the current update or one that has since been cleaned up.
+    pub fn check_update_destination(&self, destination: &Path) -> Result<(), StateError> {
+        if let Some(workspace) = &self.temp_workspace {
+            if destination.starts_with(workspace.path()) {
+                return Err(StateError::Validation(format!(
+                    "Cannot update {} because it is inside the temporary update workspace. \
+                     Choose an output location outside {}.",
+                    destination.display(),
+                    workspace.path().display()
+                )));
+            }
+        }
+
+        let parent = destination
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty());
+        if let Some(parent) = parent {
+            if !parent.is_dir() {
+                let temp_root = std::env::temp_dir();
+                return Err(StateError::Io(
+                    if parent.starts_with(&temp_root) && parent != temp_root {
+                        format!(
+                            "Cannot update {} because its folder was a temporary workspace \
+                             that no longer exists. Choose a new output location.",
+                            destination.display()
+                        )
+                    } else {
+                        format!(
+                            "Cannot update {} because the folder {} does not exist.",
+                            destination.display(),
+                            parent.display()
+                        )
+                    },
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn determine_export_source_path(&self) -> Result<PathBuf, StateError> {
+        if let Some(path) = &self.loaded_psu_path {
+            return Ok(path.clone());
         }
 
         let trimmed = self.output.trim();
         if trimmed.is_empty() {
-            Err("Load a PSU file or select a packed PSU before exporting its contents.".to_string())
+            Err(StateError::MissingSource(
+                "Load a PSU file or select a packed PSU before exporting its contents.".to_string(),
+            ))
         } else {
             Ok(PathBuf::from(trimmed))
         }
@@ -1330,93 +2097,109 @@ impl PackerState {
         &self,
         source_path: &Path,
         destination_parent: &Path,
-    ) -> Result<PathBuf, String> {
+    ) -> Result<PathBuf, StateError> {
         if !source_path.is_file() {
-            return Err(format!(
+            return Err(StateError::Io(format!(
                 "Cannot export because {} does not exist.",
                 source_path.display()
-            ));
+            )));
         }
 
-        let data = fs::read(source_path)
-            .map_err(|err| format!("Failed to read {}: {err}", source_path.display()))?;
+        let data = read_psu_bytes(source_path)?;
+
+        psu_packer::unpack_psu(&data, destination_parent).map_err(|err| {
+            let message = format!("Failed to export {}: {err}", source_path.display());
+            match err {
+                psu_packer::Error::IOError(_) | psu_packer::Error::FileError { .. } => {
+                    StateError::Io(message)
+                }
+                _ => StateError::Parse(message),
+            }
+        })
+    }
+
+    /// Rewrites the loaded PSU in place so its root directory matches the current
+    /// metadata name.
+    pub fn rename_loaded_psu_root(&mut self) -> Result<PathBuf, StateError> {
+        let path = self.loaded_psu_path.clone().ok_or_else(|| {
+            StateError::MissingSource("No PSU file is currently loaded.".to_string())
+        })?;
+        let new_name = self.folder_name();
 
-        let parsed = std::panic::catch_unwind(|| PSU::new(data))
-            .map_err(|_| format!("Failed to parse PSU file {}", source_path.display()))?;
+        let bytes = read_psu_bytes(&path)?;
+        let renamed = psu_packer::rename_root(&bytes, &new_name).map_err(|err| {
+            StateError::Validation(format!("Failed to rename {}: {err}", path.display()))
+        })?;
+        write_psu_bytes(&path, &renamed)?;
 
-        let entries = parsed.entries();
-        let root_name = entries
-            .iter()
+        self.loaded_psu_root_name = Some(new_name);
+        Ok(path)
+    }
+
+    /// Reads the root directory timestamp of the loaded PSU into `source_timestamp` so the
+    /// inherit-source strategy can reuse it.
+    pub fn load_source_timestamp_from_psu(&mut self) -> Result<Option<NaiveDateTime>, StateError> {
+        let path = self.loaded_psu_path.clone().ok_or_else(|| {
+            StateError::MissingSource("No PSU file is currently loaded.".to_string())
+        })?;
+        let bytes = read_psu_bytes(&path)?;
+        let psu = ps2_filetypes::PSU::try_new(bytes).map_err(|err| {
+            StateError::Parse(format!(
+                "Failed to parse PSU file {}: {err}",
+                path.display()
+            ))
+        })?;
+
+        let timestamp = psu
+            .entries()
+            .into_iter()
             .find(|entry| {
-                matches!(entry.kind, PSUEntryKind::Directory)
+                matches!(entry.kind, ps2_filetypes::PSUEntryKind::Directory)
                     && entry.name != "."
                     && entry.name != ".."
             })
-            .map(|entry| entry.name.clone())
-            .ok_or_else(|| format!("{} does not contain PSU metadata", source_path.display()))?;
+            .map(|entry| entry.modified);
+        self.source_timestamp = timestamp;
+        Ok(timestamp)
+    }
+
+    pub fn prepare_loaded_psu_workspace(&self) -> Result<(TempDir, PathBuf), StateError> {
+        let source_path = self.loaded_psu_path.as_ref().ok_or_else(|| {
+            StateError::MissingSource("No PSU file is currently loaded.".to_string())
+        })?;
+        let temp_dir = tempdir().map_err(|err| {
+            StateError::Io(format!("Failed to create temporary workspace: {err}"))
+        })?;
+        let export_root = self
+            .export_psu_to_folder(source_path, temp_dir.path())
+            .map_err(|err| err.context("Failed to export loaded PSU"))?;
+        Ok((temp_dir, export_root))
+    }
 
-        if root_name.trim().is_empty() {
-            return Err(format!(
-                "{} does not contain a valid root directory entry.",
-                source_path.display()
+    /// Adds a folder whose files are merged into the next pack. The project folder itself and
+    /// folders that are already listed are rejected.
+    pub fn add_merge_folder(&mut self, path: PathBuf) -> Result<(), StateError> {
+        if self.folder.as_ref() == Some(&path) {
+            return Err(StateError::Validation(
+                "The project folder is already part of the pack.".to_string(),
             ));
         }
-
-        let export_root = destination_parent.join(&root_name);
-        fs::create_dir_all(&export_root)
-            .map_err(|err| format!("Failed to create {}: {err}", export_root.display()))?;
-
-        for entry in entries {
-            match entry.kind {
-                PSUEntryKind::Directory => {
-                    if entry.name == "." || entry.name == ".." {
-                        continue;
-                    }
-
-                    let target = if entry.name == root_name {
-                        export_root.clone()
-                    } else {
-                        export_root.join(&entry.name)
-                    };
-
-                    fs::create_dir_all(&target)
-                        .map_err(|err| format!("Failed to create {}: {err}", target.display()))?;
-                }
-                PSUEntryKind::File => {
-                    let Some(contents) = entry.contents else {
-                        return Err(format!(
-                            "{} is missing file data in the PSU archive.",
-                            entry.name
-                        ));
-                    };
-
-                    let target = export_root.join(&entry.name);
-                    if let Some(parent) = target.parent() {
-                        fs::create_dir_all(parent).map_err(|err| {
-                            format!("Failed to create {}: {err}", parent.display())
-                        })?;
-                    }
-
-                    fs::write(&target, contents)
-                        .map_err(|err| format!("Failed to write {}: {err}", target.display()))?;
-                }
-            }
+        if self.merge_folders.contains(&path) {
+            return Err(StateError::Validation(format!(
+                "{} is already merged.",
+                path.display()
+            )));
         }
-
-        Ok(export_root)
+        self.merge_folders.push(path);
+        Ok(())
     }
 
-    pub fn prepare_loaded_psu_workspace(&self) -> Result<(TempDir, PathBuf), String> {
-        let source_path = self
-            .loaded_psu_path
-            .as_ref()
-            .ok_or_else(|| "No PSU file is currently loaded.".to_string())?;
-        let temp_dir =
-            tempdir().map_err(|err| format!("Failed to create temporary workspace: {err}"))?;
-        let export_root = self
-            .export_psu_to_folder(source_path, temp_dir.path())
-            .map_err(|err| format!("Failed to export loaded PSU: {err}"))?;
-        Ok((temp_dir, export_root))
+    pub fn remove_merge_folder(&mut self, index: usize) -> bool {
+        if index >= self.merge_folders.len() {
+            return false;
+        }
+        self.merge_folders.remove(index);
+        true
     }
 
     pub fn is_pack_running(&self) -> bool {
@@ -1433,16 +2216,46 @@ impl PackerState {
             return;
         }
 
-        let progress = Arc::new(Mutex::new(PackProgress::InProgress));
+        let progress = Arc::new(Mutex::new(PackProgress::InProgress(0.0)));
         let thread_progress = Arc::clone(&progress);
+        let packing_temp_workspace = self
+            .temp_workspace
+            .as_ref()
+            .is_some_and(|workspace| folder.starts_with(workspace.path()));
+        let config_to_save =
+            (self.write_config_on_pack && !packing_temp_workspace).then(|| config.clone());
+        let merge_folders = self.merge_folders.clone();
+        let merge_conflict_policy = self.merge_conflict_policy;
+        let make_output_read_only = self.make_output_read_only;
 
         let handle = thread::spawn(move || {
-            let result =
-                psu_packer::pack_with_config(folder.as_path(), output_path.as_path(), config);
+            let mut report_progress = |event: psu_packer::PackProgressEvent| {
+                let mut guard = thread_progress
+                    .lock()
+                    .unwrap_or_else(|poison| poison.into_inner());
+                if let PackProgress::InProgress(value) = &mut *guard {
+                    *value = event.fraction();
+                }
+            };
+            let result = psu_packer::pack_merged_with_progress(
+                folder.as_path(),
+                &merge_folders,
+                output_path.as_path(),
+                config,
+                merge_conflict_policy,
+                &mut report_progress,
+            );
 
             let outcome = match result {
                 Ok(_) => PackOutcome::Success {
                     output_path: output_path.clone(),
+                    saved_config: config_to_save.map(|config| {
+                        psu_packer::save_config(folder.as_path(), &config)
+                            .map_err(|error| error.to_string())
+                    }),
+                    read_only: make_output_read_only.then(|| {
+                        set_file_read_only(&output_path, true).map_err(|error| error.to_string())
+                    }),
                 },
                 Err(error) => PackOutcome::Error {
                     folder: folder.clone(),
@@ -1469,7 +2282,7 @@ impl PackerState {
         let job = self.pack_job.as_ref()?;
         let guard = job.progress.lock().ok()?;
         Some(match &*guard {
-            PackProgress::InProgress => 0.0,
+            PackProgress::InProgress(value) => value.clamp(0.0, 1.0),
             PackProgress::Finished(_) => 1.0,
         })
     }
@@ -1483,7 +2296,7 @@ impl PackerState {
             Ok(mut guard) => {
                 if let PackProgress::Finished(_) = &*guard {
                     if let PackProgress::Finished(outcome) =
-                        std::mem::replace(&mut *guard, PackProgress::InProgress)
+                        std::mem::replace(&mut *guard, PackProgress::InProgress(0.0))
                     {
                         Some(outcome)
                     } else {
@@ -1497,7 +2310,7 @@ impl PackerState {
                 let mut guard = poison.into_inner();
                 if let PackProgress::Finished(_) = &*guard {
                     if let PackProgress::Finished(outcome) =
-                        std::mem::replace(&mut *guard, PackProgress::InProgress)
+                        std::mem::replace(&mut *guard, PackProgress::InProgress(0.0))
                     {
                         Some(outcome)
                     } else {
@@ -1514,7 +2327,20 @@ impl PackerState {
                 let _ = handle.join();
             }
 
-            self.temp_workspace = None;
+            self.events.push(match &outcome {
+                PackOutcome::Success { output_path, .. } => AppEvent::PackFinished {
+                    output_path: output_path.clone(),
+                },
+                PackOutcome::Error { error, .. } => AppEvent::PackFailed {
+                    error: error.to_string(),
+                },
+            });
+
+            let workspace = self.temp_workspace.take();
+            let failed = matches!(outcome, PackOutcome::Error { .. });
+            self.retained_temp_workspace = workspace
+                .filter(|_| failed && self.keep_temp_workspace_on_failure)
+                .map(TempDir::into_path);
             Some(outcome)
         } else {
             self.pack_job = Some(job);
@@ -1523,6 +2349,29 @@ impl PackerState {
     }
 }
 
+/// Marks `path` read-only, or makes it writable for its owner again.
+pub fn set_file_read_only(path: &Path, read_only: bool) -> io::Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    if read_only {
+        permissions.set_readonly(true);
+    } else {
+        make_writable(&mut permissions);
+    }
+    fs::set_permissions(path, permissions)
+}
+
+#[cfg(unix)]
+fn make_writable(permissions: &mut fs::Permissions) {
+    use std::os::unix::fs::PermissionsExt;
+    permissions.set_mode(permissions.mode() | 0o200);
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::permissions_set_readonly_false)]
+fn make_writable(permissions: &mut fs::Permissions) {
+    permissions.set_readonly(false);
+}
+
 #[derive(Default, Clone)]
 pub struct Files(pub Vec<VirtualFile>, pub u64);
 
@@ -1592,7 +2441,7 @@ fn aligned_file_size(size: u64) -> u64 {
     ((size + 1023) as i64 & -1024) as u64
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct VirtualFile {
     pub name: String,
     pub file_path: PathBuf,
@@ -1708,6 +2557,8 @@ impl ActionDispatcher for AppState {
         match action {
             Action::FileList(FileListAction::Browse(_)) => self.opened_folder.is_some(),
             Action::FileList(FileListAction::ManualAdd(_)) => self.opened_folder.is_some(),
+            Action::FileList(FileListAction::Undo) => self.packer.file_list_history.can_undo(),
+            Action::FileList(FileListAction::Redo) => self.packer.file_list_history.can_redo(),
             Action::FileList(FileListAction::RemoveSelected(kind)) => match kind {
                 FileListKind::Include => {
                     self.opened_folder.is_some() && self.packer.selected_include.is_some()
@@ -1753,8 +2604,11 @@ impl ActionDispatcher for AppState {
         match action {
             Action::OpenProject => self.open_folder(),
             Action::PackPsu => self.export_psu(),
+            Action::ValidateProject => self.validate(),
             Action::UpdatePsu => {}
             Action::ExportPsuToFolder => {}
+            Action::ExportEntryListCsv => {}
+            Action::RevealOutput => {}
             Action::AddFiles => self.add_files(),
             Action::SaveFile => self.save_file(),
             Action::ChooseOutputDestination => self.choose_output_destination(),
@@ -1775,6 +2629,9 @@ impl ActionDispatcher for AppState {
                 MetadataAction::SetPsuFileBaseName(base_name) => {
                     self.packer.set_psu_file_base_name(base_name);
                 }
+                MetadataAction::StripRedundantPrefix => {
+                    self.packer.strip_redundant_prefix();
+                }
             },
             Action::Timestamp(timestamp_action) => match timestamp_action {
                 TimestampAction::SelectStrategy(strategy_action) => {
@@ -1782,9 +2639,10 @@ impl ActionDispatcher for AppState {
                         TimestampStrategyAction::None => TimestampStrategy::None,
                         TimestampStrategyAction::InheritSource => TimestampStrategy::InheritSource,
                         TimestampStrategyAction::SasRules => TimestampStrategy::SasRules,
+                        TimestampStrategyAction::NewestFile => TimestampStrategy::NewestFile,
                         TimestampStrategyAction::Manual => TimestampStrategy::Manual,
                     };
-                    self.packer.set_timestamp_strategy(strategy);
+                    self.packer.select_timestamp_strategy(strategy);
                 }
                 TimestampAction::RefreshFromStrategy => {
                     self.packer.refresh_timestamp_from_strategy();
@@ -1798,6 +2656,9 @@ impl ActionDispatcher for AppState {
                 TimestampAction::ResetRulesToDefault => {
                     self.packer.reset_timestamp_rules_to_default();
                 }
+                TimestampAction::ApplyRulesPreset(preset_id) => {
+                    self.packer.apply_timestamp_rules_preset(&preset_id);
+                }
                 TimestampAction::SetManualTimestamp(timestamp) => {
                     self.packer.set_manual_timestamp(timestamp);
                 }
@@ -1839,6 +2700,16 @@ impl ActionDispatcher for AppState {
                             self.packer.mark_timestamp_rules_modified();
                         }
                     }
+                    TimestampRulesAction::SelectAllAliases(index) => {
+                        if self.packer.timestamp_rules_ui.select_all_aliases(index) {
+                            self.packer.mark_timestamp_rules_modified();
+                        }
+                    }
+                    TimestampRulesAction::ClearAllAliases(index) => {
+                        if self.packer.timestamp_rules_ui.clear_all_aliases(index) {
+                            self.packer.mark_timestamp_rules_modified();
+                        }
+                    }
                 },
             },
             Action::FileList(file_action) => match file_action {
@@ -1863,6 +2734,12 @@ impl ActionDispatcher for AppState {
                 FileListAction::SelectEntry(kind, selection) => {
                     self.packer.select_file_list_entry(kind, selection);
                 }
+                FileListAction::Undo => {
+                    self.packer.undo_file_list();
+                }
+                FileListAction::Redo => {
+                    self.packer.redo_file_list();
+                }
             },
             Action::IconSys(icon_action) => match icon_action {
                 IconSysAction::Enable => {
@@ -1932,6 +2809,7 @@ impl ActionDispatcher for AppState {
 mod tests {
     use super::*;
     use chrono::{DateTime, NaiveDateTime, Utc};
+    use ps2_filetypes::PSU;
 
     fn state_with_folder() -> (AppState, TempDir) {
         let mut state = AppState::new();
@@ -1942,6 +2820,217 @@ mod tests {
         (state, workspace)
     }
 
+    #[test]
+    fn confirmation_returns_action_only_when_accepted() {
+        let mut packer = PackerState::default();
+        packer.request_confirmation("overwrite", "Overwrite?", Action::PackPsu);
+        assert!(matches!(
+            packer.take_events().as_slice(),
+            [AppEvent::Confirm { id, on_confirm_action: Action::PackPsu, .. }] if id == "overwrite"
+        ));
+
+        assert_eq!(packer.resolve_confirmation("other", true), None);
+        assert!(packer.pending_confirmation.is_some());
+        assert_eq!(
+            packer.resolve_confirmation("overwrite", true),
+            Some(Action::PackPsu)
+        );
+        assert!(packer.pending_confirmation.is_none());
+
+        packer.request_confirmation("overwrite", "Overwrite?", Action::PackPsu);
+        assert_eq!(packer.resolve_confirmation("overwrite", false), None);
+        assert!(packer.pending_confirmation.is_none());
+        assert_eq!(packer.resolve_confirmation("overwrite", true), None);
+    }
+
+    #[test]
+    fn source_timestamp_is_read_from_loaded_psu_root() {
+        let workspace = tempdir().expect("create tempdir");
+        let project = workspace.path().join("project");
+        fs::create_dir_all(&project).expect("create project");
+        fs::write(project.join("BOOT.ELF"), b"data").expect("write file");
+        let output = workspace.path().join("SAVE.psu");
+        let timestamp = naive(1_600_000_000);
+        let config = psu_packer::Config {
+            name: "APP_SAVE".to_string(),
+            timestamp: Some(timestamp),
+            ..Default::default()
+        };
+        psu_packer::pack_with_config(&project, &output, config).expect("pack PSU");
+
+        let mut packer = PackerState::default();
+        assert!(packer.load_source_timestamp_from_psu().is_err());
+
+        packer.loaded_psu_path = Some(output);
+        assert_eq!(packer.load_source_timestamp_from_psu(), Ok(Some(timestamp)));
+        assert_eq!(packer.source_timestamp, Some(timestamp));
+
+        packer.sync_timestamp_after_source_update();
+        assert_eq!(packer.timestamp_strategy, TimestampStrategy::InheritSource);
+        assert_eq!(packer.timestamp, Some(timestamp));
+    }
+
+    #[test]
+    fn gzip_compressed_psu_parses_like_the_plain_file() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let workspace = tempdir().expect("create tempdir");
+        let project = workspace.path().join("project");
+        fs::create_dir_all(&project).expect("create project");
+        fs::write(project.join("BOOT.ELF"), b"data").expect("write file");
+        let plain = workspace.path().join("SAVE.psu");
+        let config = psu_packer::Config {
+            name: "APP_SAVE".to_string(),
+            timestamp: Some(naive(1_600_000_000)),
+            ..Default::default()
+        };
+        psu_packer::pack_with_config(&project, &plain, config).expect("pack PSU");
+
+        let raw = fs::read(&plain).expect("read PSU");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&raw).expect("compress PSU");
+        let compressed = encoder.finish().expect("finish gzip stream");
+        let gzipped = workspace.path().join("SAVE.psu.gz");
+        fs::write(&gzipped, &compressed).expect("write PSU.gz");
+        assert!(is_psu_path(&gzipped));
+
+        let describe = |bytes: Vec<u8>| {
+            PSU::new(bytes)
+                .entries()
+                .into_iter()
+                .map(|entry| (entry.name, entry.size, entry.modified, entry.contents))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            describe(read_psu_bytes(&gzipped).expect("decompress PSU")),
+            describe(read_psu_bytes(&plain).expect("read PSU"))
+        );
+
+        let truncated = workspace.path().join("TRUNCATED.psu.gz");
+        fs::write(&truncated, &compressed[..compressed.len() / 2]).expect("write truncated");
+        assert!(matches!(
+            read_psu_bytes(&truncated),
+            Err(StateError::Parse(message)) if message.starts_with("Failed to decompress")
+        ));
+    }
+
+    #[test]
+    fn newest_file_strategy_uses_latest_modified_time() {
+        let (mut state, workspace) = state_with_folder();
+        let set_modified = |name: &str, secs: u64| {
+            let path = workspace.path().join(name);
+            let file = fs::File::create(&path).expect("create file");
+            file.set_modified(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+                .expect("set mtime");
+        };
+
+        state
+            .packer
+            .set_timestamp_strategy(TimestampStrategy::NewestFile);
+        assert_eq!(
+            state.packer.timestamp, None,
+            "empty folder has no timestamp"
+        );
+
+        set_modified("OLD.BIN", 1_500_000_000);
+        set_modified("NEW.BIN", 1_600_000_000);
+        state.packer.refresh_timestamp_from_strategy();
+
+        let expected = DateTime::<Utc>::from_timestamp(1_600_000_000, 0)
+            .expect("construct timestamp")
+            .with_timezone(&Local)
+            .naive_local();
+        assert_eq!(state.packer.timestamp, Some(expected));
+        assert!(!state.packer.timestamp_from_rules);
+    }
+
+    #[test]
+    fn redundant_prefix_is_detected_and_stripped() {
+        let mut state = AppState::new();
+        state.packer.selected_prefix = SasPrefix::App;
+        state.packer.folder_base_name = "APP_Game".to_string();
+        assert_eq!(state.packer.folder_name(), "APP_APP_Game");
+        assert_eq!(state.packer.redundant_prefix(), Some(SasPrefix::App));
+        assert!(state.packer.double_prefix_warning().is_some());
+
+        state.trigger_action(Action::Metadata(MetadataAction::StripRedundantPrefix));
+        assert_eq!(state.packer.folder_name(), "APP_Game");
+        assert_eq!(state.packer.redundant_prefix(), None);
+
+        state.packer.folder_base_name = "emu_Game".to_string();
+        assert!(state.packer.strip_redundant_prefix());
+        assert_eq!(state.packer.selected_prefix, SasPrefix::Emu);
+        assert_eq!(state.packer.folder_name(), "EMU_Game");
+
+        state.packer.folder_base_name = "APPSTORE".to_string();
+        assert_eq!(state.packer.redundant_prefix(), None);
+
+        state.packer.selected_prefix = SasPrefix::None;
+        state.packer.folder_base_name = "APP_Game".to_string();
+        assert_eq!(state.packer.redundant_prefix(), None);
+    }
+
+    #[test]
+    fn loading_odd_interval_reports_adjustment() {
+        let workspace = tempdir().expect("create tempdir");
+        fs::write(
+            workspace.path().join(TIMESTAMP_RULES_FILE),
+            r#"{ "seconds_between_items": 7 }"#,
+        )
+        .expect("write rules");
+
+        let mut packer = PackerState::default();
+        packer.load_timestamp_rules_from_folder(workspace.path());
+        assert_eq!(packer.timestamp_rules.seconds_between_items, 8);
+        assert_eq!(
+            packer.timestamp_rules_warnings,
+            vec!["Rounded interval from 7 to 8 seconds".to_string()]
+        );
+
+        packer.reset_timestamp_rules_to_default();
+        assert!(packer.timestamp_rules_warnings.is_empty());
+    }
+
+    #[test]
+    fn merge_folders_reject_project_and_duplicates() {
+        let (state, workspace) = state_with_folder();
+        let mut packer = state.packer;
+        let other = workspace.path().join("other");
+
+        assert!(packer
+            .add_merge_folder(workspace.path().to_path_buf())
+            .is_err());
+        assert!(packer.add_merge_folder(other.clone()).is_ok());
+        assert!(packer.add_merge_folder(other.clone()).is_err());
+        assert_eq!(packer.merge_folders, vec![other]);
+
+        assert!(!packer.remove_merge_folder(1));
+        assert!(packer.remove_merge_folder(0));
+        assert!(packer.merge_folders.is_empty());
+    }
+
+    #[test]
+    fn update_destination_in_vanished_temp_workspace_is_rejected() {
+        let packer = PackerState::default();
+        let vanished = tempdir().expect("create tempdir");
+        let destination = vanished.path().join("SAVE.psu");
+        drop(vanished);
+
+        let err = packer
+            .check_update_destination(&destination)
+            .expect_err("vanished workspace");
+        assert!(
+            err.message().contains("temporary workspace"),
+            "unexpected error: {err}"
+        );
+
+        let stable = tempdir().expect("create tempdir");
+        assert!(packer
+            .check_update_destination(&stable.path().join("SAVE.psu"))
+            .is_ok());
+    }
+
     fn naive(secs: i64) -> NaiveDateTime {
         DateTime::<Utc>::from_timestamp(secs, 0)
             .expect("construct timestamp")
@@ -2041,6 +3130,226 @@ mod tests {
         assert!(!state.packer.timestamp_rules_loaded_from_file);
     }
 
+    #[test]
+    fn rename_loaded_psu_root_uses_metadata_name() {
+        let workspace = tempdir().expect("create tempdir");
+        let project = workspace.path().join("project");
+        fs::create_dir(&project).expect("create project");
+        fs::write(project.join("DATA.BIN"), b"payload").expect("write data");
+
+        let psu_path = workspace.path().join("save.psu");
+        let config = psu_packer::Config {
+            name: "APP_OLD".to_string(),
+            timestamp: None,
+            include: None,
+            exclude: None,
+            icon_sys: None,
+            ..Default::default()
+        };
+        psu_packer::pack_with_config(&project, &psu_path, config).expect("pack psu");
+
+        let mut packer = PackerState {
+            loaded_psu_path: Some(psu_path.clone()),
+            loaded_psu_root_name: Some("APP_OLD".to_string()),
+            selected_prefix: SasPrefix::App,
+            folder_base_name: "NEW".to_string(),
+            ..PackerState::default()
+        };
+
+        packer.rename_loaded_psu_root().expect("rename succeeds");
+
+        let archive = PSU::new(fs::read(&psu_path).expect("read psu"));
+        assert_eq!(archive.entries[0].name, "APP_NEW");
+        assert_eq!(packer.loaded_psu_root_name.as_deref(), Some("APP_NEW"));
+    }
+
+    #[test]
+    fn loaded_psu_name_mismatch_compares_file_stem_with_root_name() {
+        let mut state = AppState::new();
+        state.packer.loaded_psu_path = Some(PathBuf::from("saves/MySave.psu"));
+        state.packer.loaded_psu_root_name = Some("APP_Game".to_string());
+
+        let message = state
+            .packer
+            .loaded_psu_name_mismatch()
+            .expect("mismatch should be reported");
+        assert!(message.contains("MySave"));
+        assert!(message.contains("APP_Game"));
+
+        state.packer.loaded_psu_path = Some(PathBuf::from("saves/APP_Game.psu"));
+        assert!(state.packer.loaded_psu_name_mismatch().is_none());
+    }
+
+    #[test]
+    fn timestamp_collisions_follow_the_edited_rules_without_rescanning() {
+        let workspace = tempdir().expect("create tempdir");
+        for name in ["APP_FIRST", "APP_SECOND"] {
+            fs::create_dir(workspace.path().join(name)).expect("create sibling folder");
+        }
+        let mut packer = PackerState {
+            folder: Some(workspace.path().join("APP_FIRST")),
+            ..PackerState::default()
+        };
+        assert!(packer.timestamp_collisions().is_empty());
+
+        assert!(packer.timestamp_rules_ui.set_slots_per_category(1));
+        assert_eq!(packer.timestamp_collisions().len(), 1);
+        assert_eq!(packer.timestamp_collisions()[0].0, "APP_FIRST");
+        assert_eq!(packer.timestamp_collisions()[0].1, "APP_SECOND");
+
+        fs::create_dir(workspace.path().join("APP_THIRD")).expect("create another folder");
+        assert_eq!(packer.timestamp_collisions().len(), 1);
+    }
+
+    #[test]
+    fn gzip_compressed_psu_is_renamed_in_place_and_not_updated() {
+        let workspace = tempdir().expect("create tempdir");
+        let project = workspace.path().join("project");
+        fs::create_dir(&project).expect("create project");
+        fs::write(project.join("DATA.BIN"), b"payload").expect("write data");
+        let plain = workspace.path().join("plain.psu");
+        let config = psu_packer::Config {
+            name: "APP_OLD".to_string(),
+            ..Default::default()
+        };
+        psu_packer::pack_with_config(&project, &plain, config).expect("pack psu");
+        let gzipped = workspace.path().join("APP_OLD.psu.gz");
+        write_psu_bytes(&gzipped, &fs::read(&plain).expect("read psu")).expect("write psu.gz");
+
+        let mut packer = PackerState {
+            loaded_psu_path: Some(gzipped.clone()),
+            loaded_psu_root_name: Some("APP_OLD".to_string()),
+            selected_prefix: SasPrefix::App,
+            folder_base_name: "NEW".to_string(),
+            ..PackerState::default()
+        };
+        assert_eq!(psu_file_stem(&gzipped), Some("APP_OLD"));
+        assert!(packer.loaded_psu_name_mismatch().is_none());
+        assert!(matches!(
+            packer.determine_update_destination(),
+            Err(StateError::Validation(_))
+        ));
+
+        packer.rename_loaded_psu_root().expect("rename succeeds");
+        let raw = fs::read(&gzipped).expect("read psu.gz");
+        assert!(raw.starts_with(&GZIP_MAGIC));
+        let archive = PSU::new(read_psu_bytes(&gzipped).expect("decompress psu"));
+        assert_eq!(archive.entries[0].name, "APP_NEW");
+    }
+
+    #[test]
+    fn future_timestamp_warning_flags_only_future_values() {
+        let mut state = AppState::new();
+        state
+            .packer
+            .set_timestamp_strategy(TimestampStrategy::Manual);
+        let now = naive(1_700_000_000);
+
+        state
+            .packer
+            .set_manual_timestamp(Some(naive(1_600_000_000)));
+        assert!(state.packer.future_timestamp_warning_at(now).is_none());
+
+        state
+            .packer
+            .set_manual_timestamp(Some(naive(1_800_000_000)));
+        let warning = state
+            .packer
+            .future_timestamp_warning_at(now)
+            .expect("future timestamp should warn");
+        assert!(warning.contains("future"));
+
+        state.packer.set_timestamp_strategy(TimestampStrategy::None);
+        assert!(state.packer.future_timestamp_warning_at(now).is_none());
+    }
+
+    #[test]
+    fn choosing_no_timestamp_requests_timestamp_false() {
+        let mut state = AppState::new();
+        state
+            .packer
+            .set_timestamp_strategy(TimestampStrategy::Manual);
+
+        state.trigger_action(Action::Timestamp(TimestampAction::SelectStrategy(
+            TimestampStrategyAction::None,
+        )));
+        assert_eq!(state.packer.timestamp_strategy, TimestampStrategy::None);
+        assert!(state.packer.no_timestamp_requested);
+
+        state.trigger_action(Action::Timestamp(TimestampAction::SelectStrategy(
+            TimestampStrategyAction::SasRules,
+        )));
+        assert!(!state.packer.no_timestamp_requested);
+    }
+
+    #[test]
+    fn timestamp_apply_rules_preset_replaces_categories() {
+        let mut state = AppState::new();
+        state.packer.timestamp_rules_modified = false;
+
+        state.trigger_action(Action::Timestamp(TimestampAction::ApplyRulesPreset(
+            "chronological_by_name".to_string(),
+        )));
+
+        let keys: Vec<&str> = state
+            .packer
+            .timestamp_rules
+            .categories
+            .iter()
+            .map(|category| category.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["DEFAULT"]);
+        assert_eq!(state.packer.timestamp_rules_ui.len(), 1);
+        assert!(state.packer.timestamp_rules_modified);
+    }
+
+    #[test]
+    fn select_all_and_clear_all_aliases_toggle_every_alias() {
+        let mut state = AppState::new();
+        let index = (0..state.packer.timestamp_rules_ui.len())
+            .find(|&index| {
+                state
+                    .packer
+                    .timestamp_rules_ui
+                    .category(index)
+                    .is_some_and(|category| category.available_aliases().len() > 1)
+            })
+            .expect("category with aliases");
+
+        state.trigger_action(Action::Timestamp(TimestampAction::Rules(
+            TimestampRulesAction::ClearAllAliases(index),
+        )));
+        let category = state.packer.timestamp_rules_ui.category(index).unwrap();
+        assert_eq!(category.alias_count(), 0);
+        assert!(state
+            .packer
+            .timestamp_rules_ui
+            .alias_warning(index)
+            .is_some());
+
+        state.packer.timestamp_rules_modified = false;
+        state.trigger_action(Action::Timestamp(TimestampAction::Rules(
+            TimestampRulesAction::SelectAllAliases(index),
+        )));
+        let category = state.packer.timestamp_rules_ui.category(index).unwrap();
+        assert!(category
+            .available_aliases()
+            .iter()
+            .all(|alias| category.is_alias_selected(alias)));
+        assert_eq!(category.alias_count(), category.available_aliases().len());
+        assert!(state
+            .packer
+            .timestamp_rules_ui
+            .alias_warning(index)
+            .is_none());
+        assert!(state.packer.timestamp_rules_modified);
+
+        let category = state.packer.timestamp_rules_ui.category_mut(index).unwrap();
+        assert!(!category.select_all_aliases());
+        assert!(category.clear_all_aliases());
+        assert!(!category.clear_all_aliases());
+    }
+
     #[test]
     fn file_list_browse_pushes_app_event() {
         let (mut state, _workspace) = state_with_folder();
@@ -2085,6 +3394,111 @@ mod tests {
         assert_eq!(state.packer.selected_include, Some(0));
     }
 
+    #[test]
+    fn file_list_undo_and_redo_restore_removed_entry() {
+        let (mut state, _workspace) = state_with_folder();
+        state.packer.include_files = vec!["A.bin".into(), "B.bin".into()];
+        state.packer.selected_include = Some(1);
+        let undo = Action::FileList(FileListAction::Undo);
+        let redo = Action::FileList(FileListAction::Redo);
+        assert!(!state.is_action_enabled(undo.clone()));
+
+        state.trigger_action(Action::FileList(FileListAction::RemoveSelected(
+            FileListKind::Include,
+        )));
+        assert_eq!(state.packer.include_files, vec!["A.bin".to_string()]);
+        assert!(state.is_action_enabled(undo.clone()));
+
+        state.trigger_action(undo);
+        assert_eq!(
+            state.packer.include_files,
+            vec!["A.bin".to_string(), "B.bin".to_string()]
+        );
+        assert!(state.is_action_enabled(redo.clone()));
+
+        state.trigger_action(redo.clone());
+        assert_eq!(state.packer.include_files, vec!["A.bin".to_string()]);
+        assert!(!state.is_action_enabled(redo));
+    }
+
+    #[test]
+    fn file_list_filter_hides_non_matching_entries() {
+        let mut packer = PackerState {
+            include_files: vec![
+                "DATA.BIN".into(),
+                "icon.sys".into(),
+                "save.dat".into(),
+                "Data2.bin".into(),
+            ],
+            ..Default::default()
+        };
+        assert!(file_list_filter_matches("DATA.BIN", "  "));
+        assert!(file_list_filter_matches("Data2.bin", "data"));
+        assert!(!file_list_filter_matches("icon.sys", "data"));
+
+        packer.select_file_list_entry(FileListKind::Include, Some(1));
+        packer.set_file_list_filter(FileListKind::Include, "DATA".to_string());
+
+        assert_eq!(
+            packer.filtered_file_list(FileListKind::Include),
+            vec![(0, "DATA.BIN"), (3, "Data2.bin")]
+        );
+        assert_eq!(packer.selected_include, None);
+
+        packer.select_file_list_entry(FileListKind::Include, Some(2));
+        assert_eq!(packer.selected_include, None);
+        packer.select_file_list_entry(FileListKind::Include, Some(3));
+        assert_eq!(packer.selected_include, Some(3));
+        assert_eq!(packer.include_files.len(), 4);
+    }
+
+    #[test]
+    fn file_list_edits_never_select_a_filtered_entry() {
+        let mut packer = PackerState {
+            include_files: vec![
+                "DATA.BIN".into(),
+                "icon.sys".into(),
+                "save.dat".into(),
+                "Data2.bin".into(),
+            ],
+            ..Default::default()
+        };
+        packer.set_file_list_filter(FileListKind::Include, "data".to_string());
+
+        packer.select_file_list_entry(FileListKind::Include, Some(0));
+        packer.remove_file_list_entry(FileListKind::Include, 0);
+        assert_eq!(packer.selected_include, Some(2));
+        assert_eq!(packer.include_files[2], "Data2.bin");
+
+        packer.remove_file_list_entry(FileListKind::Include, 2);
+        assert_eq!(packer.selected_include, None);
+
+        packer.add_file_list_entry(FileListKind::Include, "notes.txt".into());
+        assert_eq!(packer.selected_include, None);
+        packer.add_file_list_entry(FileListKind::Include, "DATA3.BIN".into());
+        assert_eq!(packer.selected_include, Some(3));
+
+        packer.select_file_list_entry(FileListKind::Include, Some(1));
+        packer.remove_file_list_entry(FileListKind::Include, 1);
+        assert_eq!(packer.selected_include, Some(2));
+    }
+
+    #[test]
+    fn file_list_history_is_capped() {
+        let mut packer = PackerState::default();
+        for index in 0..FILE_LIST_HISTORY_LIMIT + 10 {
+            packer.add_file_list_entry(FileListKind::Exclude, format!("{index}.bin"));
+        }
+
+        let mut undone = 0;
+        while packer.undo_file_list() {
+            undone += 1;
+        }
+
+        assert_eq!(undone, FILE_LIST_HISTORY_LIMIT);
+        assert_eq!(packer.exclude_files.len(), 10);
+    }
+
     #[test]
     fn icon_sys_enable_sets_flag() {
         let (mut state, _workspace) = state_with_folder();
@@ -2163,4 +3577,211 @@ mod tests {
         assert!(!state.icon_sys_has_existing);
         assert!(state.icon_sys_preset.is_none());
     }
+
+    fn finished_job(outcome: PackOutcome) -> PackJob {
+        PackJob {
+            progress: Arc::new(Mutex::new(PackProgress::Finished(outcome))),
+            handle: None,
+        }
+    }
+
+    fn failed_outcome() -> PackOutcome {
+        PackOutcome::Error {
+            folder: PathBuf::from("project"),
+            output_path: PathBuf::from("out.psu"),
+            error: psu_packer::Error::ConfigError("boom".to_string()),
+        }
+    }
+
+    #[test]
+    fn pack_readiness_tracks_project_and_job_state() {
+        let mut packer = PackerState::default();
+        let readiness = packer.pack_readiness();
+        assert!(!readiness.source_selected);
+        assert!(!readiness.name_set);
+        assert!(!readiness.can_start());
+        assert_eq!(
+            readiness.blocking_reason("packing").as_deref(),
+            Some("Select a project folder or open a PSU before packing.")
+        );
+
+        packer.folder = Some(PathBuf::from("project"));
+        let readiness = packer.pack_readiness();
+        assert!(!readiness.can_start());
+        assert_eq!(
+            readiness.blocking_reason("packing").as_deref(),
+            Some("Enter a save name before packing.")
+        );
+
+        packer.folder_base_name = "SAVE".to_string();
+        assert!(!packer.pack_readiness().output_set);
+        assert!(packer.pack_readiness().can_start());
+        packer.output = "SAVE.psu".to_string();
+        packer.missing_required_project_files = vec![MissingRequiredFile::always("icon.sys")];
+        let readiness = packer.pack_readiness();
+        assert!(readiness.source_selected && readiness.name_set && readiness.output_set);
+        assert!(!readiness.can_start());
+        assert_eq!(
+            readiness.blocking_reason("exporting").as_deref(),
+            Some("Add the missing project assets before exporting: icon.sys.")
+        );
+
+        packer.missing_required_project_files.clear();
+        assert!(packer.pack_readiness().can_start());
+
+        packer.pack_job = Some(finished_job(failed_outcome()));
+        let readiness = packer.pack_readiness();
+        assert!(readiness.pack_running);
+        assert!(!readiness.can_start());
+        assert_eq!(
+            readiness.blocking_reason("packing").as_deref(),
+            Some("Packing in progress…")
+        );
+    }
+
+    #[test]
+    fn timestamp_rules_file_is_packed_only_when_requested() {
+        let workspace = tempdir().expect("create tempdir");
+        let mut packer = PackerState {
+            folder: Some(workspace.path().to_path_buf()),
+            ..Default::default()
+        };
+
+        let (_, exclude) = packer.pack_file_lists();
+        assert_eq!(exclude, vec!["psu.toml".to_string()]);
+
+        fs::write(workspace.path().join(TIMESTAMP_RULES_FILE), "{}").expect("write rules");
+        let (include, exclude) = packer.pack_file_lists();
+        assert_eq!(include, None);
+        assert_eq!(
+            exclude,
+            vec!["psu.toml".to_string(), TIMESTAMP_RULES_FILE.to_string()]
+        );
+
+        packer.include_timestamp_rules_in_psu = true;
+        packer.timestamp_strategy = TimestampStrategy::Manual;
+        assert!(!packer.packs_timestamp_rules());
+        assert!(packer
+            .pack_file_lists()
+            .1
+            .contains(&TIMESTAMP_RULES_FILE.to_string()));
+
+        packer.timestamp_strategy = TimestampStrategy::SasRules;
+        packer.include_files = vec!["BOOT.ELF".to_string()];
+        let (include, exclude) = packer.pack_file_lists();
+        assert_eq!(
+            include,
+            Some(vec![
+                "BOOT.ELF".to_string(),
+                TIMESTAMP_RULES_FILE.to_string()
+            ])
+        );
+        assert_eq!(exclude, vec!["psu.toml".to_string()]);
+    }
+
+    #[test]
+    fn finished_pack_job_pushes_completion_event() {
+        let workspace = tempdir().expect("create tempdir");
+        let folder = workspace.path().join("project");
+        fs::create_dir_all(&folder).expect("create project");
+        fs::write(folder.join("DATA.BIN"), b"data").expect("write file");
+        let output_path = workspace.path().join("out.psu");
+
+        let mut packer = PackerState::default();
+        packer.start_pack_job(
+            folder,
+            output_path.clone(),
+            psu_packer::Config {
+                name: "APP_EVENTS".to_string(),
+                ..Default::default()
+            },
+        );
+        let outcome = loop {
+            if let Some(outcome) = packer.poll_pack_job() {
+                break outcome;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        };
+
+        assert!(matches!(outcome, PackOutcome::Success { .. }));
+        assert!(packer.take_events().iter().any(|event| matches!(
+            event,
+            AppEvent::PackFinished { output_path: path } if *path == output_path
+        )));
+
+        packer.pack_job = Some(finished_job(failed_outcome()));
+        assert!(packer.poll_pack_job().is_some());
+        assert!(matches!(
+            packer.take_events().as_slice(),
+            [AppEvent::PackFailed { error }] if error == "boom"
+        ));
+    }
+
+    #[test]
+    fn failed_pack_keeps_workspace_when_requested() {
+        let mut packer = PackerState {
+            keep_temp_workspace_on_failure: true,
+            ..Default::default()
+        };
+        let workspace = tempdir().expect("create tempdir");
+        let workspace_path = workspace.path().to_path_buf();
+        packer.temp_workspace = Some(workspace);
+        packer.pack_job = Some(finished_job(failed_outcome()));
+
+        assert!(matches!(
+            packer.poll_pack_job(),
+            Some(PackOutcome::Error { .. })
+        ));
+        assert!(packer.temp_workspace.is_none());
+        assert_eq!(
+            packer.retained_temp_workspace.as_deref(),
+            Some(workspace_path.as_path())
+        );
+        assert!(workspace_path.is_dir());
+        fs::remove_dir_all(&workspace_path).expect("clean up retained workspace");
+    }
+
+    #[test]
+    fn workspace_is_removed_after_success_or_without_debug_setting() {
+        let mut packer = PackerState {
+            keep_temp_workspace_on_failure: true,
+            ..Default::default()
+        };
+        let workspace = tempdir().expect("create tempdir");
+        let workspace_path = workspace.path().to_path_buf();
+        packer.temp_workspace = Some(workspace);
+        packer.pack_job = Some(finished_job(PackOutcome::Success {
+            output_path: PathBuf::from("out.psu"),
+            saved_config: None,
+            read_only: None,
+        }));
+        assert!(packer.poll_pack_job().is_some());
+        assert!(packer.retained_temp_workspace.is_none());
+        assert!(!workspace_path.exists());
+
+        packer.keep_temp_workspace_on_failure = false;
+        let workspace = tempdir().expect("create tempdir");
+        let workspace_path = workspace.path().to_path_buf();
+        packer.temp_workspace = Some(workspace);
+        packer.pack_job = Some(finished_job(failed_outcome()));
+        assert!(packer.poll_pack_job().is_some());
+        assert!(packer.retained_temp_workspace.is_none());
+        assert!(!workspace_path.exists());
+    }
+
+    #[test]
+    fn exporting_a_missing_psu_reports_an_io_error() {
+        let workspace = tempdir().expect("create tempdir");
+        let source = workspace.path().join("missing.psu");
+
+        let err = PackerState::default()
+            .export_psu_to_folder(&source, workspace.path())
+            .expect_err("missing source must fail");
+
+        assert!(matches!(err, StateError::Io(_)));
+        assert_eq!(
+            err.to_string(),
+            format!("Cannot export because {} does not exist.", source.display())
+        );
+    }
 }
@@ -1,15 +1,14 @@
 use egui::{self, Color32, RichText};
 use psu_packer::{
     color_config_to_rgba, color_f_config_to_rgba, rgba_to_color_config, rgba_to_color_f_config,
-    sanitize_icon_sys_line, shift_jis_byte_length, ColorConfig, ColorFConfig, IconSysPreset,
-    VectorConfig, ICON_SYS_FLAG_OPTIONS, ICON_SYS_PRESETS, ICON_SYS_TITLE_CHAR_LIMIT,
+    sanitize_icon_sys_line, shift_jis_byte_length, split_icon_sys_title_with_limit, ColorConfig,
+    ColorFConfig, IconSysConfig, IconSysFlags, IconSysPreset, VectorConfig, ICON_SYS_FLAG_OPTIONS,
+    ICON_SYS_PRESETS,
 };
 
 pub mod state;
 pub use state::IconSysState;
 
-const TITLE_INPUT_WIDTH: f32 = (ICON_SYS_TITLE_CHAR_LIMIT as f32) * 9.0;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IconFlagSelection {
     Preset(usize),
@@ -19,6 +18,8 @@ pub enum IconFlagSelection {
 pub struct TitleSectionState<'a> {
     pub line1: &'a mut String,
     pub line2: &'a mut String,
+    /// Maximum characters per line, usually [`psu_packer::ICON_SYS_TITLE_CHAR_LIMIT`].
+    pub char_limit: usize,
 }
 
 pub struct TitleSectionIds {
@@ -46,29 +47,38 @@ pub fn title_editor(
         .spacing(egui::vec2(8.0, 4.0))
         .show(ui, |ui| {
             ui.label("Line 1");
-            if title_input(ui, ids.line1, &mut state.line1) {
+            if title_input(ui, ids.line1, state.line1, state.char_limit) {
                 changed = true;
             }
             ui.end_row();
 
             ui.label("Line 2");
-            if title_input(ui, ids.line2, &mut state.line2) {
+            if title_input(ui, ids.line2, state.line2, state.char_limit) {
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("");
+            if ui
+                .button("Swap lines")
+                .on_hover_text("Exchange line 1 and line 2. The line break moves with them.")
+                .clicked()
+                && swap_title_lines(&mut state)
+            {
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("");
+            if paste_title_lines(ui, &mut state) {
                 changed = true;
             }
             ui.end_row();
 
             ui.label("Preview");
             ui.vertical(|ui| {
-                ui.monospace(format!(
-                    "{:<width$}",
-                    state.line1,
-                    width = ICON_SYS_TITLE_CHAR_LIMIT
-                ));
-                ui.monospace(format!(
-                    "{:<width$}",
-                    state.line2,
-                    width = ICON_SYS_TITLE_CHAR_LIMIT
-                ));
+                ui.monospace(format!("{:<width$}", state.line1, width = state.char_limit));
+                ui.monospace(format!("{:<width$}", state.line2, width = state.char_limit));
 
                 match shift_jis_byte_length(&state.line1) {
                     Ok(break_pos) => {
@@ -94,16 +104,86 @@ pub fn title_editor(
     TitleSectionResponse { changed }
 }
 
-fn title_input(ui: &mut egui::Ui, id: egui::Id, value: &mut String) -> bool {
+/// Exchanges the two title lines. Returns `false` when both lines are identical.
+///
+/// The line break position is derived from the first line, so it follows the swap.
+pub fn swap_title_lines(state: &mut TitleSectionState<'_>) -> bool {
+    if state.line1 == state.line2 {
+        return false;
+    }
+    std::mem::swap(state.line1, state.line2);
+    true
+}
+
+/// A menu taking a pasted `"LINE1\nLINE2"` title. The draft text and any truncation warnings
+/// are kept in egui memory between frames.
+fn paste_title_lines(ui: &mut egui::Ui, state: &mut TitleSectionState<'_>) -> bool {
+    let id = ui.make_persistent_id("icon_sys_paste_title");
+    let warnings_id = id.with("warnings");
+    let mut changed = false;
+
+    ui.vertical(|ui| {
+        ui.menu_button("Paste two-line title…", |ui| {
+            let mut text = ui
+                .data(|data| data.get_temp::<String>(id))
+                .unwrap_or_default();
+            ui.add(
+                egui::TextEdit::multiline(&mut text)
+                    .hint_text("LINE1\nLINE2")
+                    .desired_rows(2)
+                    .desired_width(state.char_limit as f32 * 9.0),
+            );
+            if ui.button("Apply").clicked() {
+                let warnings = apply_pasted_title(state, &text);
+                changed = true;
+                ui.data_mut(|data| {
+                    data.remove::<String>(id);
+                    data.insert_temp(warnings_id, warnings);
+                });
+                ui.close_menu();
+            } else {
+                ui.data_mut(|data| data.insert_temp(id, text));
+            }
+        });
+
+        let warnings = ui
+            .data(|data| data.get_temp::<Vec<String>>(warnings_id))
+            .unwrap_or_default();
+        for warning in warnings {
+            ui.small(RichText::new(warning).color(Color32::YELLOW));
+        }
+    });
+
+    changed
+}
+
+/// Splits `text` at its first line break and stores the lines through
+/// [`IconSysConfig::set_title_from_lines_with_limit`] using the state's per-line limit.
+/// Returns the truncation warnings.
+pub fn apply_pasted_title(state: &mut TitleSectionState<'_>, text: &str) -> Vec<String> {
+    let (line1, line2) = text.split_once('\n').unwrap_or((text, ""));
+    let mut config = IconSysConfig::new(IconSysFlags::new(0), "", 0);
+    let warnings = config.set_title_from_lines_with_limit(line1, line2, state.char_limit);
+    let (line1, line2) = split_icon_sys_title_with_limit(
+        &config.title,
+        usize::from(config.linebreak_position()),
+        state.char_limit,
+    );
+    *state.line1 = line1;
+    *state.line2 = line2;
+    warnings
+}
+
+fn title_input(ui: &mut egui::Ui, id: egui::Id, value: &mut String, char_limit: usize) -> bool {
     let mut edit = egui::TextEdit::singleline(value)
-        .char_limit(ICON_SYS_TITLE_CHAR_LIMIT)
-        .desired_width(TITLE_INPUT_WIDTH);
+        .char_limit(char_limit)
+        .desired_width(char_limit as f32 * 9.0);
     edit = edit.id_source(id);
 
     let response = ui.add(edit);
     let mut changed = false;
     if response.changed() {
-        let sanitized = sanitize_icon_sys_line(value, ICON_SYS_TITLE_CHAR_LIMIT);
+        let sanitized = sanitize_icon_sys_line(value, char_limit);
         if *value != sanitized {
             *value = sanitized;
         }
@@ -112,7 +192,7 @@ fn title_input(ui: &mut egui::Ui, id: egui::Id, value: &mut String) -> bool {
 
     let char_count = value.chars().count();
     ui.small(format!(
-        "{char_count} / {ICON_SYS_TITLE_CHAR_LIMIT} characters (Shift-JIS compatible)"
+        "{char_count} / {char_limit} characters (Shift-JIS compatible)"
     ));
     changed
 }
@@ -201,6 +281,8 @@ pub struct PresetSectionState<'a> {
 
 pub struct PresetPreviewData<'a> {
     pub background_colors: &'a [ColorConfig; 4],
+    /// The icon.sys background transparency, where higher values are more see-through.
+    pub background_transparency: u32,
     pub light_colors: &'a [ColorFConfig; 3],
     pub ambient_color: &'a ColorFConfig,
 }
@@ -208,6 +290,10 @@ pub struct PresetPreviewData<'a> {
 pub enum PresetSelection<'a> {
     Manual,
     Preset(&'a IconSysPreset),
+    /// The user asked to load a preset from a JSON file.
+    Import,
+    /// The user asked to save the current colors and lights as a JSON preset.
+    Export,
 }
 
 pub struct PresetSectionResponse<'a> {
@@ -255,6 +341,23 @@ pub fn preset_selector<'a>(
             }
         });
 
+    ui.horizontal(|ui| {
+        if ui
+            .button("Import preset…")
+            .on_hover_text("Load colors and lights from a preset JSON file.")
+            .clicked()
+        {
+            selection = Some(PresetSelection::Import);
+        }
+        if ui
+            .button("Export preset…")
+            .on_hover_text("Save the current colors and lights as a preset JSON file.")
+            .clicked()
+        {
+            selection = Some(PresetSelection::Export);
+        }
+    });
+
     ui.add_space(6.0);
     preset_preview(ui, preview);
 
@@ -262,12 +365,15 @@ pub fn preset_selector<'a>(
 }
 
 fn preset_preview(ui: &mut egui::Ui, preview: PresetPreviewData<'_>) {
+    let gamma = gamma_preview_enabled(ui);
     ui.vertical(|ui| {
         ui.label("Background gradient");
         ui.horizontal(|ui| {
             for color in preview.background_colors {
-                let rgba = color_config_to_rgba(*color);
-                draw_color_swatch(ui, color32_from_rgba_u8(rgba));
+                draw_color_swatch(
+                    ui,
+                    background_swatch_color(*color, preview.background_transparency),
+                );
             }
         });
 
@@ -275,16 +381,29 @@ fn preset_preview(ui: &mut egui::Ui, preview: PresetPreviewData<'_>) {
         ui.horizontal(|ui| {
             for color in preview.light_colors {
                 let rgba = color_f_config_to_rgba(*color);
-                draw_color_swatch(ui, color32_from_rgba_f32(rgba));
+                draw_color_swatch(ui, color32_from_rgba_f32(rgba, gamma));
             }
         });
 
         ui.label("Ambient");
         let ambient = color_f_config_to_rgba(*preview.ambient_color);
-        draw_color_swatch(ui, color32_from_rgba_f32(ambient));
+        draw_color_swatch(ui, color32_from_rgba_f32(ambient, gamma));
     });
 }
 
+/// The console blends the background using the transparency value rather than each color's
+/// own alpha, so the swatch takes its alpha from the inverted transparency.
+fn background_swatch_color(color: ColorConfig, transparency: u32) -> Color32 {
+    let mut rgba = color_config_to_rgba(color);
+    rgba[3] = background_alpha(transparency);
+    color32_from_rgba_u8(rgba)
+}
+
+/// Maps the 0–255 transparency, where higher is more see-through, to an opacity.
+fn background_alpha(transparency: u32) -> u8 {
+    255 - transparency.min(255) as u8
+}
+
 fn draw_color_swatch(ui: &mut egui::Ui, color: Color32) {
     let (rect, _) = ui.allocate_exact_size(egui::vec2(20.0, 14.0), egui::Sense::hover());
     ui.painter().rect_filled(rect, 3.0, color);
@@ -312,7 +431,7 @@ pub fn background_editor(ui: &mut egui::Ui, state: BackgroundSectionState<'_>) -
 
     let mut background_changed = false;
     egui::Grid::new("icon_sys_background_grid")
-        .num_columns(2)
+        .num_columns(3)
         .spacing(egui::vec2(8.0, 4.0))
         .show(ui, |ui| {
             for (index, color) in state.colors.iter_mut().enumerate() {
@@ -324,6 +443,10 @@ pub fn background_editor(ui: &mut egui::Ui, state: BackgroundSectionState<'_>) -
                     *color = rgba_to_color_config(updated);
                     background_changed = true;
                 }
+                let hex_id = ui.make_persistent_id(("icon_sys_background_hex", index));
+                if hex_color_input(ui, hex_id, color) {
+                    background_changed = true;
+                }
                 ui.end_row();
             }
         });
@@ -334,15 +457,94 @@ pub fn background_editor(ui: &mut egui::Ui, state: BackgroundSectionState<'_>) -
     SectionResponse { changed }
 }
 
+/// Text field accepting `#RRGGBB`/`#RRGGBBAA`. The draft text is kept in egui memory while
+/// the field has focus so partial input is not overwritten by the current color.
+fn hex_color_input(ui: &mut egui::Ui, id: egui::Id, color: &mut ColorConfig) -> bool {
+    let mut text = ui
+        .data(|data| data.get_temp::<String>(id))
+        .unwrap_or_else(|| color.to_hex());
+    let draft_invalid = ColorConfig::from_hex(&text).is_err();
+    let mut edit = egui::TextEdit::singleline(&mut text)
+        .id(id.with("edit"))
+        .desired_width(80.0)
+        .font(egui::TextStyle::Monospace);
+    if draft_invalid {
+        edit = edit.text_color(Color32::LIGHT_RED);
+    }
+    let response = ui.add(edit);
+
+    let mut changed = false;
+    let parsed = ColorConfig::from_hex(&text);
+    if response.changed() {
+        if let Ok(value) = parsed {
+            if value != *color {
+                *color = value;
+                changed = true;
+            }
+        }
+    }
+
+    let focused = response.has_focus();
+    if let Err(message) = &parsed {
+        response.on_hover_text(message.as_str());
+    }
+    if focused {
+        ui.data_mut(|data| data.insert_temp(id, text));
+    } else {
+        ui.data_mut(|data| data.remove::<String>(id));
+    }
+
+    changed
+}
+
 pub struct LightingSectionState<'a> {
     pub light_colors: &'a mut [ColorFConfig; 3],
     pub light_directions: &'a mut [VectorConfig; 3],
     pub ambient_color: &'a mut ColorFConfig,
 }
 
-pub fn lighting_editor(ui: &mut egui::Ui, state: LightingSectionState<'_>) -> SectionResponse {
+impl LightingSectionState<'_> {
+    /// Restores the default light colors, directions, and ambient color. The background is
+    /// left untouched.
+    pub fn reset_to_defaults(&mut self) -> SectionResponse {
+        let light_colors = IconSysConfig::default_light_colors();
+        let light_directions = IconSysConfig::default_light_directions();
+        let ambient_color = IconSysConfig::default_ambient_color();
+        let changed = *self.light_colors != light_colors
+            || *self.light_directions != light_directions
+            || *self.ambient_color != ambient_color;
+
+        *self.light_colors = light_colors;
+        *self.light_directions = light_directions;
+        *self.ambient_color = ambient_color;
+        SectionResponse { changed }
+    }
+}
+
+pub fn lighting_editor(ui: &mut egui::Ui, mut state: LightingSectionState<'_>) -> SectionResponse {
     let mut changed = false;
 
+    if ui
+        .small_button("Reset lighting")
+        .on_hover_text("Restore the default light colors, directions, and ambient color.")
+        .clicked()
+        && state.reset_to_defaults().changed
+    {
+        changed = true;
+    }
+
+    let mut gamma = gamma_preview_enabled(ui);
+    if ui
+        .checkbox(&mut gamma, "Gamma-corrected preview")
+        .on_hover_text(
+            "Show the light swatches sRGB-encoded, closer to how they appear on hardware. \
+             This only affects the preview; the stored values are unchanged.",
+        )
+        .changed()
+    {
+        ui.data_mut(|data| data.insert_temp(gamma_preview_id(), gamma));
+    }
+
     for (index, (color, direction)) in state
         .light_colors
         .iter_mut()
@@ -353,7 +555,7 @@ pub fn lighting_editor(ui: &mut egui::Ui, state: LightingSectionState<'_>) -> Se
         ui.collapsing(format!("Light {}", index + 1), |ui| {
             ui.label("Color");
             let mut rgba = color_f_config_to_rgba(*color);
-            if ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed() {
+            if light_color_edit_button(ui, &mut rgba, gamma).changed() {
                 *color = rgba_to_color_f_config(rgba);
                 light_dirty = true;
             }
@@ -380,6 +582,21 @@ pub fn lighting_editor(ui: &mut egui::Ui, state: LightingSectionState<'_>) -> Se
                     }
                 });
             }
+            if !direction.is_normalized() {
+                ui.horizontal(|ui| {
+                    ui.colored_label(
+                        Color32::YELLOW,
+                        format!(
+                            "Length is {:.2}; directions should have length 1.",
+                            direction.magnitude()
+                        ),
+                    );
+                    if ui.small_button("Normalize").clicked() {
+                        direction.normalize();
+                        light_dirty = true;
+                    }
+                });
+            }
         });
         if light_dirty {
             changed = true;
@@ -389,10 +606,7 @@ pub fn lighting_editor(ui: &mut egui::Ui, state: LightingSectionState<'_>) -> Se
 
     ui.label("Ambient color");
     let mut ambient = color_f_config_to_rgba(*state.ambient_color);
-    if ui
-        .color_edit_button_rgba_unmultiplied(&mut ambient)
-        .changed()
-    {
+    if light_color_edit_button(ui, &mut ambient, gamma).changed() {
         *state.ambient_color = rgba_to_color_f_config(ambient);
         changed = true;
     }
@@ -400,6 +614,22 @@ pub fn lighting_editor(ui: &mut egui::Ui, state: LightingSectionState<'_>) -> Se
     SectionResponse { changed }
 }
 
+/// A color button for a float light color whose swatch follows the gamma preview toggle, so it
+/// matches the preset preview. egui shows float colors sRGB-encoded, so the linear preview
+/// edits the channels as bytes instead.
+fn light_color_edit_button(ui: &mut egui::Ui, rgba: &mut [f32; 4], gamma: bool) -> egui::Response {
+    if gamma {
+        return ui.color_edit_button_rgba_unmultiplied(rgba);
+    }
+
+    let mut srgba = color32_from_rgba_f32(*rgba, false).to_srgba_unmultiplied();
+    let response = ui.color_edit_button_srgba_unmultiplied(&mut srgba);
+    if response.changed() {
+        *rgba = srgba.map(|channel| channel as f32 / 255.0);
+    }
+    response
+}
+
 fn find_preset(id: &str) -> Option<&'static IconSysPreset> {
     ICON_SYS_PRESETS.iter().find(|preset| preset.id == id)
 }
@@ -408,19 +638,47 @@ fn color32_from_rgba_u8(rgba: [u8; 4]) -> Color32 {
     Color32::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
 }
 
-fn color32_from_rgba_f32(rgba: [f32; 4]) -> Color32 {
+fn gamma_preview_id() -> egui::Id {
+    egui::Id::new("icon_sys_gamma_preview")
+}
+
+/// Whether the lighting swatches are shown gamma-corrected. Linear is the default.
+fn gamma_preview_enabled(ui: &egui::Ui) -> bool {
+    ui.data(|data| data.get_temp::<bool>(gamma_preview_id()))
+        .unwrap_or(false)
+}
+
+/// Converts a float color to a swatch color. With `gamma` set, the color channels are
+/// sRGB-encoded first; alpha always stays linear.
+fn color32_from_rgba_f32(rgba: [f32; 4], gamma: bool) -> Color32 {
     let clamp = |value: f32| -> u8 { (value.clamp(0.0, 1.0) * 255.0).round() as u8 };
+    let channel = |value: f32| -> u8 {
+        if gamma {
+            clamp(srgb_encode(value.clamp(0.0, 1.0)))
+        } else {
+            clamp(value)
+        }
+    };
     Color32::from_rgba_unmultiplied(
-        clamp(rgba[0]),
-        clamp(rgba[1]),
-        clamp(rgba[2]),
+        channel(rgba[0]),
+        channel(rgba[1]),
+        channel(rgba[2]),
         clamp(rgba[3]),
     )
 }
 
+fn srgb_encode(linear: f32) -> f32 {
+    if linear <= 0.003_130_8 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use psu_packer::ICON_SYS_TITLE_CHAR_LIMIT;
 
     #[test]
     fn title_editor_renders() {
@@ -436,6 +694,7 @@ mod tests {
             let state = TitleSectionState {
                 line1: &mut line1,
                 line2: &mut line2,
+                char_limit: ICON_SYS_TITLE_CHAR_LIMIT,
             };
             let response = title_editor(ui, ids, state);
             assert!(!response.changed);
@@ -443,6 +702,29 @@ mod tests {
         ctx.end_frame();
     }
 
+    #[test]
+    fn swap_title_lines_exchanges_lines_and_moves_break() {
+        let mut line1 = "セーブ".to_string();
+        let mut line2 = "DATA".to_string();
+        let mut state = TitleSectionState {
+            line1: &mut line1,
+            line2: &mut line2,
+            char_limit: ICON_SYS_TITLE_CHAR_LIMIT,
+        };
+        assert!(swap_title_lines(&mut state));
+        assert_eq!(line1, "DATA");
+        assert_eq!(line2, "セーブ");
+        assert_eq!(shift_jis_byte_length(&line1).unwrap(), 4);
+
+        let mut same1 = "SAME".to_string();
+        let mut same2 = "SAME".to_string();
+        assert!(!swap_title_lines(&mut TitleSectionState {
+            line1: &mut same1,
+            line2: &mut same2,
+            char_limit: ICON_SYS_TITLE_CHAR_LIMIT,
+        }));
+    }
+
     #[test]
     fn flag_selector_renders() {
         let ctx = egui::Context::default();
@@ -478,6 +760,7 @@ mod tests {
                 },
                 PresetPreviewData {
                     background_colors: &background,
+                    background_transparency: 128,
                     light_colors: &lights,
                     ambient_color: &ambient,
                 },
@@ -487,6 +770,68 @@ mod tests {
         ctx.end_frame();
     }
 
+    #[test]
+    fn background_swatch_alpha_inverts_transparency() {
+        let color = ColorConfig {
+            r: 200,
+            g: 100,
+            b: 50,
+            a: 0,
+        };
+
+        assert_eq!(background_swatch_color(color, 0).a(), 255);
+        assert_eq!(background_swatch_color(color, 128).a(), 127);
+        assert_eq!(background_swatch_color(color, 255).a(), 0);
+        assert_eq!(background_swatch_color(color, 1000).a(), 0);
+    }
+
+    #[test]
+    fn apply_pasted_title_splits_at_the_newline() {
+        let mut line1 = String::new();
+        let mut line2 = String::new();
+        let mut state = TitleSectionState {
+            line1: &mut line1,
+            line2: &mut line2,
+            char_limit: ICON_SYS_TITLE_CHAR_LIMIT,
+        };
+        let warnings = apply_pasted_title(&mut state, "セーブ\r\nDATA");
+        assert!(warnings.is_empty());
+        assert_eq!(line1, "セーブ");
+        assert_eq!(line2, "DATA");
+    }
+
+    #[test]
+    fn apply_pasted_title_uses_the_state_char_limit() {
+        let pasted = format!("{}\nDATA", "A".repeat(20));
+        for (limit, expected_line1, expected_warnings) in
+            [(24, "A".repeat(20), 0), (8, "A".repeat(8), 1)]
+        {
+            let mut line1 = String::new();
+            let mut line2 = String::new();
+            let mut state = TitleSectionState {
+                line1: &mut line1,
+                line2: &mut line2,
+                char_limit: limit,
+            };
+            let warnings = apply_pasted_title(&mut state, &pasted);
+            assert_eq!(warnings.len(), expected_warnings);
+            assert_eq!(line1, expected_line1);
+            assert_eq!(line2, "DATA");
+        }
+    }
+
+    #[test]
+    fn gamma_preview_brightens_mid_gray() {
+        let gray = [0.5, 0.5, 0.5, 1.0];
+        let linear = color32_from_rgba_f32(gray, false);
+        let gamma = color32_from_rgba_f32(gray, true);
+
+        assert_eq!(linear.r(), 128);
+        assert!(gamma.r() > linear.r());
+        assert_eq!(gamma.r(), gamma.g());
+        assert_eq!(gamma.a(), linear.a());
+    }
+
     #[test]
     fn background_editor_renders() {
         let ctx = egui::Context::default();
@@ -506,6 +851,33 @@ mod tests {
         ctx.end_frame();
     }
 
+    #[test]
+    fn reset_lighting_restores_defaults() {
+        let mut light_colors = psu_packer::IconSysConfig::default_light_colors();
+        let mut light_directions = psu_packer::IconSysConfig::default_light_directions();
+        let mut ambient = psu_packer::IconSysConfig::default_ambient_color();
+        light_directions[1].x = -0.25;
+        ambient.r = 0.9;
+
+        let mut state = LightingSectionState {
+            light_colors: &mut light_colors,
+            light_directions: &mut light_directions,
+            ambient_color: &mut ambient,
+        };
+        assert!(state.reset_to_defaults().changed);
+        assert!(!state.reset_to_defaults().changed);
+
+        assert_eq!(
+            light_directions,
+            psu_packer::IconSysConfig::default_light_directions()
+        );
+        assert_eq!(
+            light_colors,
+            psu_packer::IconSysConfig::default_light_colors()
+        );
+        assert_eq!(ambient, psu_packer::IconSysConfig::default_ambient_color());
+    }
+
     #[test]
     fn lighting_editor_renders() {
         let ctx = egui::Context::default();
@@ -1,8 +1,8 @@
 use crate::IconFlagSelection;
 use ps2_filetypes::IconSys;
 use psu_packer::{
-    ColorConfig, ColorFConfig, IconSysConfig, IconSysPreset, VectorConfig, ICON_SYS_FLAG_OPTIONS,
-    ICON_SYS_PRESETS,
+    ColorConfig, ColorFConfig, IconSysConfig, IconSysPreset, IconSysPresetFile, VectorConfig,
+    ICON_SYS_FLAG_OPTIONS, ICON_SYS_PRESETS,
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -60,6 +60,28 @@ impl IconSysState {
         self.selected_preset = Some(preset.id.to_string());
     }
 
+    /// Captures the current colors and lights as a named, shareable preset.
+    pub fn to_preset_file(&self, name: &str) -> IconSysPresetFile {
+        IconSysPresetFile {
+            name: name.to_string(),
+            background_transparency: self.background_transparency,
+            background_colors: self.background_colors,
+            light_directions: self.light_directions,
+            light_colors: self.light_colors,
+            ambient_color: self.ambient_color,
+        }
+    }
+
+    /// Applies an imported preset. Matches against the built-in presets are still detected.
+    pub fn apply_preset_file(&mut self, preset: &IconSysPresetFile) {
+        self.background_transparency = preset.background_transparency;
+        self.background_colors = preset.background_colors;
+        self.light_directions = preset.light_directions;
+        self.light_colors = preset.light_colors;
+        self.ambient_color = preset.ambient_color;
+        self.update_detected_preset();
+    }
+
     pub fn clear_preset(&mut self) {
         self.selected_preset = None;
     }
@@ -110,6 +132,18 @@ impl IconSysState {
         self.ambient_color = ambient_color_from_icon_sys(icon_sys);
         self.clear_preset();
     }
+
+    /// Whether the flags, background, lights or ambient color differ from `existing`. The
+    /// title is edited outside this state, so callers compare it themselves.
+    pub fn differs_from(&self, existing: &IconSys) -> bool {
+        let flags = crate::selected_icon_flag_value(self.flag_selection, self.custom_flag);
+        flags != Ok(existing.flags)
+            || self.background_transparency != existing.background_transparency
+            || self.background_colors != background_colors_from_icon_sys(existing)
+            || self.light_directions != light_directions_from_icon_sys(existing)
+            || self.light_colors != light_colors_from_icon_sys(existing)
+            || self.ambient_color != ambient_color_from_icon_sys(existing)
+    }
 }
 
 fn background_colors_from_icon_sys(icon_sys: &IconSys) -> [ColorConfig; 4] {
@@ -173,6 +207,82 @@ mod tests {
         assert_eq!(state.detect_preset(), Some(preset.id.to_string()));
     }
 
+    #[test]
+    fn preset_file_round_trips_colors_through_json() {
+        let mut state = IconSysState {
+            background_transparency: 12,
+            ..IconSysState::default()
+        };
+        state.background_colors[2] = ColorConfig {
+            r: 10,
+            g: 20,
+            b: 30,
+            a: 40,
+        };
+        state.light_colors[1] = ColorFConfig {
+            r: 0.25,
+            g: 0.5,
+            b: 0.75,
+            a: 1.0,
+        };
+        state.light_directions[0] = VectorConfig {
+            x: -0.5,
+            y: 0.5,
+            z: 0.25,
+            w: 0.0,
+        };
+        state.ambient_color = ColorFConfig {
+            r: 0.1,
+            g: 0.2,
+            b: 0.3,
+            a: 0.4,
+        };
+
+        let json = state
+            .to_preset_file("Sunset")
+            .to_json()
+            .expect("serialize preset");
+        let preset = IconSysPresetFile::from_json(&json).expect("parse preset");
+        assert_eq!(preset.name, "Sunset");
+
+        let mut imported = IconSysState::default();
+        imported.apply_preset_file(&preset);
+        assert_eq!(imported.background_transparency, 12);
+        assert_eq!(imported.background_colors, state.background_colors);
+        assert_eq!(imported.light_directions, state.light_directions);
+        assert_eq!(imported.light_colors, state.light_colors);
+        assert_eq!(imported.ambient_color, state.ambient_color);
+        assert_eq!(imported.selected_preset, None);
+    }
+
+    #[test]
+    fn imported_preset_matching_builtin_is_detected() {
+        let preset = IconSysPresetFile::from(&ICON_SYS_PRESETS[1]);
+        let mut state = IconSysState::default();
+        state.apply_preset_file(&preset);
+        assert_eq!(
+            state.selected_preset.as_deref(),
+            Some(ICON_SYS_PRESETS[1].id)
+        );
+    }
+
+    #[test]
+    fn differs_from_detects_ambient_change() {
+        let existing = IconSysConfig::new(
+            psu_packer::IconSysFlags::new(ICON_SYS_FLAG_OPTIONS[1].0),
+            "Title",
+            0,
+        )
+        .build_icon_sys()
+        .expect("build icon.sys");
+
+        let mut state = IconSysState::from_icon_sys(&existing);
+        assert!(!state.differs_from(&existing));
+
+        state.ambient_color.b += 0.25;
+        assert!(state.differs_from(&existing));
+    }
+
     #[test]
     fn apply_icon_sys_populates_fields() {
         let icon_sys = IconSys {
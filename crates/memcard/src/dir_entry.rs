@@ -12,14 +12,14 @@ pub const DF_0400: u16 = 0x0400;
 pub const DF_EXISTS: u16 = 0x8000;
 pub const DF_HIDDEN: u16 = 0x2000;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct DateTime {
-    seconds: u8,
-    minutes: u8,
-    hours: u8,
-    days: u8,
-    months: u8,
-    years: u16,
+    pub seconds: u8,
+    pub minutes: u8,
+    pub hours: u8,
+    pub days: u8,
+    pub months: u8,
+    pub years: u16,
 }
 
 impl DateTime {
@@ -49,7 +49,7 @@ pub struct DirEntry {
     pub(crate) created: DateTime,
     pub cluster: u32,
     dir_entry: u32,
-    modified: DateTime,
+    pub(crate) modified: DateTime,
     attributes: u32,
     pub(crate) name: [u8; 32],
 }
@@ -92,6 +92,15 @@ impl DirEntry {
         self.name[0] == 0x00
     }
 
+    pub fn exists(&self) -> bool {
+        self.mode & DF_EXISTS != 0
+    }
+
+    /// True for the `.` and `..` entries every directory starts with.
+    pub fn is_dot_entry(&self) -> bool {
+        matches!(self.name_as_string().as_str(), "." | "..")
+    }
+
     pub fn is_deleted(&self) -> bool {
         self.name[0] == 0xE5
     }
@@ -1,7 +1,12 @@
-use crate::dir_entry::DirEntry;
+use crate::dir_entry::{
+    DateTime, DirEntry, DF_0400, DF_DIRECTORY, DF_EXECUTE, DF_EXISTS, DF_HIDDEN, DF_READ, DF_WRITE,
+};
 use byteorder::{ReadBytesExt, LE};
 use std::cmp::min;
-use std::io::{Cursor, Read, Seek};
+use std::fmt;
+use std::fs;
+use std::io::{self, Cursor, Read, Seek};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub struct Superblock {
@@ -68,6 +73,198 @@ fn read_superblock(c: &mut Cursor<Vec<u8>>) -> std::io::Result<Superblock> {
     })
 }
 
+/// Memory card capacities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McSize {
+    Mb8,
+    Mb16,
+    Mb32,
+    Mb64,
+}
+
+// Layout of a freshly formatted 8 MB card.
+const FORMAT_PAGE_SIZE: usize = 512;
+const FORMAT_RAW_PAGE_SIZE: usize = FORMAT_PAGE_SIZE + 16;
+const FORMAT_PAGES_PER_CLUSTER: usize = 2;
+const FORMAT_PAGES_PER_BLOCK: usize = 16;
+const FORMAT_CLUSTERS_PER_CARD: u32 = 8192;
+const FORMAT_IFC_CLUSTER: u32 = 8;
+const FORMAT_FAT_CLUSTERS: u32 = 32;
+const FORMAT_ALLOC_OFFSET: u32 = FORMAT_IFC_CLUSTER + 1 + FORMAT_FAT_CLUSTERS;
+/// Two blocks at the end of the card are kept back for the backup blocks.
+const FORMAT_ALLOC_END: u32 = FORMAT_CLUSTERS_PER_CARD
+    - FORMAT_ALLOC_OFFSET
+    - 2 * (FORMAT_PAGES_PER_BLOCK / FORMAT_PAGES_PER_CLUSTER) as u32;
+
+/// Builds the image of a blank 8 MB card: a superblock, a FAT in which only the root
+/// directory's cluster is allocated, and a root directory holding just `.` and `..`. Every
+/// page's spare area carries its ECC, as on a card formatted by the console.
+fn blank_image() -> Vec<u8> {
+    let blocks =
+        FORMAT_CLUSTERS_PER_CARD as usize * FORMAT_PAGES_PER_CLUSTER / FORMAT_PAGES_PER_BLOCK;
+    let mut image =
+        vec![
+            0u8;
+            FORMAT_CLUSTERS_PER_CARD as usize * FORMAT_PAGES_PER_CLUSTER * FORMAT_RAW_PAGE_SIZE
+        ];
+    let put_u16 = |image: &mut Vec<u8>, offset: usize, value: u16| {
+        image[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+    };
+    let put_u32 = |image: &mut Vec<u8>, offset: usize, value: u32| {
+        image[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    };
+    // Offset of the `index`th byte of `cluster`, skipping the spare area after each page.
+    let cluster_offset = |cluster: u32, index: usize| {
+        let page = cluster as usize * FORMAT_PAGES_PER_CLUSTER + index / FORMAT_PAGE_SIZE;
+        page * FORMAT_RAW_PAGE_SIZE + index % FORMAT_PAGE_SIZE
+    };
+
+    image[..28].copy_from_slice(b"Sony PS2 Memory Card Format ");
+    image[28..35].copy_from_slice(b"1.2.0.0");
+    put_u16(&mut image, 40, FORMAT_PAGE_SIZE as u16);
+    put_u16(&mut image, 42, FORMAT_PAGES_PER_CLUSTER as u16);
+    put_u16(&mut image, 44, FORMAT_PAGES_PER_BLOCK as u16);
+    put_u16(&mut image, 46, 0xFF00);
+    put_u32(&mut image, 48, FORMAT_CLUSTERS_PER_CARD);
+    put_u32(&mut image, 52, FORMAT_ALLOC_OFFSET);
+    put_u32(&mut image, 56, FORMAT_ALLOC_END);
+    put_u32(&mut image, 60, 0);
+    put_u32(&mut image, 64, blocks as u32 - 1);
+    put_u32(&mut image, 68, blocks as u32 - 2);
+    for slot in 0..32 {
+        let ifc = if slot == 0 {
+            FORMAT_IFC_CLUSTER
+        } else {
+            0xFFFFFFFF
+        };
+        put_u32(&mut image, 80 + slot * 4, ifc);
+        put_u32(&mut image, 208 + slot * 4, 0xFFFFFFFF);
+    }
+    image[336] = 2; // PS2 memory card
+    image[337] = 0x52;
+
+    let entries_per_cluster = FORMAT_PAGE_SIZE * FORMAT_PAGES_PER_CLUSTER / 4;
+    for index in 0..entries_per_cluster {
+        let fat_cluster = FORMAT_IFC_CLUSTER + 1 + index as u32;
+        let value = if index < FORMAT_FAT_CLUSTERS as usize {
+            fat_cluster
+        } else {
+            0xFFFFFFFF
+        };
+        put_u32(
+            &mut image,
+            cluster_offset(FORMAT_IFC_CLUSTER, index * 4),
+            value,
+        );
+    }
+
+    for cluster in 0..FORMAT_FAT_CLUSTERS as usize * entries_per_cluster {
+        // The root directory in cluster 0 is the only allocated chain; everything else is free.
+        let value = if cluster == 0 { 0xFFFFFFFF } else { 0x7FFFFFFF };
+        let fat_cluster = FORMAT_IFC_CLUSTER + 1 + (cluster / entries_per_cluster) as u32;
+        put_u32(
+            &mut image,
+            cluster_offset(fat_cluster, cluster % entries_per_cluster * 4),
+            value,
+        );
+    }
+
+    let dir_mode = DF_WRITE | DF_EXECUTE | DF_DIRECTORY | DF_0400 | DF_EXISTS;
+    for (index, (mode, length, name)) in [
+        (dir_mode | DF_READ, 2, "."),
+        (dir_mode | DF_HIDDEN, 0, ".."),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let offset = cluster_offset(FORMAT_ALLOC_OFFSET, index * 512);
+        put_u16(&mut image, offset, mode);
+        put_u32(&mut image, offset + 4, length);
+        image[offset + 64..offset + 64 + name.len()].copy_from_slice(name.as_bytes());
+    }
+
+    for page in image.chunks_exact_mut(FORMAT_RAW_PAGE_SIZE) {
+        let (data, spare) = page.split_at_mut(FORMAT_PAGE_SIZE);
+        for (chunk, ecc) in data.chunks_exact(128).zip(spare.chunks_exact_mut(3)) {
+            ecc.copy_from_slice(&chunk_ecc(chunk));
+        }
+    }
+
+    image
+}
+
+/// The three-byte Hamming code stored in the spare area for each 128-byte chunk of a page:
+/// a column parity byte followed by two line parity bytes.
+fn chunk_ecc(chunk: &[u8]) -> [u8; 3] {
+    const COLUMN_MASKS: [u8; 7] = [0x55, 0x33, 0x0F, 0x00, 0xAA, 0xCC, 0xF0];
+
+    let mut column_parity = 0x77u8;
+    let mut line_parity_0 = 0x7Fu8;
+    let mut line_parity_1 = 0x7Fu8;
+    for (index, &byte) in chunk.iter().enumerate() {
+        for (bit, mask) in COLUMN_MASKS.into_iter().enumerate() {
+            column_parity ^= ((byte & mask).count_ones() as u8 & 1) << bit;
+        }
+        if byte.count_ones() % 2 == 1 {
+            line_parity_0 ^= !(index as u8);
+            line_parity_1 ^= index as u8;
+        }
+    }
+
+    [column_parity, line_parity_0 & 0x7F, line_parity_1]
+}
+
+/// A top-level save directory on a memory card, as reported by [`Memcard::list_saves`].
+#[derive(Debug, Clone)]
+pub struct SaveEntry {
+    pub name: String,
+    pub created: DateTime,
+    pub modified: DateTime,
+    /// Number of entries in the directory, not counting `.` and `..`.
+    pub file_count: usize,
+}
+
+/// A structural problem in the allocation table, as reported by [`Memcard::check_integrity`].
+/// Clusters are numbered from the start of the allocatable area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FatError {
+    /// Following the chain from the first listed cluster leads back to it.
+    Cycle { clusters: Vec<u32> },
+    /// More than one allocated cluster points at `cluster`.
+    CrossLinked { cluster: u32, from: Vec<u32> },
+    /// `cluster` points at `next`, which lies outside the allocatable area.
+    OutOfRange { cluster: u32, next: u32 },
+}
+
+impl fmt::Display for FatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FatError::Cycle { clusters } => {
+                let chain = clusters
+                    .iter()
+                    .chain(clusters.first())
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                write!(f, "Cluster chain loops: {chain}")
+            }
+            FatError::CrossLinked { cluster, from } => {
+                let from = from
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "Cluster {cluster} is linked from clusters {from}")
+            }
+            FatError::OutOfRange { cluster, next } => {
+                write!(f, "Cluster {cluster} points at {next}, outside the card")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FatError {}
+
 pub struct Memcard {
     c: Cursor<Vec<u8>>,
     page_size: usize,
@@ -75,6 +272,7 @@ pub struct Memcard {
     ifc_list: [u32; 32],
     pub(crate) rootdir_cluster: usize,
     alloc_offset: usize,
+    alloc_end: usize,
     spare_size: usize,
     raw_page_size: usize,
     cluster_size: usize,
@@ -94,6 +292,7 @@ impl Memcard {
         let ifc_list: [u32; 32] = sb.ifc_list;
         let rootdir_cluster = sb.rootdir_cluster as usize;
         let alloc_offset = sb.alloc_offset as usize;
+        let alloc_end = sb.alloc_end as usize;
         let spare_size = (page_size / 128) * 4;
         let raw_page_size = page_size + spare_size;
         let cluster_size = page_size * pages_per_cluster;
@@ -106,6 +305,7 @@ impl Memcard {
             ifc_list,
             rootdir_cluster,
             alloc_offset,
+            alloc_end,
             spare_size,
             raw_page_size,
             cluster_size,
@@ -120,10 +320,296 @@ impl Memcard {
         mc
     }
 
+    /// Writes a blank, formatted card image to `path` and opens it. Only 8 MB cards are
+    /// supported.
+    pub fn format_new(path: &Path, size: McSize) -> io::Result<Memcard> {
+        if size != McSize::Mb8 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("Formatting {size:?} cards is not supported; only 8 MB cards are"),
+            ));
+        }
+
+        let image = blank_image();
+        fs::write(path, &image)?;
+        Ok(Memcard::new(image))
+    }
+
     pub fn rootdir_cluster(&self) -> u32 {
         self.rootdir_cluster as u32
     }
 
+    /// Bytes left in clusters the FAT marks as free.
+    pub fn free_bytes(&self) -> u64 {
+        let free_clusters = self.alloc_end - self.allocated_clusters();
+        free_clusters as u64 * self.cluster_size as u64
+    }
+
+    /// Bytes held by clusters the FAT marks as allocated, apart from the root directory's,
+    /// so a blank card reports zero.
+    pub fn used_bytes(&self) -> u64 {
+        let used_clusters = self
+            .allocated_clusters()
+            .saturating_sub(self.root_directory_clusters());
+        used_clusters as u64 * self.cluster_size as u64
+    }
+
+    /// Counts the allocated clusters in the root directory's chain.
+    fn root_directory_clusters(&self) -> usize {
+        const END_OF_CHAIN: u32 = 0x7FFFFFFF;
+
+        let mut count = 0;
+        let mut cluster = self.rootdir_cluster;
+        while cluster < self.alloc_end && count < self.alloc_end {
+            let Some(value) = self.fat_entry(cluster) else {
+                break;
+            };
+            if value & 0x80000000 == 0 {
+                break;
+            }
+            count += 1;
+            if value & END_OF_CHAIN == END_OF_CHAIN {
+                break;
+            }
+            cluster = (value & END_OF_CHAIN) as usize;
+        }
+        count
+    }
+
+    /// Counts the allocatable clusters whose FAT entry has the in-use bit set.
+    ///
+    /// Entries missing from a truncated FAT are counted as allocated so the free space is never
+    /// overstated.
+    fn allocated_clusters(&self) -> usize {
+        (0..self.alloc_end)
+            .filter(|&n| {
+                self.fat_entry(n)
+                    .is_none_or(|value| value & 0x80000000 != 0)
+            })
+            .count()
+    }
+
+    /// Walks every allocated cluster chain and reports loops, clusters shared between chains,
+    /// and links that point outside the card. An empty list means the FAT is consistent.
+    pub fn check_integrity(&self) -> Vec<FatError> {
+        const END_OF_CHAIN: u32 = 0x7FFFFFFF;
+
+        // The next cluster of each allocated cluster; free clusters and chain ends map to None.
+        let mut next = vec![None; self.alloc_end];
+        let mut errors = vec![];
+        for (cluster, slot) in next.iter_mut().enumerate() {
+            let Some(value) = self.fat_entry(cluster) else {
+                continue;
+            };
+            if value & 0x80000000 == 0 {
+                continue;
+            }
+            let link = value & END_OF_CHAIN;
+            if link == END_OF_CHAIN {
+                continue;
+            }
+            if link as usize >= self.alloc_end {
+                errors.push(FatError::OutOfRange {
+                    cluster: cluster as u32,
+                    next: link,
+                });
+                continue;
+            }
+            *slot = Some(link as usize);
+        }
+
+        let mut predecessors = vec![vec![]; self.alloc_end];
+        for (cluster, link) in next.iter().enumerate() {
+            if let Some(link) = link {
+                predecessors[*link].push(cluster as u32);
+            }
+        }
+        for (cluster, from) in predecessors.into_iter().enumerate() {
+            if from.len() > 1 {
+                errors.push(FatError::CrossLinked {
+                    cluster: cluster as u32,
+                    from,
+                });
+            }
+        }
+
+        // Each walk stops at a cluster an earlier walk already covered, so every loop is found
+        // once, by the walk that first enters it.
+        let mut walked_by = vec![None; self.alloc_end];
+        for start in 0..self.alloc_end {
+            let mut path = vec![];
+            let mut cluster = Some(start);
+            while let Some(current) = cluster {
+                if let Some(walk) = walked_by[current] {
+                    if walk == start {
+                        let loop_start = path.iter().position(|&c| c == current).unwrap();
+                        errors.push(FatError::Cycle {
+                            clusters: path[loop_start..].iter().map(|&c| c as u32).collect(),
+                        });
+                    }
+                    break;
+                }
+                walked_by[current] = Some(start);
+                path.push(current);
+                cluster = next[current];
+            }
+        }
+
+        errors
+    }
+
+    fn fat_entry(&self, n: usize) -> Option<u32> {
+        self.fat_matrix
+            .get(n / self.fat_per_cluster)
+            .and_then(|row| row.get(n % self.fat_per_cluster))
+            .copied()
+    }
+
+    /// Lists the directories in the root of the card with their timestamps and entry counts.
+    pub fn list_saves(&self) -> io::Result<Vec<SaveEntry>> {
+        let mut saves = vec![];
+        for entry in self.root_entries()? {
+            if !entry.exists() || !entry.is_directory() || entry.is_dot_entry() {
+                continue;
+            }
+
+            let file_count = self
+                .read_directory(entry.cluster, entry.length as usize)?
+                .iter()
+                .filter(|child| child.exists() && !child.is_dot_entry())
+                .count();
+            saves.push(SaveEntry {
+                name: entry.name_as_string(),
+                created: entry.created,
+                modified: entry.modified,
+                file_count,
+            });
+        }
+
+        Ok(saves)
+    }
+
+    /// Writes the save directory `dir_name` and its files to a new folder inside `dest`,
+    /// returning the path of that folder.
+    pub fn export_save(&self, dir_name: &str, dest: &Path) -> io::Result<PathBuf> {
+        let save = self
+            .root_entries()?
+            .into_iter()
+            .find(|entry| {
+                entry.exists()
+                    && entry.is_directory()
+                    && !entry.is_dot_entry()
+                    && entry.name_as_string() == dir_name
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No save directory named {dir_name} on the memory card"),
+                )
+            })?;
+
+        let target = dest.join(checked_name(&save)?);
+        self.export_directory(&save, &target)?;
+        Ok(target)
+    }
+
+    fn export_directory(&self, directory: &DirEntry, target: &Path) -> io::Result<()> {
+        fs::create_dir_all(target)?;
+        for entry in self.read_directory(directory.cluster, directory.length as usize)? {
+            if !entry.exists() || entry.is_dot_entry() {
+                continue;
+            }
+
+            let path = target.join(checked_name(&entry)?);
+            if entry.is_directory() {
+                self.export_directory(&entry, &path)?;
+            } else {
+                fs::write(path, self.read_file(&entry)?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Follows a file's cluster chain and returns its contents.
+    fn read_file(&self, entry: &DirEntry) -> io::Result<Vec<u8>> {
+        let length = entry.length as usize;
+        let mut buffer = Vec::with_capacity(length);
+        let mut cluster = entry.cluster;
+        let mut visited = 0;
+
+        while buffer.len() < length {
+            if cluster == 0x7FFFFFFF || visited > self.alloc_end {
+                return Err(invalid_data("File cluster chain ends early"));
+            }
+            visited += 1;
+
+            let bytes = self.cluster_bytes(cluster as usize + self.alloc_offset)?;
+            let to_read = min(length - buffer.len(), bytes.len());
+            buffer.extend_from_slice(&bytes[..to_read]);
+
+            cluster = self.next_cluster(cluster)?;
+        }
+
+        Ok(buffer)
+    }
+
+    fn root_entries(&self) -> io::Result<Vec<DirEntry>> {
+        let first = self.read_directory(self.rootdir_cluster as u32, 1)?;
+        let root_length = first
+            .first()
+            .map(|entry| entry.length as usize)
+            .ok_or_else(|| invalid_data("Root directory is empty"))?;
+        self.read_directory(self.rootdir_cluster as u32, root_length)
+    }
+
+    fn next_cluster(&self, cluster: u32) -> io::Result<u32> {
+        self.fat_entry(cluster as usize)
+            .map(|value| value & 0x7FFFFFFF)
+            .ok_or_else(|| invalid_data("Cluster is outside the allocation table"))
+    }
+
+    /// Reads the first `count` entries of the directory whose chain starts at `cluster`.
+    fn read_directory(&self, cluster: u32, count: usize) -> io::Result<Vec<DirEntry>> {
+        let mut entries = vec![];
+        let mut cluster = cluster;
+        let mut visited = 0;
+
+        while entries.len() < count {
+            if cluster == 0x7FFFFFFF || visited > self.alloc_end {
+                return Err(invalid_data("Directory cluster chain ends early"));
+            }
+            visited += 1;
+
+            let bytes = self.cluster_bytes(cluster as usize + self.alloc_offset)?;
+            for chunk in bytes.chunks_exact(512) {
+                if entries.len() == count {
+                    break;
+                }
+                entries.push(DirEntry::from_bytes(chunk)?);
+            }
+
+            cluster = self.next_cluster(cluster)?;
+        }
+
+        Ok(entries)
+    }
+
+    /// Reads a cluster from the image without moving the cursor.
+    fn cluster_bytes(&self, n: usize) -> io::Result<Vec<u8>> {
+        let image = self.c.get_ref();
+        let mut buffer = Vec::with_capacity(self.cluster_size);
+        for page in n * self.pages_per_cluster..(n + 1) * self.pages_per_cluster {
+            let offset = page * self.raw_page_size;
+            let bytes = image
+                .get(offset..offset + self.page_size)
+                .ok_or_else(|| invalid_data("Cluster lies beyond the end of the image"))?;
+            buffer.extend_from_slice(bytes);
+        }
+
+        Ok(buffer)
+    }
+
     fn build_matrix(&mut self, cluster_list: Vec<u32>) -> Vec<Vec<u32>> {
         let mut matrix = vec![vec![0; self.fat_per_cluster]; cluster_list.len()];
 
@@ -280,3 +766,16 @@ impl Memcard {
         }
     }
 }
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Returns the entry name, refusing names that would escape the export folder.
+fn checked_name(entry: &DirEntry) -> io::Result<String> {
+    let name = entry.name_as_string();
+    if name.is_empty() || name.contains(['/', '\\']) || entry.is_dot_entry() {
+        return Err(invalid_data(&format!("Invalid entry name {name:?}")));
+    }
+    Ok(name)
+}
@@ -8,4 +8,4 @@
 pub mod dir_entry;
 pub mod fat;
 
-pub use fat::Memcard;
+pub use fat::{FatError, McSize, Memcard, SaveEntry};
@@ -0,0 +1,116 @@
+//! Builds small card images for the integration tests.
+#![allow(dead_code)]
+
+pub const PAGE_SIZE: usize = 512;
+pub const PAGES_PER_CLUSTER: usize = 2;
+pub const CLUSTER_SIZE: u64 = (PAGE_SIZE * PAGES_PER_CLUSTER) as u64;
+pub const ALLOC_END: u32 = 16;
+
+const RAW_PAGE_SIZE: usize = PAGE_SIZE + 16;
+const IFC_CLUSTER: u32 = 8;
+const FAT_CLUSTER: u32 = 9;
+const ALLOC_OFFSET: u32 = 10;
+
+const DF_READ: u16 = 0x0001;
+const DF_WRITE: u16 = 0x0002;
+const DF_EXECUTE: u16 = 0x0004;
+const DF_FILE: u16 = 0x0010;
+const DF_DIRECTORY: u16 = 0x0020;
+const DF_EXISTS: u16 = 0x8000;
+
+pub const DIRECTORY_MODE: u16 = DF_READ | DF_WRITE | DF_EXECUTE | DF_DIRECTORY | DF_EXISTS;
+pub const FILE_MODE: u16 = DF_READ | DF_WRITE | DF_EXECUTE | DF_FILE | DF_EXISTS;
+
+/// A card image with the root directory in allocatable cluster 0.
+pub struct CardImage {
+    bytes: Vec<u8>,
+}
+
+impl CardImage {
+    /// Creates a card whose FAT lists `fat` for the first allocatable clusters and marks the
+    /// rest free.
+    pub fn new(fat: &[u32]) -> Self {
+        let mut card = Self {
+            bytes: vec![0u8; cluster_offset(ALLOC_OFFSET + ALLOC_END)],
+        };
+
+        card.bytes[..28].copy_from_slice(b"Sony PS2 Memory Card Format ");
+        card.bytes[40..42].copy_from_slice(&(PAGE_SIZE as u16).to_le_bytes());
+        card.bytes[42..44].copy_from_slice(&(PAGES_PER_CLUSTER as u16).to_le_bytes());
+        card.bytes[44..46].copy_from_slice(&16u16.to_le_bytes());
+        card.write_u32(48, ALLOC_OFFSET + ALLOC_END);
+        card.write_u32(52, ALLOC_OFFSET);
+        card.write_u32(56, ALLOC_END);
+        card.write_u32(60, 0);
+        for slot in 0..32 {
+            let cluster = if slot == 0 { IFC_CLUSTER } else { 0xFFFFFFFF };
+            card.write_u32(80 + slot * 4, cluster);
+        }
+
+        let ifc = cluster_offset(IFC_CLUSTER);
+        card.write_u32(ifc, FAT_CLUSTER);
+        for index in 1..PAGE_SIZE / 4 {
+            card.write_u32(ifc + index * 4, 0xFFFFFFFF);
+        }
+
+        let fat_start = cluster_offset(FAT_CLUSTER);
+        for cluster in 0..ALLOC_END as usize {
+            let value = fat.get(cluster).copied().unwrap_or(0x7FFFFFFF);
+            card.write_u32(fat_start + cluster * 4, value);
+        }
+
+        card
+    }
+
+    /// Writes a directory entry into slot `index` of the directory chain starting at the
+    /// allocatable cluster `first_cluster`, assuming the chain is contiguous.
+    pub fn write_entry(
+        &mut self,
+        first_cluster: u32,
+        index: usize,
+        mode: u16,
+        length: u32,
+        cluster: u32,
+        name: &str,
+    ) {
+        let cluster_index = first_cluster as usize + index / PAGES_PER_CLUSTER;
+        let page =
+            (ALLOC_OFFSET as usize + cluster_index) * PAGES_PER_CLUSTER + index % PAGES_PER_CLUSTER;
+        let offset = page * RAW_PAGE_SIZE;
+
+        self.bytes[offset..offset + 2].copy_from_slice(&mode.to_le_bytes());
+        self.write_u32(offset + 4, length);
+        self.bytes[offset + 8..offset + 16].copy_from_slice(&timestamp(1));
+        self.write_u32(offset + 16, cluster);
+        self.bytes[offset + 24..offset + 32].copy_from_slice(&timestamp(2));
+        self.bytes[offset + 64..offset + 64 + name.len()].copy_from_slice(name.as_bytes());
+    }
+
+    /// Writes file contents starting at the allocatable cluster `first_cluster`, assuming the
+    /// chain is contiguous.
+    pub fn write_data(&mut self, first_cluster: u32, data: &[u8]) {
+        for (index, chunk) in data.chunks(PAGE_SIZE).enumerate() {
+            let page = (ALLOC_OFFSET + first_cluster) as usize * PAGES_PER_CLUSTER + index;
+            let offset = page * RAW_PAGE_SIZE;
+            self.bytes[offset..offset + chunk.len()].copy_from_slice(chunk);
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    fn write_u32(&mut self, offset: usize, value: u32) {
+        self.bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// 2024-10-`day` 12:34:56 in the on-card layout.
+fn timestamp(day: u8) -> [u8; 8] {
+    let year = 2024u16.to_le_bytes();
+    [0, 56, 34, 12, day, 10, year[0], year[1]]
+}
+
+fn cluster_offset(cluster: u32) -> usize {
+    cluster as usize * PAGES_PER_CLUSTER * RAW_PAGE_SIZE
+}
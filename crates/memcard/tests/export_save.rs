@@ -0,0 +1,67 @@
+mod common;
+
+use std::fs;
+use std::io;
+
+use common::{CardImage, DIRECTORY_MODE, FILE_MODE};
+use memcard::Memcard;
+use tempfile::tempdir;
+
+const END_OF_CHAIN: u32 = 0xFFFFFFFF;
+
+fn payload() -> Vec<u8> {
+    (0..1500u32).map(|value| (value % 251) as u8).collect()
+}
+
+fn crafted_card() -> Memcard {
+    // Root in cluster 0, SAVE_A in 1 -> 2, DATA.BIN spanning clusters 3 -> 4.
+    let mut card = CardImage::new(&[
+        END_OF_CHAIN,
+        0x80000002,
+        END_OF_CHAIN,
+        0x80000004,
+        END_OF_CHAIN,
+    ]);
+
+    card.write_entry(0, 0, DIRECTORY_MODE, 2, 0, ".");
+    card.write_entry(0, 1, DIRECTORY_MODE, 3, 1, "SAVE_A");
+
+    card.write_entry(1, 0, DIRECTORY_MODE, 0, 0, ".");
+    card.write_entry(1, 1, DIRECTORY_MODE, 0, 0, "..");
+    card.write_entry(1, 2, FILE_MODE, payload().len() as u32, 3, "DATA.BIN");
+    card.write_data(3, &payload());
+
+    Memcard::new(card.into_bytes())
+}
+
+#[test]
+fn exported_file_matches_card_contents() {
+    let workspace = tempdir().expect("temp dir");
+
+    let exported = crafted_card()
+        .export_save("SAVE_A", workspace.path())
+        .expect("export save");
+
+    assert_eq!(exported, workspace.path().join("SAVE_A"));
+    let names = fs::read_dir(&exported)
+        .expect("read export")
+        .map(|entry| entry.expect("dir entry").file_name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["DATA.BIN"]);
+    assert_eq!(
+        fs::read(exported.join("DATA.BIN")).expect("read file"),
+        payload()
+    );
+}
+
+#[test]
+fn missing_save_is_not_found() {
+    let workspace = tempdir().expect("temp dir");
+
+    let err = crafted_card()
+        .export_save("SAVE_B", workspace.path())
+        .expect_err("missing save");
+
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert!(!workspace.path().join("SAVE_B").exists());
+}
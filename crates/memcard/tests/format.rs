@@ -0,0 +1,37 @@
+use memcard::{McSize, Memcard};
+use tempfile::tempdir;
+
+#[test]
+fn formatted_card_reads_back_empty() {
+    let dir = tempdir().expect("temp dir");
+    let path = dir.path().join("blank.ps2");
+
+    let card = Memcard::format_new(&path, McSize::Mb8).expect("format card");
+    let reopened = Memcard::new(std::fs::read(&path).expect("read image"));
+
+    for card in [card, reopened] {
+        // Only the root directory's single cluster is allocated, and it is not counted.
+        assert_eq!(card.used_bytes(), 0);
+        assert_eq!(card.free_bytes(), 8134 * 1024);
+        assert!(card.list_saves().expect("list saves").is_empty());
+        assert!(card.check_integrity().is_empty());
+    }
+    let image = std::fs::read(&path).expect("read image");
+    assert_eq!(image.len(), 8192 * 2 * 528);
+    // An all-zero page carries the ECC a console writes for blank data in each 128-byte chunk.
+    let last_spare = &image[image.len() - 16..];
+    assert_eq!(last_spare[..12], [0x77, 0x7F, 0x7F].repeat(4));
+    assert_eq!(last_spare[12..], [0; 4]);
+}
+
+#[test]
+fn rejects_unsupported_sizes() {
+    let dir = tempdir().expect("temp dir");
+    let path = dir.path().join("big.ps2");
+
+    let err = Memcard::format_new(&path, McSize::Mb64)
+        .err()
+        .expect("64 MB cards are rejected");
+    assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+    assert!(!path.exists());
+}
@@ -0,0 +1,22 @@
+mod common;
+
+use common::{CardImage, ALLOC_END, CLUSTER_SIZE};
+use memcard::Memcard;
+
+#[test]
+fn counts_free_and_used_clusters() {
+    // Root directory in cluster 0 and a two-cluster file chained 1 -> 2.
+    let card = Memcard::new(CardImage::new(&[0xFFFFFFFF, 0x80000002, 0xFFFFFFFF]).into_bytes());
+
+    // The root directory's cluster is allocated but not counted as used.
+    assert_eq!(card.used_bytes(), 2 * CLUSTER_SIZE);
+    assert_eq!(card.free_bytes(), 13 * CLUSTER_SIZE);
+}
+
+#[test]
+fn empty_card_is_entirely_free() {
+    let card = Memcard::new(CardImage::new(&[]).into_bytes());
+
+    assert_eq!(card.used_bytes(), 0);
+    assert_eq!(card.free_bytes(), ALLOC_END as u64 * CLUSTER_SIZE);
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use common::CardImage;
+use memcard::{FatError, Memcard};
+
+#[test]
+fn consistent_fat_has_no_errors() {
+    // Root directory in cluster 0 and a two-cluster file chained 1 -> 2.
+    let card = Memcard::new(CardImage::new(&[0xFFFFFFFF, 0x80000002, 0xFFFFFFFF]).into_bytes());
+
+    assert!(card.check_integrity().is_empty());
+}
+
+#[test]
+fn reports_a_cluster_cycle() {
+    // Clusters 1 -> 2 -> 3 -> 1 never reach the end of the chain.
+    let card = Memcard::new(
+        CardImage::new(&[0xFFFFFFFF, 0x80000002, 0x80000003, 0x80000001]).into_bytes(),
+    );
+
+    assert_eq!(
+        card.check_integrity(),
+        vec![FatError::Cycle {
+            clusters: vec![1, 2, 3]
+        }]
+    );
+}
+
+#[test]
+fn reports_cross_links_and_out_of_range_pointers() {
+    // Clusters 1 and 2 both continue into 3, and cluster 4 points past the end of the card.
+    let card = Memcard::new(
+        CardImage::new(&[0xFFFFFFFF, 0x80000003, 0x80000003, 0xFFFFFFFF, 0x80000040]).into_bytes(),
+    );
+
+    assert_eq!(
+        card.check_integrity(),
+        vec![
+            FatError::OutOfRange {
+                cluster: 4,
+                next: 0x40
+            },
+            FatError::CrossLinked {
+                cluster: 3,
+                from: vec![1, 2]
+            },
+        ]
+    );
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{CardImage, DIRECTORY_MODE, FILE_MODE};
+use memcard::dir_entry::DateTime;
+use memcard::Memcard;
+
+const END_OF_CHAIN: u32 = 0xFFFFFFFF;
+
+fn crafted_card() -> Memcard {
+    // Root in clusters 0 -> 1 -> 2, SAVE_A in 3 -> 4, SAVE_B in 5 -> 6.
+    let mut card = CardImage::new(&[
+        0x80000001,
+        0x80000002,
+        END_OF_CHAIN,
+        0x80000004,
+        END_OF_CHAIN,
+        0x80000006,
+        END_OF_CHAIN,
+    ]);
+
+    card.write_entry(0, 0, DIRECTORY_MODE, 5, 0, ".");
+    card.write_entry(0, 1, DIRECTORY_MODE, 0, 0, "..");
+    card.write_entry(0, 2, DIRECTORY_MODE, 3, 3, "SAVE_A");
+    card.write_entry(0, 3, DIRECTORY_MODE, 4, 5, "SAVE_B");
+    card.write_entry(0, 4, FILE_MODE, 0, 0x7FFFFFFF, "LOOSE.BIN");
+
+    card.write_entry(3, 0, DIRECTORY_MODE, 0, 0, ".");
+    card.write_entry(3, 1, DIRECTORY_MODE, 0, 0, "..");
+    card.write_entry(3, 2, FILE_MODE, 0, 0x7FFFFFFF, "icon.sys");
+
+    card.write_entry(5, 0, DIRECTORY_MODE, 0, 0, ".");
+    card.write_entry(5, 1, DIRECTORY_MODE, 0, 0, "..");
+    card.write_entry(5, 2, FILE_MODE, 0, 0x7FFFFFFF, "icon.sys");
+    card.write_entry(5, 3, FILE_MODE, 0, 0x7FFFFFFF, "DATA.BIN");
+
+    Memcard::new(card.into_bytes())
+}
+
+#[test]
+fn lists_top_level_directories_with_child_counts() {
+    let saves = crafted_card().list_saves().expect("list saves");
+
+    let summary = saves
+        .iter()
+        .map(|save| (save.name.as_str(), save.file_count))
+        .collect::<Vec<_>>();
+    assert_eq!(summary, vec![("SAVE_A", 1), ("SAVE_B", 2)]);
+
+    assert_eq!(
+        saves[0].created,
+        DateTime {
+            seconds: 56,
+            minutes: 34,
+            hours: 12,
+            days: 1,
+            months: 10,
+            years: 2024,
+        }
+    );
+    assert_eq!(saves[0].modified.days, 2);
+}
+
+#[test]
+fn broken_directory_chain_is_an_error() {
+    let mut card = CardImage::new(&[END_OF_CHAIN]);
+    card.write_entry(0, 0, DIRECTORY_MODE, 4, 0, ".");
+
+    assert!(Memcard::new(card.into_bytes()).list_saves().is_err());
+}
@@ -1,7 +1,7 @@
 mod bin;
 pub mod color;
 mod icn;
-mod psu;
+pub mod psu;
 pub mod sjis;
 
 pub use bin::*;
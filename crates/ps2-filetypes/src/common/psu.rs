@@ -1,5 +1,7 @@
 use std::io::Cursor;
 
+pub mod format;
+
 pub const DIR_ID: u16 = 0x8427;
 pub const FILE_ID: u16 = 0x8497;
 
@@ -29,7 +31,9 @@ pub struct PSUEntry {
     pub contents: Option<Vec<u8>>,
 }
 
-pub(crate) struct PSUParser {
-    pub(crate) c: Cursor<Vec<u8>>,
+pub(crate) struct PSUParser<'a> {
+    pub(crate) c: Cursor<&'a [u8]>,
     pub(crate) len: u64,
+    /// When false, file contents are skipped and entries are returned without them.
+    pub(crate) read_contents: bool,
 }
@@ -0,0 +1,74 @@
+//! On-disk layout of a PSU archive.
+//!
+//! A PSU file is a flat sequence of entries. Every entry starts with a
+//! fixed-size header; file entries are followed by their contents, padded
+//! with zeroes up to the next [`DATA_ALIGNMENT`] boundary. Directory entries
+//! carry no data. All integers are little-endian.
+
+pub use super::{DIR_ID, FILE_ID, PAGE_SIZE};
+
+/// Size in bytes of every entry header.
+pub const ENTRY_HEADER_SIZE: usize = 512;
+
+/// Size in bytes of an encoded timestamp (`created` / `modified`).
+pub const TIMESTAMP_SIZE: usize = 8;
+
+/// Size in bytes of the reserved block between the modified timestamp and the name.
+pub const RESERVED_SIZE: usize = 32;
+
+/// Size in bytes of the NUL-padded entry name field.
+pub const NAME_FIELD_LEN: usize = 448;
+
+/// File contents are padded to a multiple of this many bytes.
+pub const DATA_ALIGNMENT: u32 = PAGE_SIZE;
+
+/// Byte offsets of the fields inside an entry header.
+///
+/// | Offset | Size | Field                                   |
+/// |--------|------|-----------------------------------------|
+/// | 0x00   | 2    | entry id ([`DIR_ID`] or [`FILE_ID`])    |
+/// | 0x02   | 2    | reserved                                |
+/// | 0x04   | 4    | size (bytes for files, entries for dirs)|
+/// | 0x08   | 8    | created timestamp                       |
+/// | 0x10   | 2    | sector                                  |
+/// | 0x12   | 6    | reserved                                |
+/// | 0x18   | 8    | modified timestamp                      |
+/// | 0x20   | 32   | reserved                                |
+/// | 0x40   | 448  | name, NUL padded                        |
+///
+/// Timestamps are stored as `[0, seconds, minutes, hours, day, month, year_lo, year_hi]`.
+pub struct EntryHeaderLayout;
+
+impl EntryHeaderLayout {
+    pub const ID: usize = 0x00;
+    pub const SIZE: usize = 0x04;
+    pub const CREATED: usize = 0x08;
+    pub const SECTOR: usize = 0x10;
+    pub const MODIFIED: usize = 0x18;
+    pub const RESERVED: usize = 0x20;
+    pub const NAME: usize = 0x40;
+}
+
+const _: () = assert!(EntryHeaderLayout::MODIFIED + TIMESTAMP_SIZE == EntryHeaderLayout::RESERVED);
+const _: () = assert!(EntryHeaderLayout::RESERVED + RESERVED_SIZE == EntryHeaderLayout::NAME);
+const _: () = assert!(EntryHeaderLayout::NAME + NAME_FIELD_LEN == ENTRY_HEADER_SIZE);
+
+/// Number of zero bytes written after `size` bytes of file contents.
+pub const fn data_padding(size: u32) -> u32 {
+    let rem = size % DATA_ALIGNMENT;
+    if rem == 0 {
+        0
+    } else {
+        DATA_ALIGNMENT - rem
+    }
+}
+
+/// Total number of bytes an entry occupies in the archive, header included.
+///
+/// `data_size` is the size of the file contents; pass `None` for directories.
+pub const fn entry_stride(data_size: Option<u32>) -> u64 {
+    match data_size {
+        Some(size) => ENTRY_HEADER_SIZE as u64 + size as u64 + data_padding(size) as u64,
+        None => ENTRY_HEADER_SIZE as u64,
+    }
+}
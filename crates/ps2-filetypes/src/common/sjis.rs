@@ -19,6 +19,16 @@ pub fn decode_sjis(input: &[u8]) -> String {
     decoded.trim_end_matches('\0').to_string()
 }
 
+/// Decodes Shift-JIS bytes up to the first NUL, or `None` if they are not valid Shift-JIS.
+pub fn try_decode_sjis(input: &[u8]) -> Option<String> {
+    let end = input
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(input.len());
+    let (decoded, _, had_errors) = SHIFT_JIS.decode(&input[..end]);
+    (!had_errors).then(|| decoded.into_owned())
+}
+
 pub fn is_roundtrip_sjis(value: &str) -> bool {
     let (encoded, _, encode_errors) = SHIFT_JIS.encode(value);
     if encode_errors {
@@ -1,5 +1,5 @@
 use crate::color::Color;
-use crate::sjis::{decode_sjis, encode_sjis};
+use crate::sjis::{decode_sjis, encode_sjis, try_decode_sjis};
 use crate::util::parse_cstring;
 use byteorder::{ReadBytesExt, LE};
 use std::io::{Cursor, Read, Result};
@@ -44,6 +44,49 @@ impl Vector {
     }
 }
 
+/// Size of an icon.sys file in bytes.
+pub const ICON_SYS_SIZE: usize = 964;
+const TITLE_OFFSET: usize = 0xC0;
+const TITLE_LEN: usize = 68;
+
+/// A structural problem found by [`IconSys::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IconSysError {
+    WrongLength {
+        actual: usize,
+    },
+    BadMagic,
+    BadLinebreak {
+        linebreak_pos: u16,
+        title_len: usize,
+    },
+    UndecodableTitle,
+}
+
+impl std::fmt::Display for IconSysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IconSysError::WrongLength { actual } => {
+                write!(
+                    f,
+                    "icon.sys must be {ICON_SYS_SIZE} bytes long, found {actual}"
+                )
+            }
+            IconSysError::BadMagic => write!(f, "icon.sys does not start with PS2D"),
+            IconSysError::BadLinebreak {
+                linebreak_pos,
+                title_len,
+            } => write!(
+                f,
+                "line break at byte {linebreak_pos} is outside the {title_len}-byte title"
+            ),
+            IconSysError::UndecodableTitle => write!(f, "the title is not valid Shift-JIS"),
+        }
+    }
+}
+
+impl std::error::Error for IconSysError {}
+
 /**
  * IconSys Flags
  * 00 -> PS2 Save File
@@ -79,6 +122,44 @@ impl IconSys {
         parse_icon_sys(bytes).unwrap()
     }
 
+    /// Parses an icon.sys file, reporting truncated input as an error instead of panicking.
+    pub fn try_new(bytes: Vec<u8>) -> std::io::Result<Self> {
+        parse_icon_sys(bytes)
+    }
+
+    /// Checks that `bytes` hold a well-formed icon.sys without building an [`IconSys`].
+    pub fn validate(bytes: &[u8]) -> std::result::Result<(), IconSysError> {
+        if bytes.len() != ICON_SYS_SIZE {
+            return Err(IconSysError::WrongLength {
+                actual: bytes.len(),
+            });
+        }
+        if &bytes[..4] != b"PS2D" {
+            return Err(IconSysError::BadMagic);
+        }
+
+        let title_bytes = &bytes[TITLE_OFFSET..TITLE_OFFSET + TITLE_LEN];
+        let title_len = title_bytes
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(TITLE_LEN);
+        if try_decode_sjis(title_bytes).is_none() {
+            return Err(IconSysError::UndecodableTitle);
+        }
+
+        let linebreak_pos = u16::from_le_bytes([bytes[6], bytes[7]]);
+        let splits_character =
+            try_decode_sjis(&title_bytes[..title_len.min(linebreak_pos as usize)]).is_none();
+        if linebreak_pos as usize > title_len || splits_character {
+            return Err(IconSysError::BadLinebreak {
+                linebreak_pos,
+                title_len,
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn to_bytes(&self) -> std::io::Result<Vec<u8>> {
         let mut bytes = Vec::new();
         bytes.extend_from_slice(b"PS2D");
@@ -1,29 +1,87 @@
 use std::io::{Cursor, Read, Seek};
 
+use crate::psu::format::{data_padding, DATA_ALIGNMENT, NAME_FIELD_LEN, RESERVED_SIZE};
 use crate::util::parse_cstring;
-use crate::{PSUEntry, PSUEntryKind, PSUParser, DIR_ID, FILE_ID, PAGE_SIZE, PSU};
+use crate::{PSUEntry, PSUEntryKind, PSUParser, DIR_ID, FILE_ID, PSU};
 use byteorder::{ReadBytesExt, LE};
 
 impl PSU {
     pub fn entries(&self) -> Vec<PSUEntry> {
         self.entries.clone()
     }
+
+    /// Borrows the contents of the file entry called `name`, compared case-insensitively.
+    pub fn entry_contents(&self, name: &str) -> Option<&[u8]> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+            .and_then(|entry| entry.contents.as_deref())
+    }
+
+    /// Fills in the `sector` of every file entry with the offset of its data, counted in
+    /// [`DATA_ALIGNMENT`]-sized pages from the first file. Each file takes as many pages as its
+    /// aligned size, so an empty file shares its offset with the next one. Directory entries
+    /// are set to 0. Fails without changing anything if an offset does not fit in 16 bits.
+    pub fn assign_sectors(&mut self) -> Result<(), std::io::Error> {
+        let mut next = 0u32;
+        let mut sectors = Vec::with_capacity(self.entries.len());
+        for entry in &self.entries {
+            if matches!(entry.kind, PSUEntryKind::File) {
+                let sector = u16::try_from(next).map_err(|_| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "PSU contents exceed the addressable sector range",
+                    )
+                })?;
+                sectors.push(sector);
+                next += entry.size.div_ceil(DATA_ALIGNMENT);
+            } else {
+                sectors.push(0);
+            }
+        }
+
+        for (entry, sector) in self.entries.iter_mut().zip(sectors) {
+            entry.sector = sector;
+        }
+        Ok(())
+    }
+
+    /// The `sector` of every file entry, in archive order.
+    pub fn sectors(&self) -> Vec<(&str, u16)> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+            .map(|entry| (entry.name.as_str(), entry.sector))
+            .collect()
+    }
 }
 
 impl PSU {
     pub fn new(bytes: Vec<u8>) -> Self {
-        Self {
-            entries: PSUParser::new(bytes).parse().unwrap(),
-        }
+        Self::try_new(bytes).unwrap()
+    }
+
+    /// Parses a PSU archive, reporting malformed input as an error instead of panicking.
+    pub fn try_new(bytes: Vec<u8>) -> Result<Self, std::io::Error> {
+        Ok(Self {
+            entries: PSUParser::new(&bytes, true).parse()?,
+        })
+    }
+
+    /// Reads the entry headers of a PSU archive without copying file contents, so every
+    /// returned entry has `contents: None`. Truncated file data is still reported as an error.
+    pub fn read_entry_headers(bytes: &[u8]) -> Result<Vec<PSUEntry>, std::io::Error> {
+        PSUParser::new(bytes, false).parse()
     }
 }
 
-impl PSUParser {
-    fn new(bytes: Vec<u8>) -> PSUParser {
-        let len = bytes.len() as u64;
+impl<'a> PSUParser<'a> {
+    fn new(bytes: &'a [u8], read_contents: bool) -> Self {
         Self {
             c: Cursor::new(bytes),
-            len,
+            len: bytes.len() as u64,
+            read_contents,
         }
     }
 
@@ -46,18 +104,26 @@ impl PSUParser {
         let _ = self.c.read_u16::<LE>()?;
         let _ = self.c.read_u32::<LE>()?;
         let modified = self.read_timestamp()?;
-        self.c.seek_relative(32)?;
+        self.c.seek_relative(RESERVED_SIZE as i64)?;
 
-        let mut name = [0; 448];
+        let mut name = [0; NAME_FIELD_LEN];
         self.c.read_exact(&mut name)?;
 
-        let contents = if id == FILE_ID {
+        let contents = if id == FILE_ID && !self.read_contents {
+            let end = self.c.position() + size as u64;
+            if end > self.len {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "PSU file entry extends past the end of the archive",
+                ));
+            }
+            self.c
+                .seek_relative(size as i64 + data_padding(size) as i64)?;
+            None
+        } else if id == FILE_ID {
             let mut contents = vec![0; size as usize];
             self.c.read_exact(&mut contents)?;
-            let rem = 1024 - (size % 1024);
-
-            self.c
-                .seek_relative(if rem == PAGE_SIZE { 0 } else { rem as i64 })?;
+            self.c.seek_relative(data_padding(size) as i64)?;
 
             Some(contents)
         } else {
@@ -89,10 +155,10 @@ impl PSUParser {
         let months = self.c.read_u8()?;
         let year = self.c.read_u16::<LE>()?;
 
-        let date = chrono::NaiveDate::from_ymd_opt(year as i32, months as u32, days as u32)
-            .unwrap()
-            .and_hms_opt(hours as u32, minutes as u32, seconds as u32)
-            .unwrap();
-        Ok(date)
+        chrono::NaiveDate::from_ymd_opt(year as i32, months as u32, days as u32)
+            .and_then(|date| date.and_hms_opt(hours as u32, minutes as u32, seconds as u32))
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid PSU timestamp")
+            })
     }
 }
@@ -3,6 +3,15 @@ use std::fmt::{Display, Formatter};
 use std::string::ToString;
 use toml::Table;
 
+/// Keys listed by [`TitleCfg::to_summary`], with the label shown for each.
+const SUMMARY_FIELDS: &[(&str, &str)] = &[
+    ("title", "Title"),
+    ("Version", "Version"),
+    ("Developer", "Developer"),
+    ("Genre", "Genre"),
+    ("Description", "Description"),
+];
+
 const MANDATORY_KEYS: &[&str] = &[
     "title",
     "Description",
@@ -17,11 +26,70 @@ pub struct TitleCfg {
     pub contents: String,
     pub index_map: IndexMap<String, String>,
     pub helper: Table,
+    layout: Layout,
+}
+
+/// A field that differs between two title.cfg files, as reported by [`TitleCfg::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange {
+    Added {
+        key: String,
+        value: String,
+    },
+    Removed {
+        key: String,
+        value: String,
+    },
+    Modified {
+        key: String,
+        old: String,
+        new: String,
+    },
+}
+
+impl FieldChange {
+    pub fn key(&self) -> &str {
+        match self {
+            FieldChange::Added { key, .. }
+            | FieldChange::Removed { key, .. }
+            | FieldChange::Modified { key, .. } => key,
+        }
+    }
+}
+
+/// The lines of the parsed file that are not `key=value` pairs, kept so that writing the
+/// index map back out does not drop them. Comments and blank lines belong to the key that
+/// follows them and move with it; anything after the last key is kept at the end.
+#[derive(Debug, Clone, Default)]
+struct Layout {
+    leading: IndexMap<String, Vec<String>>,
+    trailing: Vec<String>,
+}
+
+impl Layout {
+    fn parse(contents: &str) -> Self {
+        let mut layout = Self::default();
+        for line in contents.lines() {
+            match line.split_once('=') {
+                Some((key, _)) => {
+                    let pending = std::mem::take(&mut layout.trailing);
+                    layout
+                        .leading
+                        .entry(key.to_string())
+                        .or_default()
+                        .extend(pending);
+                }
+                None => layout.trailing.push(line.to_string()),
+            }
+        }
+        layout
+    }
 }
 
 impl TitleCfg {
     pub fn new(contents: String) -> Self {
         let index_map = string_to_index_map(contents.clone());
+        let layout = Layout::parse(&contents);
 
         let helper = include_str!("../../title_cfg.toml")
             .parse::<Table>()
@@ -31,6 +99,7 @@ impl TitleCfg {
             contents,
             index_map,
             helper,
+            layout,
         }
     }
 
@@ -40,6 +109,7 @@ impl TitleCfg {
 
     pub fn sync_contents_to_index_map(&mut self) {
         self.index_map = string_to_index_map(self.contents.clone());
+        self.layout = Layout::parse(&self.contents);
     }
 
     pub fn has_mandatory_fields(&self) -> bool {
@@ -54,6 +124,74 @@ impl TitleCfg {
             .collect()
     }
 
+    /// Lists `(key, message)` pairs for fields whose value is not one of the `values` the
+    /// helper table allows. Fields without a `values` list and empty values are skipped.
+    pub fn validate_values(&self) -> Vec<(String, String)> {
+        self.index_map
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .filter_map(|(key, value)| {
+                let allowed = self
+                    .helper
+                    .get(key)
+                    .and_then(|entry| entry.get("values"))
+                    .and_then(|values| values.as_array())?
+                    .iter()
+                    .filter_map(|allowed| allowed.as_str())
+                    .collect::<Vec<_>>();
+                (!allowed.contains(&value.as_str())).then(|| {
+                    (
+                        key.clone(),
+                        format!("`{value}` is not one of: {}", allowed.join(", ")),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Lists the fields that change going from `self` to `other`: modified and removed keys in
+    /// the order of `self`, then added keys in the order of `other`. Keys whose value is the
+    /// same in both are not reported.
+    pub fn diff(&self, other: &TitleCfg) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        for (key, old) in &self.index_map {
+            match other.index_map.get(key) {
+                Some(new) if new == old => {}
+                Some(new) => changes.push(FieldChange::Modified {
+                    key: key.clone(),
+                    old: old.clone(),
+                    new: new.clone(),
+                }),
+                None => changes.push(FieldChange::Removed {
+                    key: key.clone(),
+                    value: old.clone(),
+                }),
+            }
+        }
+        for (key, value) in &other.index_map {
+            if !self.index_map.contains_key(key) {
+                changes.push(FieldChange::Added {
+                    key: key.clone(),
+                    value: value.clone(),
+                });
+            }
+        }
+        changes
+    }
+
+    /// A short plain-text summary with one `Label: value` line for each of the title,
+    /// version, developer, genre and description. Fields that are missing or empty are left
+    /// out.
+    pub fn to_summary(&self) -> String {
+        SUMMARY_FIELDS
+            .iter()
+            .filter_map(|(key, label)| {
+                let value = self.index_map.get(*key)?.trim();
+                (!value.is_empty()).then(|| format!("{label}: {value}\n"))
+            })
+            .collect()
+    }
+
     pub fn add_missing_fields(&mut self) -> &Self {
         for (_, key) in MANDATORY_KEYS.iter().enumerate() {
             if !self.index_map.contains_key(*key) {
@@ -68,8 +206,14 @@ impl Display for TitleCfg {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut contents: String = "".to_string();
         for (key, value) in self.index_map.iter() {
+            for line in self.layout.leading.get(key).into_iter().flatten() {
+                contents.push_str(&format!("{line}\n"));
+            }
             contents.push_str(format!("{key}={value}\n").to_owned().as_str());
         }
+        for line in &self.layout.trailing {
+            contents.push_str(&format!("{line}\n"));
+        }
         write!(f, "{contents}")
     }
 }
@@ -132,4 +276,82 @@ mod tests {
         assert!(missing.contains(&"source"));
         assert!(!cfg.has_mandatory_fields());
     }
+
+    #[test]
+    fn editing_a_value_keeps_comments_and_blank_lines() {
+        let contents = "# Example title.cfg\ntitle=Example\n\n# Shown in the launcher\nDescription=Old text\nVersion=1.00\n\n# end of file\n";
+
+        let mut cfg = TitleCfg::new(contents.to_string());
+        cfg.index_map
+            .insert("Description".to_string(), "New text".to_string());
+        cfg.sync_index_map_to_contents();
+
+        assert_eq!(
+            cfg.contents,
+            contents.replace("Description=Old text", "Description=New text")
+        );
+
+        cfg.index_map.shift_remove("title");
+        cfg.index_map
+            .insert("title".to_string(), "Moved".to_string());
+        assert_eq!(
+            cfg.to_string(),
+            "\n# Shown in the launcher\nDescription=New text\nVersion=1.00\n# Example title.cfg\ntitle=Moved\n\n# end of file\n"
+        );
+    }
+
+    #[test]
+    fn diff_reports_modified_and_added_fields() {
+        let saved = TitleCfg::new("title=Example\nVersion=1.00\nDeveloper=Someone".to_string());
+        let edited = TitleCfg::new(
+            "title=Example\nVersion=1.01\nDeveloper=Someone\nRelease=2024".to_string(),
+        );
+
+        let changes = saved.diff(&edited);
+
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange::Modified {
+                    key: "Version".to_string(),
+                    old: "1.00".to_string(),
+                    new: "1.01".to_string(),
+                },
+                FieldChange::Added {
+                    key: "Release".to_string(),
+                    value: "2024".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            edited.diff(&saved).last().map(FieldChange::key),
+            Some("Release")
+        );
+    }
+
+    #[test]
+    fn summary_lists_set_fields_only() {
+        let cfg = TitleCfg::new(
+            "title=Example Game\nboot=BOOT.ELF\nDeveloper=Someone\nVersion=\nDescription=A demo"
+                .to_string(),
+        );
+
+        assert_eq!(
+            cfg.to_summary(),
+            "Title: Example Game\nDeveloper: Someone\nDescription: A demo\n"
+        );
+    }
+
+    #[test]
+    fn validate_values_reports_values_outside_the_helper_list() {
+        let contents = "title=Example\nAspect=aspect/x\nVmode=vmode/pal\nVersion=anything\nScan=";
+
+        let cfg = TitleCfg::new(contents.to_string());
+        let errors = cfg.validate_values();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "Aspect");
+        assert!(errors[0].1.contains("aspect/x"));
+        assert!(errors[0].1.contains("aspect/w"));
+    }
 }
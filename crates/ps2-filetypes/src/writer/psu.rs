@@ -1,4 +1,5 @@
-use crate::{PSUEntry, FILE_ID, PAGE_SIZE, PSU};
+use crate::psu::format::{data_padding, NAME_FIELD_LEN, RESERVED_SIZE};
+use crate::{PSUEntry, FILE_ID, PSU};
 use byteorder::{WriteBytesExt, LE};
 use chrono::{Datelike, NaiveDateTime, Timelike};
 use std::io::Write;
@@ -26,7 +27,7 @@ impl PSUWriter {
     }
 
     fn write_string(&self, string: String) -> std::io::Result<Vec<u8>> {
-        let remainder = 448 - string.len();
+        let remainder = NAME_FIELD_LEN - string.len();
         let mut data = vec![];
         for c in string.chars() {
             data.push(c as u8);
@@ -46,18 +47,13 @@ impl PSUWriter {
         data.write_u16::<LE>(0)?;
         data.write_u32::<LE>(0)?;
         data.write_all(&self.write_timestamp(entry.modified)?)?;
-        let padding = vec![0u8; 32];
+        let padding = vec![0u8; RESERVED_SIZE];
         data.write_all(&padding)?;
         data.write_all(&self.write_string(entry.name.clone())?)?;
 
         if entry.id == FILE_ID {
             data.write_all(&entry.contents.clone().unwrap())?;
-            let rem = 1024 - (entry.size % 1024);
-
-            let rem = if rem == PAGE_SIZE { 0 } else { rem as i64 };
-            for _ in 0..rem {
-                data.write_u8(0)?;
-            }
+            data.write_all(&vec![0u8; data_padding(entry.size) as usize])?;
         }
 
         Ok(data)
@@ -1,5 +1,5 @@
 use base64::{engine::general_purpose::STANDARD, Engine};
-use ps2_filetypes::{color::Color, IconSys};
+use ps2_filetypes::{color::Color, IconSys, IconSysError};
 
 fn decode_fixture(path: &str) -> Vec<u8> {
     let encoded = match path {
@@ -80,3 +80,60 @@ fn icon_sys_roundtrips_shift_jis_title() {
     assert_eq!(reparsed.title, "SAVE!&テスト");
     assert_eq!(reparsed.linebreak_pos, icon_sys.linebreak_pos);
 }
+
+fn icon_sys_with_title(title: &str, linebreak_pos: u16) -> Vec<u8> {
+    let mut icon_sys = IconSys::new(decode_fixture("fixtures/icon_sys_int.b64"));
+    icon_sys.title = title.to_string();
+    icon_sys.linebreak_pos = linebreak_pos;
+    icon_sys.to_bytes().expect("serialize icon.sys")
+}
+
+#[test]
+fn validate_accepts_well_formed_files() {
+    assert_eq!(
+        IconSys::validate(&decode_fixture("fixtures/icon_sys_float.b64")),
+        Ok(())
+    );
+    assert_eq!(
+        IconSys::validate(&icon_sys_with_title("メモリー", 4)),
+        Ok(())
+    );
+    assert_eq!(IconSys::validate(&icon_sys_with_title("SAVE", 4)), Ok(()));
+}
+
+#[test]
+fn validate_reports_specific_problems() {
+    let bytes = icon_sys_with_title("SAVE DATA", 4);
+
+    assert_eq!(
+        IconSys::validate(&bytes[..900]),
+        Err(IconSysError::WrongLength { actual: 900 })
+    );
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[..4].copy_from_slice(b"PS2X");
+    assert_eq!(IconSys::validate(&bad_magic), Err(IconSysError::BadMagic));
+
+    assert_eq!(
+        IconSys::validate(&icon_sys_with_title("SAVE DATA", 10)),
+        Err(IconSysError::BadLinebreak {
+            linebreak_pos: 10,
+            title_len: 9
+        })
+    );
+    assert!(matches!(
+        IconSys::validate(&icon_sys_with_title("メモリー", 3)),
+        Err(IconSysError::BadLinebreak {
+            linebreak_pos: 3,
+            ..
+        })
+    ));
+
+    let mut bad_title = bytes;
+    bad_title[0xC0] = 0x81;
+    bad_title[0xC1] = 0x20;
+    assert_eq!(
+        IconSys::validate(&bad_title),
+        Err(IconSysError::UndecodableTitle)
+    );
+}
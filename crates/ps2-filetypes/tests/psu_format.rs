@@ -0,0 +1,109 @@
+use ps2_filetypes::chrono::NaiveDateTime;
+use ps2_filetypes::psu::format::{
+    entry_stride, EntryHeaderLayout, DATA_ALIGNMENT, ENTRY_HEADER_SIZE, NAME_FIELD_LEN,
+};
+use ps2_filetypes::{PSUEntry, PSUEntryKind, PSUWriter, DIR_ID, FILE_ID, PSU};
+
+fn entry(id: u16, name: &str, contents: Option<Vec<u8>>) -> PSUEntry {
+    let timestamp = NaiveDateTime::parse_from_str("2024-01-02 03:04:05", "%Y-%m-%d %H:%M:%S")
+        .expect("valid timestamp");
+    PSUEntry {
+        id,
+        size: contents.as_ref().map_or(0, |data| data.len() as u32),
+        created: timestamp,
+        sector: 0,
+        modified: timestamp,
+        name: name.to_string(),
+        kind: if id == DIR_ID {
+            PSUEntryKind::Directory
+        } else {
+            PSUEntryKind::File
+        },
+        contents,
+    }
+}
+
+#[test]
+fn writer_output_matches_documented_layout() {
+    let psu = PSU {
+        entries: vec![
+            entry(DIR_ID, "SAVE", None),
+            entry(FILE_ID, "A.BIN", Some(vec![0xAA; 5])),
+            entry(FILE_ID, "B.BIN", Some(vec![0xBB; 1024])),
+        ],
+    };
+    let bytes = PSUWriter::new(psu).to_bytes().expect("write psu");
+
+    let expected = entry_stride(None) + entry_stride(Some(5)) + entry_stride(Some(1024));
+    assert_eq!(bytes.len() as u64, expected);
+    assert_eq!(entry_stride(Some(5)), ENTRY_HEADER_SIZE as u64 + 1024);
+    assert_eq!(entry_stride(Some(1024)), ENTRY_HEADER_SIZE as u64 + 1024);
+
+    let file = &bytes[ENTRY_HEADER_SIZE..];
+    let id = u16::from_le_bytes([file[EntryHeaderLayout::ID], file[EntryHeaderLayout::ID + 1]]);
+    assert_eq!(id, FILE_ID);
+    let name = &file[EntryHeaderLayout::NAME..EntryHeaderLayout::NAME + NAME_FIELD_LEN];
+    assert!(name.starts_with(b"A.BIN\0"));
+    assert_eq!(file[ENTRY_HEADER_SIZE], 0xAA);
+}
+
+#[test]
+fn entry_contents_borrows_file_by_name_ignoring_case() {
+    let psu = PSU {
+        entries: vec![
+            entry(DIR_ID, "SAVE", None),
+            entry(FILE_ID, "title.cfg", Some(b"title=Test".to_vec())),
+            entry(FILE_ID, "BOOT.ELF", Some(vec![0x7F, b'E'])),
+        ],
+    };
+
+    assert_eq!(psu.entry_contents("TITLE.CFG"), Some(&b"title=Test"[..]));
+    assert_eq!(psu.entry_contents("boot.elf"), Some(&[0x7F, b'E'][..]));
+    assert_eq!(psu.entry_contents("SAVE"), None);
+    assert_eq!(psu.entry_contents("icon.sys"), None);
+}
+
+#[test]
+fn assign_sectors_lays_out_files_on_page_boundaries() {
+    let mut psu = PSU {
+        entries: vec![
+            entry(DIR_ID, "SAVE", None),
+            entry(DIR_ID, ".", None),
+            entry(DIR_ID, "..", None),
+            entry(FILE_ID, "A.BIN", Some(vec![0xAA; 5])),
+            entry(FILE_ID, "B.BIN", Some(vec![0xBB; 1024])),
+            entry(FILE_ID, "C.BIN", Some(vec![0xCC; 3000])),
+            entry(FILE_ID, "D.BIN", Some(Vec::new())),
+            entry(FILE_ID, "E.BIN", Some(vec![0xEE; 1])),
+        ],
+    };
+
+    psu.assign_sectors().expect("assign sectors");
+
+    assert_eq!(
+        psu.sectors(),
+        vec![
+            ("A.BIN", 0),
+            ("B.BIN", 1),
+            ("C.BIN", 2),
+            ("D.BIN", 5),
+            ("E.BIN", 5)
+        ]
+    );
+    assert!(psu.entries[..3].iter().all(|entry| entry.sector == 0));
+    let files = psu.sectors();
+    for pair in files.windows(2) {
+        let (_, previous) = pair[0];
+        let (name, sector) = pair[1];
+        assert!(sector >= previous, "{name} goes backwards");
+    }
+    let c = &psu.entries[5];
+    assert_eq!(
+        u32::from(files[3].1 - files[2].1) * DATA_ALIGNMENT,
+        c.size.div_ceil(DATA_ALIGNMENT) * DATA_ALIGNMENT
+    );
+
+    let bytes = PSUWriter::new(psu).to_bytes().expect("write psu");
+    let parsed = PSU::try_new(bytes).expect("parse psu");
+    assert_eq!(parsed.sectors()[2], ("C.BIN", 2));
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fs, io,
     path::{Path, PathBuf},
 };
@@ -15,15 +15,16 @@ use gui_core::{
         MetadataAction, MetadataTarget, TimestampAction, TimestampRulesAction,
         TimestampStrategyAction,
     },
+    commands::{apply_event, AppEvent, EventResult},
     state::{
-        MissingRequiredFile, PackErrorMessage, PackOutcome, PackPreparation, PackerState,
-        PendingPackAction, TimestampStrategy,
+        set_file_read_only, MissingRequiredFile, PackErrorMessage, PackOutcome, PackPreparation,
+        PackerState, PendingPackAction, StateError, TimestampStrategy,
     },
 };
 use icon_sys_ui::IconSysState;
 use indexmap::IndexMap;
 use ps2_filetypes::{sjis, templates, IconSys, TitleCfg};
-use psu_packer::{split_icon_sys_title, ICON_SYS_PRESETS};
+use psu_packer::{split_icon_sys_title_with_limit, ICON_SYS_PRESETS};
 #[cfg(any(test, feature = "psu-toml-editor"))]
 #[cfg(feature = "psu-toml-editor")]
 use tempfile::tempdir;
@@ -66,17 +67,52 @@ const TITLE_CFG_SECTIONS: &[(&str, &[&str])] = &[
     ("Ratings", &["Rating", "RatingText"]),
 ];
 
+/// Results derived from scanning the project folder, kept for the folder and configuration
+/// they were computed from so the UI does not rescan the project every frame.
+#[derive(Default)]
+pub(crate) struct ProjectScanCache {
+    inputs: Option<(PathBuf, psu_packer::Config)>,
+    estimated_size: Option<Option<u64>>,
+    missing_icon_files: Option<Vec<psu_packer::ConfigWarning>>,
+}
+
+impl ProjectScanCache {
+    /// Drops the cached results unless they were computed for `folder` and `config`.
+    fn refresh(&mut self, folder: &Path, config: &psu_packer::Config) {
+        let unchanged = self
+            .inputs
+            .as_ref()
+            .is_some_and(|(cached_folder, cached_config)| {
+                cached_folder == folder && cached_config == config
+            });
+        if !unchanged {
+            *self = Self {
+                inputs: Some((folder.to_path_buf(), config.clone())),
+                ..Self::default()
+            };
+        }
+    }
+
+    /// Forgets every cached result, for when files on disk may have changed.
+    pub(crate) fn invalidate(&mut self) {
+        *self = Self::default();
+    }
+}
+
 struct TitleCfgCache {
     cfg: TitleCfg,
     missing_fields: Vec<&'static str>,
+    value_errors: Vec<(String, String)>,
 }
 
 impl TitleCfgCache {
     fn new(cfg: TitleCfg) -> Self {
         let missing_fields = cfg.missing_mandatory_fields();
+        let value_errors = cfg.validate_values();
         Self {
             cfg,
             missing_fields,
+            value_errors,
         }
     }
 
@@ -96,8 +132,13 @@ impl TitleCfgCache {
         &self.missing_fields
     }
 
+    fn value_errors(&self) -> &[(String, String)] {
+        &self.value_errors
+    }
+
     fn refresh_metadata(&mut self) {
         self.missing_fields = self.cfg.missing_mandatory_fields();
+        self.value_errors = self.cfg.validate_values();
     }
 
     fn sync_index_map_to_contents(&mut self) {
@@ -180,6 +221,10 @@ impl TextFileEditor {
     fn title_cfg_missing_fields(&mut self) -> Option<&[&'static str]> {
         self.title_cfg_cache().map(|cache| cache.missing_fields())
     }
+
+    fn title_cfg_value_errors(&mut self) -> Option<&[(String, String)]> {
+        self.title_cfg_cache().map(|cache| cache.value_errors())
+    }
 }
 
 pub struct PackerApp {
@@ -189,15 +234,33 @@ pub struct PackerApp {
     pub(crate) icon_sys_enabled: bool,
     pub(crate) icon_sys_title_line1: String,
     pub(crate) icon_sys_title_line2: String,
+    /// Characters allowed per icon.sys title line. Custom launchers may render more or fewer
+    /// than the standard [`ICON_SYS_TITLE_CHAR_LIMIT`].
+    pub(crate) icon_sys_title_char_limit: usize,
     pub(crate) icon_sys_state: IconSysState,
     pub(crate) icon_sys_use_existing: bool,
     pub(crate) icon_sys_existing: Option<IconSys>,
+    pub(crate) icon_sys_variants: BTreeMap<String, psu_packer::IconSysConfig>,
+    pub(crate) icon_sys_variant: Option<String>,
+    pub(crate) root_size_excludes_dot_entries: bool,
+    pub(crate) file_timestamps: BTreeMap<String, psu_packer::EntryTimestamps>,
+    pub(crate) preserve_include_order: bool,
+    pub(crate) include_from_manifest: Option<PathBuf>,
+    pub(crate) verify_after_pack: bool,
+    pub(crate) root_dir_name: Option<String>,
+    pub(crate) generate_icon_sys: bool,
+    /// Output path and result of the last comparison between the project folder and that PSU.
+    pub(crate) pending_changes: Option<(PathBuf, Result<Vec<psu_packer::PendingChange>, String>)>,
+    /// Read-only output made writable for the running update; protected again if it fails.
+    pub(crate) writable_update_output: Option<PathBuf>,
+    pub(crate) project_scan_cache: ProjectScanCache,
     pub(crate) zoom_factor: f32,
     pub(crate) active_editor: EditorAction,
     pub(crate) psu_toml_editor: TextFileEditor,
     pub(crate) title_cfg_editor: TextFileEditor,
     pub(crate) psu_toml_sync_blocked: bool,
     pub(crate) theme: theme::Palette,
+    pub(crate) palette_id: theme::PaletteId,
     #[cfg(test)]
     pub(crate) test_pack_job_started: bool,
 }
@@ -211,15 +274,29 @@ impl Default for PackerApp {
             icon_sys_enabled: false,
             icon_sys_title_line1: String::new(),
             icon_sys_title_line2: String::new(),
+            icon_sys_title_char_limit: ICON_SYS_TITLE_CHAR_LIMIT,
             icon_sys_state: IconSysState::default(),
             icon_sys_use_existing: false,
             icon_sys_existing: None,
+            icon_sys_variants: BTreeMap::new(),
+            icon_sys_variant: None,
+            root_size_excludes_dot_entries: false,
+            file_timestamps: BTreeMap::new(),
+            preserve_include_order: false,
+            include_from_manifest: None,
+            verify_after_pack: false,
+            root_dir_name: None,
+            generate_icon_sys: true,
+            pending_changes: None,
+            writable_update_output: None,
+            project_scan_cache: ProjectScanCache::default(),
             zoom_factor: 1.0,
             active_editor: EditorAction::PsuSettings,
             psu_toml_editor: TextFileEditor::default(),
             title_cfg_editor: TextFileEditor::default(),
             psu_toml_sync_blocked: false,
             theme: theme::Palette::default(),
+            palette_id: theme::PaletteId::default(),
             #[cfg(test)]
             test_pack_job_started: false,
         }
@@ -230,10 +307,21 @@ impl PackerApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         let mut app = Self::default();
         app.zoom_factor = cc.egui_ctx.pixels_per_point();
+        if let Some(settings) = cc.storage.and_then(crate::view::DisplaySettings::load) {
+            app.zoom_factor = settings.zoom_factor;
+            app.palette_id = settings.palette;
+            app.theme = settings.palette.palette();
+        }
         theme::install(&cc.egui_ctx, &app.theme);
         app
     }
 
+    pub(crate) fn set_palette(&mut self, ctx: &egui::Context, palette: theme::PaletteId) {
+        self.palette_id = palette;
+        self.theme = palette.palette();
+        theme::apply_palette(ctx, &self.theme);
+    }
+
     pub(crate) fn packer_state(&self) -> &PackerState {
         &self.packer_state
     }
@@ -274,6 +362,12 @@ impl PackerApp {
         self.refresh_psu_toml_editor();
     }
 
+    pub(crate) fn apply_timestamp_rules_preset(&mut self, preset_id: &str) {
+        if self.packer_state.apply_timestamp_rules_preset(preset_id) {
+            self.refresh_psu_toml_editor();
+        }
+    }
+
     pub(crate) fn clear_error_message(&mut self) {
         self.packer_state.clear_error_message();
     }
@@ -326,6 +420,12 @@ impl PackerApp {
         self.icon_sys_state = IconSysState::default();
     }
 
+    /// Forgets the `[icon_sys.<label>]` variants of the previously loaded project.
+    pub(crate) fn clear_icon_sys_variants(&mut self) {
+        self.icon_sys_variants.clear();
+        self.icon_sys_variant = None;
+    }
+
     pub(crate) fn apply_icon_sys_config(
         &mut self,
         icon_cfg: psu_packer::IconSysConfig,
@@ -337,7 +437,11 @@ impl PackerApp {
             .apply_icon_sys_config(&icon_cfg, icon_sys_fallback);
 
         let break_index = icon_cfg.linebreak_position() as usize;
-        let (line1, line2) = split_icon_sys_title(&icon_cfg.title, break_index);
+        let (line1, line2) = split_icon_sys_title_with_limit(
+            &icon_cfg.title,
+            break_index,
+            self.icon_sys_title_char_limit,
+        );
         self.icon_sys_title_line1 = line1;
         self.icon_sys_title_line2 = line2;
     }
@@ -349,11 +453,51 @@ impl PackerApp {
         self.icon_sys_state.apply_icon_sys(icon_sys);
 
         let break_index = icon_sys.linebreak_pos as usize;
-        let (line1, line2) = split_icon_sys_title(&icon_sys.title, break_index);
+        let (line1, line2) = split_icon_sys_title_with_limit(
+            &icon_sys.title,
+            break_index,
+            self.icon_sys_title_char_limit,
+        );
         self.icon_sys_title_line1 = line1;
         self.icon_sys_title_line2 = line2;
     }
 
+    /// Whether the generated icon.sys settings differ from the project's existing icon.sys.
+    pub(crate) fn icon_sys_differs_from_existing(&self) -> bool {
+        let Some(existing) = self.icon_sys_existing.as_ref() else {
+            return false;
+        };
+        if !self.icon_sys_enabled || self.icon_sys_use_existing {
+            return false;
+        }
+
+        let (line1, line2) = split_icon_sys_title_with_limit(
+            &existing.title,
+            existing.linebreak_pos as usize,
+            self.icon_sys_title_char_limit,
+        );
+        self.icon_sys_title_line1 != line1
+            || self.icon_sys_title_line2 != line2
+            || self.icon_sys_state.differs_from(existing)
+    }
+
+    /// Icon files named by the project's own icon.sys that are not packed with it. The folder
+    /// is only rescanned when the configuration changes.
+    pub(crate) fn missing_icon_file_warnings(&mut self) -> Vec<psu_packer::ConfigWarning> {
+        let Some(folder) = self.packer_state.folder.clone() else {
+            return Vec::new();
+        };
+        let Ok(config) = self.config_from_state() else {
+            return Vec::new();
+        };
+        let cache = &mut self.project_scan_cache;
+        cache.refresh(&folder, &config);
+        cache
+            .missing_icon_files
+            .get_or_insert_with(|| psu_packer::missing_icon_file_warnings(&folder, &config))
+            .clone()
+    }
+
     pub fn icon_sys_state(&self) -> &IconSysState {
         &self.icon_sys_state
     }
@@ -375,9 +519,71 @@ impl PackerApp {
         self.icon_sys_state.clear_preset();
     }
 
+    /// Prompts for a preset JSON file and applies its colors and lights. Returns `true` when
+    /// the icon.sys settings changed.
+    pub(crate) fn import_icon_sys_preset(&mut self) -> bool {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("icon.sys preset", &["json"])
+            .pick_file()
+        else {
+            return false;
+        };
+
+        let preset = match fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|json| {
+                psu_packer::IconSysPresetFile::from_json(&json).map_err(|err| err.to_string())
+            }) {
+            Ok(preset) => preset,
+            Err(err) => {
+                self.set_error_message(format!("Failed to import {}: {err}", path.display()));
+                return false;
+            }
+        };
+
+        self.icon_sys_state.apply_preset_file(&preset);
+        self.clear_error_message();
+        self.packer_state.status = format!("Imported icon.sys preset \"{}\".", preset.name);
+        true
+    }
+
+    /// Saves the current colors and lights as a preset JSON file chosen by the user.
+    pub(crate) fn export_icon_sys_preset(&mut self) {
+        let name = if self.packer_state.folder_base_name.trim().is_empty() {
+            "Custom preset".to_string()
+        } else {
+            self.packer_state.folder_base_name.clone()
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("icon.sys preset", &["json"])
+            .set_file_name(format!("{name}.json"))
+            .save_file()
+        else {
+            return;
+        };
+
+        let result = self
+            .icon_sys_state
+            .to_preset_file(&name)
+            .to_json()
+            .map_err(|err| err.to_string())
+            .and_then(|json| fs::write(&path, json).map_err(|err| err.to_string()));
+        match result {
+            Ok(()) => {
+                self.clear_error_message();
+                self.packer_state.status =
+                    format!("Exported icon.sys preset to {}.", path.display());
+            }
+            Err(err) => {
+                self.set_error_message(format!("Failed to export {}: {err}", path.display()));
+            }
+        }
+    }
+
     pub(crate) fn reset_metadata_fields(&mut self) {
         self.packer_state.reset_metadata_fields();
         self.reset_icon_sys_fields();
+        self.clear_icon_sys_variants();
     }
 
     pub(crate) fn metadata_inputs_changed(&mut self, previous_default_output: Option<String>) {
@@ -420,7 +626,8 @@ impl PackerApp {
             missing_required_files,
         } = preparation;
 
-        if missing_required_files.is_empty() {
+        let overwrites_output = output_path.exists();
+        if missing_required_files.is_empty() && !overwrites_output {
             self.begin_pack_job(folder, output_path, config);
         } else {
             self.packer_state.pending_pack_action = Some(PendingPackAction::Pack {
@@ -428,6 +635,7 @@ impl PackerApp {
                 output_path,
                 config,
                 missing_required_files,
+                overwrites_output,
             });
             self.packer_state.request_pack_confirmation();
         }
@@ -437,6 +645,63 @@ impl PackerApp {
         self.trigger_action(Action::PackPsu);
     }
 
+    /// Runs the checks a pack would, without packing, and reports every problem found: the
+    /// configuration (including the icon.sys title), missing required files and title.cfg's
+    /// mandatory fields and values. Returns whether validation passed.
+    pub(crate) fn handle_validate_request(&mut self) -> bool {
+        let Some(folder) = self.packer_state.folder.clone() else {
+            self.set_error_message("Please select a folder");
+            return false;
+        };
+
+        let mut problems = Vec::new();
+        if let Err(message) = self
+            .build_config()
+            .and_then(|config| self.check_duplicate_file_names(&folder, &config))
+        {
+            problems.push(message);
+        }
+        for missing in self
+            .packer_state
+            .missing_required_project_files_for(&folder)
+        {
+            problems.push(format!(
+                "{} is missing from the project folder",
+                missing.name
+            ));
+        }
+        if folder.join("title.cfg").is_file() {
+            if let Some(fields) = self
+                .title_cfg_editor
+                .title_cfg_missing_fields()
+                .filter(|fields| !fields.is_empty())
+            {
+                problems.push(format!(
+                    "title.cfg is missing mandatory fields: {}",
+                    fields.join(", ")
+                ));
+            }
+            if let Some(errors) = self.title_cfg_editor.title_cfg_value_errors() {
+                problems.extend(
+                    errors
+                        .iter()
+                        .map(|(key, message)| format!("title.cfg {key}: {message}")),
+                );
+            }
+        }
+
+        if problems.is_empty() {
+            self.clear_error_message();
+            self.packer_state.status =
+                "Validation passed. The project is ready to pack.".to_string();
+            true
+        } else {
+            self.packer_state.status.clear();
+            self.set_error_message(("Validation failed".to_string(), problems));
+            false
+        }
+    }
+
     fn process_update_psu_request(&mut self) {
         if self.is_pack_running() {
             return;
@@ -457,6 +722,11 @@ impl PackerApp {
             }
         };
 
+        if let Err(message) = self.packer_state.check_update_destination(&destination) {
+            self.set_error_message(message);
+            return;
+        }
+
         if !destination.exists() {
             self.set_error_message(format!(
                 "Cannot update because {} does not exist.",
@@ -497,6 +767,20 @@ impl PackerApp {
 
         let PackPreparation { folder, config, .. } = preparation;
 
+        let read_only = fs::metadata(&destination)
+            .map(|metadata| metadata.permissions().readonly())
+            .unwrap_or(false);
+        if read_only {
+            if let Err(err) = set_file_read_only(&destination, false) {
+                self.set_error_message(format!(
+                    "Cannot update {} because it is read-only and could not be made writable: {err}",
+                    destination.display()
+                ));
+                return;
+            }
+            self.writable_update_output = Some(destination.clone());
+        }
+
         self.packer_state.temp_workspace = temp_workspace_to_hold;
         self.begin_pack_job(folder, destination, config);
     }
@@ -545,6 +829,98 @@ impl PackerApp {
         }
     }
 
+    /// Entry list of the loaded PSU, or of the archive the selected project would produce.
+    pub(crate) fn entry_list_info(&self) -> Result<psu_packer::PsuInfo, String> {
+        if let Some(path) = &self.packer_state.loaded_psu_path {
+            let bytes = gui_core::state::read_psu_bytes(path).map_err(|err| err.to_string())?;
+            return psu_packer::PsuInfo::from_psu_bytes(&bytes)
+                .map_err(|err| format!("Failed to inspect {}: {err}", path.display()));
+        }
+
+        let folder = self
+            .packer_state
+            .folder
+            .as_ref()
+            .ok_or_else(|| "Select a project folder or load a PSU file first.".to_string())?;
+        let config = self.build_config()?;
+        psu_packer::PsuInfo::from_project(folder, &config)
+            .map_err(|err| format!("Failed to inspect {}: {err}", folder.display()))
+    }
+
+    /// The output path, when a previously packed PSU already exists there.
+    pub(crate) fn existing_output_path(&self) -> Option<PathBuf> {
+        let output = self.packer_state.output.trim();
+        let path = PathBuf::from(output);
+        (!output.is_empty() && path.is_file()).then_some(path)
+    }
+
+    /// Compares the project folder with the PSU at the output path and keeps the result for
+    /// display.
+    pub(crate) fn refresh_pending_changes(&mut self) {
+        let Some(output) = self.existing_output_path() else {
+            self.pending_changes = None;
+            return;
+        };
+        let result = match (&self.packer_state.folder, self.build_config()) {
+            (None, _) => Err("Select a project folder first.".to_string()),
+            (Some(_), Err(message)) => Err(message),
+            (Some(folder), Ok(config)) => psu_packer::pending_merged_changes(
+                folder,
+                &self.packer_state.merge_folders,
+                &config,
+                self.packer_state.merge_conflict_policy,
+                &output,
+            )
+            .map_err(|err| format!("Failed to compare with {}: {err}", output.display())),
+        };
+        self.pending_changes = Some((output, result));
+    }
+
+    fn process_export_entry_list_csv(&mut self) {
+        let info = match self.entry_list_info() {
+            Ok(info) => info,
+            Err(message) => {
+                self.set_error_message(message);
+                return;
+            }
+        };
+
+        let file_name = match self.packer_state.psu_file_base_name.trim() {
+            "" => "entries.csv".to_string(),
+            base => format!("{base}.csv"),
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name(file_name)
+            .save_file()
+        else {
+            return;
+        };
+
+        match fs::write(&path, info.to_csv()) {
+            Ok(()) => {
+                self.clear_error_message();
+                self.packer_state.status = format!("Exported entry list to {}", path.display());
+            }
+            Err(err) => {
+                self.set_error_message(format!("Failed to write {}: {err}", path.display()));
+            }
+        }
+    }
+
+    fn reveal_output(&mut self) {
+        let Some(output) = self.existing_output_path() else {
+            return;
+        };
+        let folder = match output.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        if let Err(err) = open_in_file_manager(&folder) {
+            self.set_error_message(format!("Failed to open {}: {err}", folder.display()));
+        }
+    }
+
     pub(crate) fn handle_save_as_folder_with_contents(&mut self) {
         self.trigger_action(Action::ExportPsuToFolder);
     }
@@ -608,6 +984,11 @@ impl PackerApp {
                 }
             },
         };
+        if let Err(err) = self.check_duplicate_file_names(&folder, &config) {
+            self.set_error_message(err);
+            self.packer_state.pending_pack_action = None;
+            return None;
+        }
 
         Some(PackPreparation {
             folder,
@@ -686,7 +1067,7 @@ impl PackerApp {
                 file.set_extension("psu");
             }
 
-            self.packer_state.output = file.display().to_string();
+            self.apply_state_event(AppEvent::SetOutput(file));
             true
         } else {
             false
@@ -702,6 +1083,47 @@ impl PackerApp {
             return false;
         };
 
+        self.add_file_paths(kind, &folder, paths)
+    }
+
+    /// Handles files dropped onto the window. A `.psu` file is opened; other files are added
+    /// to the include list of the open project. Folders are rejected with a status message.
+    pub(crate) fn handle_dropped_files(&mut self, paths: Vec<PathBuf>) {
+        let (directories, paths): (Vec<_>, Vec<_>) =
+            paths.into_iter().partition(|path| path.is_dir());
+        if !directories.is_empty() {
+            self.packer_state.status = format!(
+                "Dropped folders cannot be added: {}",
+                directories
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+
+        let (psu_files, files): (Vec<_>, Vec<_>) = paths
+            .into_iter()
+            .partition(|path| gui_core::state::is_psu_path(path));
+        if let Some(psu) = psu_files.into_iter().next() {
+            self.open_psu_file(psu);
+            return;
+        }
+
+        if files.is_empty() {
+            return;
+        }
+        let Some(folder) = self.packer_state.folder.clone() else {
+            self.packer_state.status =
+                "Open a project folder before dropping files to include.".to_string();
+            return;
+        };
+        if self.add_file_paths(FileListKind::Include, &folder, files) {
+            self.refresh_psu_toml_editor();
+        }
+    }
+
+    fn add_file_paths(&mut self, kind: FileListKind, folder: &Path, paths: Vec<PathBuf>) -> bool {
         if paths.is_empty() {
             return false;
         }
@@ -710,7 +1132,7 @@ impl PackerApp {
         let mut added_any = false;
 
         for path in paths {
-            let Ok(relative) = path.strip_prefix(&folder) else {
+            let Ok(relative) = path.strip_prefix(folder) else {
                 invalid_entries.push(format!(
                     "{} (must be in the selected folder)",
                     path.display()
@@ -782,10 +1204,7 @@ impl PackerApp {
     pub(crate) fn remove_selected_file_from_list(&mut self, kind: FileListKind) -> bool {
         let selection = self.packer_state.file_list_selection(kind);
         if let Some(index) = selection {
-            let removed = self
-                .packer_state
-                .remove_file_list_entry(kind, index)
-                .is_some();
+            let removed = self.apply_state_event(AppEvent::RemoveFileListEntry { kind, index });
             if removed {
                 self.clear_error_message();
                 self.packer_state.status.clear();
@@ -816,11 +1235,11 @@ impl PackerApp {
             .add_file_list_entry(kind, trimmed.to_string()))
     }
 
-    fn determine_update_destination(&self) -> Result<PathBuf, String> {
+    fn determine_update_destination(&self) -> Result<PathBuf, StateError> {
         self.packer_state.determine_update_destination()
     }
 
-    fn determine_export_source_path(&self) -> Result<PathBuf, String> {
+    fn determine_export_source_path(&self) -> Result<PathBuf, StateError> {
         self.packer_state.determine_export_source_path()
     }
 
@@ -828,16 +1247,17 @@ impl PackerApp {
         &self,
         source_path: &Path,
         destination_parent: &Path,
-    ) -> Result<PathBuf, String> {
+    ) -> Result<PathBuf, StateError> {
         self.packer_state
             .export_psu_to_folder(source_path, destination_parent)
     }
 
-    fn prepare_loaded_psu_workspace(&self) -> Result<(TempDir, PathBuf), String> {
+    fn prepare_loaded_psu_workspace(&self) -> Result<(TempDir, PathBuf), StateError> {
         self.packer_state.prepare_loaded_psu_workspace()
     }
 
     pub(crate) fn reload_project_files(&mut self) {
+        self.project_scan_cache.invalidate();
         if let Some(folder) = self.packer_state.folder.clone() {
             load_text_file_into_editor(folder.as_path(), "psu.toml", &mut self.psu_toml_editor);
             load_text_file_into_editor(folder.as_path(), "title.cfg", &mut self.title_cfg_editor);
@@ -880,9 +1300,19 @@ impl PackerApp {
         let psu_packer::Config {
             name,
             timestamp,
+            no_timestamp,
             include,
             exclude,
             icon_sys,
+            icon_sys_variants,
+            icon_sys_variant,
+            root_size_excludes_dot_entries,
+            file_timestamps,
+            preserve_include_order,
+            include_from_manifest,
+            verify_after_pack,
+            root_dir_name,
+            generate_icon_sys,
         } = config;
 
         self.set_folder_name_from_full(&name);
@@ -896,6 +1326,7 @@ impl PackerApp {
             } else {
                 TimestampStrategy::None
             });
+        self.packer_state.no_timestamp_requested = no_timestamp;
         self.metadata_inputs_changed(previous_default_output);
 
         self.packer_state
@@ -917,6 +1348,15 @@ impl PackerApp {
                 }
             }
         }
+        self.icon_sys_variants = icon_sys_variants;
+        self.icon_sys_variant = icon_sys_variant;
+        self.root_size_excludes_dot_entries = root_size_excludes_dot_entries;
+        self.file_timestamps = file_timestamps;
+        self.preserve_include_order = preserve_include_order;
+        self.include_from_manifest = include_from_manifest;
+        self.verify_after_pack = verify_after_pack;
+        self.root_dir_name = root_dir_name;
+        self.generate_icon_sys = generate_icon_sys;
 
         self.psu_toml_sync_blocked = false;
         self.clear_error_message();
@@ -938,11 +1378,30 @@ impl PackerApp {
             return false;
         }
 
+        if let Some(errors) = self
+            .title_cfg_editor
+            .title_cfg_value_errors()
+            .filter(|errors| !errors.is_empty())
+        {
+            let details = errors
+                .iter()
+                .map(|(key, message)| format!("{key}: {message}"))
+                .collect::<Vec<_>>()
+                .join("; ");
+            self.set_error_message(format!("title.cfg has invalid values. {details}."));
+            return false;
+        }
+
         self.clear_error_message();
         self.packer_state.status = "Validated title.cfg contents.".to_string();
         true
     }
 
+    /// Whether the psu.toml or title.cfg editor holds changes that are not saved to disk.
+    fn has_unsaved_text_edits(&self) -> bool {
+        self.psu_toml_editor.modified || self.title_cfg_editor.modified
+    }
+
     fn clear_text_editors(&mut self) {
         #[cfg(feature = "psu-toml-editor")]
         {
@@ -1048,6 +1507,36 @@ impl PackerApp {
         self.active_editor = EditorAction::PsuToml;
     }
 
+    /// The file name and editor of the open text editor tab, if any.
+    fn active_text_editor(&self) -> Option<(&'static str, &TextFileEditor)> {
+        match self.active_editor {
+            #[cfg(feature = "psu-toml-editor")]
+            EditorAction::PsuToml => Some(("psu.toml", &self.psu_toml_editor)),
+            EditorAction::TitleCfg => Some(("title.cfg", &self.title_cfg_editor)),
+            _ => None,
+        }
+    }
+
+    /// Writes the psu.toml or title.cfg editor contents to the project folder.
+    pub(crate) fn save_text_editor(&mut self, file_name: &str) {
+        let folder = self.packer_state.folder.clone();
+        let editor = if file_name == "psu.toml" {
+            &mut self.psu_toml_editor
+        } else {
+            &mut self.title_cfg_editor
+        };
+        match save_editor_to_disk(folder.as_deref(), file_name, editor) {
+            Ok(path) => {
+                self.packer_state.status = format!("Saved {}", path.display());
+                self.clear_error_message();
+                self.project_scan_cache.invalidate();
+            }
+            Err(err) => {
+                self.set_error_message(format!("Failed to save {file_name}: {err}"));
+            }
+        }
+    }
+
     pub(crate) fn open_title_cfg_tab(&mut self) {
         self.active_editor = EditorAction::TitleCfg;
     }
@@ -1113,50 +1602,156 @@ impl PackerApp {
 
     pub(crate) fn poll_pack_job(&mut self) {
         if let Some(outcome) = self.packer_state.poll_pack_job() {
+            let writable_update_output = self.writable_update_output.take();
             match outcome {
-                PackOutcome::Success { output_path } => {
+                PackOutcome::Success {
+                    output_path,
+                    saved_config,
+                    read_only,
+                } => {
                     self.packer_state.status = format!("Packed to {}", output_path.display());
-                    self.clear_error_message();
+                    let mut failures = Vec::new();
+                    match saved_config {
+                        Some(Ok(config_path)) => {
+                            self.packer_state.status.push_str(&format!(
+                                "; saved configuration to {}",
+                                config_path.display()
+                            ));
+                        }
+                        Some(Err(error)) => {
+                            failures.push(format!("failed to save psu.toml: {error}"));
+                        }
+                        None => {}
+                    }
+                    match read_only {
+                        Some(Ok(())) => self.packer_state.status.push_str("; marked read-only"),
+                        Some(Err(error)) => {
+                            failures.push(format!("failed to mark it read-only: {error}"));
+                        }
+                        None => {}
+                    }
+                    if failures.is_empty() {
+                        self.clear_error_message();
+                    } else {
+                        self.set_error_message(format!(
+                            "Packed to {}, but {}",
+                            output_path.display(),
+                            failures.join(" and ")
+                        ));
+                    }
                 }
                 PackOutcome::Error {
                     folder,
                     output_path,
                     error,
                 } => {
-                    let message = self
-                        .packer_state
-                        .format_pack_error(&folder, &output_path, error);
+                    let mut message =
+                        self.packer_state
+                            .format_pack_error(&folder, &output_path, error);
+                    if writable_update_output.as_ref() == Some(&output_path) && output_path.exists()
+                    {
+                        if let Err(err) = set_file_read_only(&output_path, true) {
+                            message.push_str(&format!(
+                                "\nIt could not be marked read-only again: {err}"
+                            ));
+                        }
+                    }
+                    if let Some(workspace) = &self.packer_state.retained_temp_workspace {
+                        message.push_str(&format!(
+                            "\nThe extracted workspace was kept at {}.",
+                            workspace.display()
+                        ));
+                    }
                     self.set_error_message(message);
                 }
             }
         }
     }
 
+    /// Drains the events queued by the shared state. State-only events go through
+    /// [`apply_event`]; dialogs and file operations run where their actions are triggered, so
+    /// of the rest only pack results need handling here.
+    pub(crate) fn process_events(&mut self) {
+        for event in self.packer_state.take_events() {
+            if self.apply_state_event(event) {
+                self.refresh_psu_toml_editor();
+            }
+        }
+    }
+
+    /// Applies `event` to the shared state through [`apply_event`], handling it here when it
+    /// needs the frontend. Returns whether the state changed.
+    fn apply_state_event(&mut self, event: AppEvent) -> bool {
+        let event = match apply_event(&mut self.packer_state, event) {
+            EventResult::Applied { changed } => return changed,
+            EventResult::PassThrough(event) => event,
+        };
+        match event {
+            AppEvent::PackFinished { output_path } => {
+                let compared_output = self
+                    .pending_changes
+                    .as_ref()
+                    .is_some_and(|(path, _)| *path == output_path);
+                if compared_output {
+                    self.refresh_pending_changes();
+                }
+            }
+            // `poll_pack_job` already reported the failure.
+            AppEvent::PackFailed { .. } => {}
+            _ => {}
+        }
+        false
+    }
+
     pub(crate) fn build_config(&self) -> Result<psu_packer::Config, String> {
         self.validate_icon_sys_settings()?;
         self.config_from_state()
     }
 
-    fn config_from_state(&self) -> Result<psu_packer::Config, String> {
-        let include = if self.packer_state.include_files.is_empty() {
-            None
-        } else {
-            Some(self.packer_state.include_files.clone())
-        };
-
-        let mut exclude = self.packer_state.exclude_files.clone();
-        if !exclude.iter().any(|entry| entry == "psu.toml") {
-            exclude.push("psu.toml".to_string());
+    /// Rejects packing `folder` when two of its files, or a file in a merge folder, would end
+    /// up with the same entry name. This scans the folders, so it only runs when a pack starts
+    /// or the project is validated.
+    fn check_duplicate_file_names(
+        &self,
+        folder: &Path,
+        config: &psu_packer::Config,
+    ) -> Result<(), String> {
+        if !folder.is_dir() {
+            return Ok(());
         }
+        psu_packer::check_duplicate_file_names(folder, &self.packer_state.merge_folders, config)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Names in both file lists. These are not errors because exclude wins, but the user
+    /// probably meant to pack them.
+    pub(crate) fn file_list_warnings(&self) -> Vec<psu_packer::ConfigWarning> {
+        self.config_from_state()
+            .map(|config| psu_packer::include_exclude_overlap_warnings(&config))
+            .unwrap_or_default()
+    }
+
+    /// Size of the PSU that packing the current folder would produce, if it can be computed.
+    /// The folder is only rescanned when the configuration changes.
+    pub(crate) fn estimated_pack_size(&mut self) -> Option<u64> {
+        let folder = self.packer_state.folder.clone()?;
+        let config = self.build_config().ok()?;
+        let cache = &mut self.project_scan_cache;
+        cache.refresh(&folder, &config);
+        *cache
+            .estimated_size
+            .get_or_insert_with(|| psu_packer::estimate_packed_size(&folder, &config).ok())
+    }
+
+    fn config_from_state(&self) -> Result<psu_packer::Config, String> {
+        let (include, exclude) = self.packer_state.pack_file_lists();
         let exclude = Some(exclude);
 
         let icon_sys = if self.icon_sys_enabled && !self.icon_sys_use_existing {
-            let encoded_line1 = sjis::encode_sjis(&self.icon_sys_title_line1).map_err(|_| {
-                "Icon.sys titles must contain characters representable in Shift-JIS".to_string()
-            })?;
-            let linebreak_pos = encoded_line1.len() as u16;
-            let combined_title =
-                format!("{}{}", self.icon_sys_title_line1, self.icon_sys_title_line2);
+            let (combined_title, linebreak_pos) = psu_packer::IconSysConfig::title_from_lines(
+                &self.icon_sys_title_line1,
+                &self.icon_sys_title_line2,
+            )?;
             let flag_value = self.selected_icon_flag_value()?;
 
             Some(psu_packer::IconSysConfig {
@@ -1183,9 +1778,23 @@ impl PackerApp {
         Ok(psu_packer::Config {
             name,
             timestamp: self.packer_state.timestamp,
+            no_timestamp: self.packer_state.no_timestamp_requested
+                && self.packer_state.timestamp_strategy == TimestampStrategy::None,
             include,
             exclude,
             icon_sys,
+            icon_sys_variants: self.icon_sys_variants.clone(),
+            icon_sys_variant: self
+                .icon_sys_variant
+                .clone()
+                .filter(|_| !self.icon_sys_use_existing),
+            root_size_excludes_dot_entries: self.root_size_excludes_dot_entries,
+            file_timestamps: self.file_timestamps.clone(),
+            preserve_include_order: self.preserve_include_order,
+            include_from_manifest: self.include_from_manifest.clone(),
+            verify_after_pack: self.verify_after_pack,
+            root_dir_name: self.root_dir_name.clone(),
+            generate_icon_sys: self.generate_icon_sys,
         })
     }
 
@@ -1193,16 +1802,13 @@ impl PackerApp {
         if self.icon_sys_enabled && !self.icon_sys_use_existing {
             let line1 = &self.icon_sys_title_line1;
             let line2 = &self.icon_sys_title_line2;
+            let limit = self.icon_sys_title_char_limit;
 
-            if line1.chars().count() > ICON_SYS_TITLE_CHAR_LIMIT {
-                return Err(format!(
-                    "Icon.sys line 1 cannot exceed {ICON_SYS_TITLE_CHAR_LIMIT} characters"
-                ));
+            if line1.chars().count() > limit {
+                return Err(format!("Icon.sys line 1 cannot exceed {limit} characters"));
             }
-            if line2.chars().count() > ICON_SYS_TITLE_CHAR_LIMIT {
-                return Err(format!(
-                    "Icon.sys line 2 cannot exceed {ICON_SYS_TITLE_CHAR_LIMIT} characters"
-                ));
+            if line2.chars().count() > limit {
+                return Err(format!("Icon.sys line 2 cannot exceed {limit} characters"));
             }
             let title_is_valid = |value: &str| {
                 !value.chars().any(|c| c.is_control()) && sjis::is_roundtrip_sjis(value)
@@ -1214,6 +1820,8 @@ impl PackerApp {
                 );
             }
 
+            psu_packer::check_icon_sys_title_length(&format!("{line1}{line2}"))?;
+
             let has_content = line1.chars().any(|c| !c.is_whitespace())
                 || line2.chars().any(|c| !c.is_whitespace());
             if !has_content {
@@ -1274,17 +1882,37 @@ impl ActionDispatcher for PackerApp {
     fn is_action_enabled(&self, action: Action) -> bool {
         match action {
             Action::PackPsu | Action::UpdatePsu | Action::ExportPsuToFolder => {
+                self.packer_state.pack_readiness().can_start()
+            }
+            Action::ValidateProject => {
+                !self.is_pack_running() && self.packer_state.folder.is_some()
+            }
+            Action::RenameLoadedPsuRoot | Action::ConfirmRenameLoadedPsuRoot => {
+                !self.is_pack_running() && self.packer_state.loaded_psu_path.is_some()
+            }
+            Action::ExportEntryListCsv => {
                 !self.is_pack_running()
-                    && self.packer_state.missing_required_project_files.is_empty()
+                    && (self.packer_state.loaded_psu_path.is_some()
+                        || self.packer_state.folder.is_some())
             }
+            Action::RevealOutput => self.existing_output_path().is_some(),
             Action::ChooseOutputDestination => !self.is_pack_running(),
             Action::SelectProjectFolder => !self.is_pack_running(),
+            Action::ReloadProject | Action::ConfirmReloadProject => {
+                !self.is_pack_running() && self.has_source()
+            }
             Action::ConfirmPack | Action::CancelPack => {
                 self.packer_state.pending_pack_action.is_some()
             }
             Action::ShowExitConfirmation => !self.exit_confirmed,
             Action::ConfirmExit | Action::CancelExit => self.show_exit_confirm,
             Action::ZoomIn | Action::ZoomOut | Action::ResetZoom => true,
+            Action::SaveFile => {
+                self.packer_state.folder.is_some()
+                    && self
+                        .active_text_editor()
+                        .is_some_and(|(_, editor)| editor.modified)
+            }
             #[cfg(feature = "psu-toml-editor")]
             Action::EditMetadata(MetadataTarget::PsuToml)
             | Action::CreateMetadataTemplate(MetadataTarget::PsuToml) => true,
@@ -1300,6 +1928,12 @@ impl ActionDispatcher for PackerApp {
                 FileListKind::Exclude => self.packer_state.selected_exclude.is_some(),
             },
             Action::FileList(FileListAction::SelectEntry(_, _)) => true,
+            Action::FileList(FileListAction::Undo) => {
+                self.packer_state.file_list_history.can_undo()
+            }
+            Action::FileList(FileListAction::Redo) => {
+                self.packer_state.file_list_history.can_redo()
+            }
             Action::IconSys(icon_action) => match icon_action {
                 IconSysAction::UseExisting => {
                     self.icon_sys_enabled && self.icon_sys_existing.is_some()
@@ -1323,9 +1957,57 @@ impl ActionDispatcher for PackerApp {
             Action::SelectProjectFolder => {
                 self.select_project_folder_dialog();
             }
+            Action::ReloadProject => {
+                if self.has_unsaved_text_edits() {
+                    self.packer_state.request_confirmation(
+                        RELOAD_CONFIRMATION_ID,
+                        "Reloading discards unsaved changes to psu.toml and title.cfg. Reload anyway?",
+                        Action::ConfirmReloadProject,
+                    );
+                } else {
+                    self.reload_project_files();
+                }
+            }
+            Action::ConfirmReloadProject => self.reload_project_files(),
+            Action::SaveFile => {
+                if let Some((file_name, _)) = self.active_text_editor() {
+                    self.save_text_editor(file_name);
+                }
+            }
             Action::PackPsu => self.process_pack_request(),
+            Action::ValidateProject => {
+                self.handle_validate_request();
+            }
             Action::UpdatePsu => self.process_update_psu_request(),
             Action::ExportPsuToFolder => self.process_save_as_folder_with_contents(),
+            Action::ExportEntryListCsv => self.process_export_entry_list_csv(),
+            Action::RevealOutput => self.reveal_output(),
+            Action::RenameLoadedPsuRoot => {
+                if let Some(path) = self.packer_state.loaded_psu_path.clone() {
+                    self.packer_state.request_confirmation(
+                        RENAME_ROOT_CONFIRMATION_ID,
+                        format!(
+                            "Renaming the internal folder to {} overwrites {} in place. Continue?",
+                            self.packer_state.folder_name(),
+                            path.display()
+                        ),
+                        Action::ConfirmRenameLoadedPsuRoot,
+                    );
+                }
+            }
+            Action::ConfirmRenameLoadedPsuRoot => {
+                match self.packer_state.rename_loaded_psu_root() {
+                    Ok(path) => {
+                        self.clear_error_message();
+                        self.packer_state.status = format!(
+                            "Renamed internal folder of {} to {}",
+                            path.display(),
+                            self.packer_state.folder_name()
+                        );
+                    }
+                    Err(err) => self.set_error_message(err),
+                }
+            }
             Action::ChooseOutputDestination => {
                 self.packer_state.request_output_destination_dialog();
                 self.choose_output_destination_dialog();
@@ -1396,12 +2078,12 @@ impl ActionDispatcher for PackerApp {
                     self.refresh_psu_toml_editor();
                 }
                 MetadataAction::SelectPrefix(prefix) => {
-                    if self.packer_state.set_selected_prefix(prefix) {
+                    if self.apply_state_event(AppEvent::SelectPrefix(prefix)) {
                         self.refresh_psu_toml_editor();
                     }
                 }
                 MetadataAction::SetFolderBaseName(base_name) => {
-                    if self.packer_state.set_folder_base_name(base_name) {
+                    if self.apply_state_event(AppEvent::SetFolderBaseName(base_name)) {
                         self.refresh_psu_toml_editor();
                     }
                 }
@@ -1410,6 +2092,11 @@ impl ActionDispatcher for PackerApp {
                         self.refresh_psu_toml_editor();
                     }
                 }
+                MetadataAction::StripRedundantPrefix => {
+                    if self.packer_state.strip_redundant_prefix() {
+                        self.refresh_psu_toml_editor();
+                    }
+                }
             },
             Action::Timestamp(timestamp_action) => match timestamp_action {
                 TimestampAction::SelectStrategy(strategy_action) => {
@@ -1417,9 +2104,12 @@ impl ActionDispatcher for PackerApp {
                         TimestampStrategyAction::None => TimestampStrategy::None,
                         TimestampStrategyAction::InheritSource => TimestampStrategy::InheritSource,
                         TimestampStrategyAction::SasRules => TimestampStrategy::SasRules,
+                        TimestampStrategyAction::NewestFile => TimestampStrategy::NewestFile,
                         TimestampStrategyAction::Manual => TimestampStrategy::Manual,
                     };
-                    self.set_timestamp_strategy(strategy);
+                    if self.packer_state.select_timestamp_strategy(strategy) {
+                        self.refresh_psu_toml_editor();
+                    }
                 }
                 TimestampAction::RefreshFromStrategy => {
                     self.refresh_timestamp_from_strategy();
@@ -1433,6 +2123,9 @@ impl ActionDispatcher for PackerApp {
                 TimestampAction::ResetRulesToDefault => {
                     self.reset_timestamp_rules_to_default();
                 }
+                TimestampAction::ApplyRulesPreset(preset_id) => {
+                    self.apply_timestamp_rules_preset(&preset_id);
+                }
                 TimestampAction::SetManualTimestamp(timestamp) => {
                     if self.packer_state.set_manual_timestamp(timestamp) {
                         self.refresh_psu_toml_editor();
@@ -1484,6 +2177,24 @@ impl ActionDispatcher for PackerApp {
                             self.mark_timestamp_rules_modified();
                         }
                     }
+                    TimestampRulesAction::SelectAllAliases(index) => {
+                        if self
+                            .packer_state
+                            .timestamp_rules_ui
+                            .select_all_aliases(index)
+                        {
+                            self.mark_timestamp_rules_modified();
+                        }
+                    }
+                    TimestampRulesAction::ClearAllAliases(index) => {
+                        if self
+                            .packer_state
+                            .timestamp_rules_ui
+                            .clear_all_aliases(index)
+                        {
+                            self.mark_timestamp_rules_modified();
+                        }
+                    }
                 },
             },
             Action::FileList(file_action) => match file_action {
@@ -1523,8 +2234,18 @@ impl ActionDispatcher for PackerApp {
                 FileListAction::SelectEntry(kind, selection) => {
                     self.packer_state.select_file_list_entry(kind, selection);
                 }
-            },
-            Action::IconSys(icon_action) => match icon_action {
+                FileListAction::Undo => {
+                    if self.packer_state.undo_file_list() {
+                        self.refresh_psu_toml_editor();
+                    }
+                }
+                FileListAction::Redo => {
+                    if self.packer_state.redo_file_list() {
+                        self.refresh_psu_toml_editor();
+                    }
+                }
+            },
+            Action::IconSys(icon_action) => match icon_action {
                 IconSysAction::Enable => {
                     if !self.icon_sys_enabled {
                         self.icon_sys_enabled = true;
@@ -1588,7 +2309,6 @@ impl ActionDispatcher for PackerApp {
                 cfg!(feature = "psu-toml-editor")
             }
             Action::AddFiles
-            | Action::SaveFile
             | Action::OpenSettings
             | Action::CreateMetadataTemplate(MetadataTarget::IconSys) => false,
             _ => true,
@@ -1596,6 +2316,31 @@ impl ActionDispatcher for PackerApp {
     }
 }
 
+/// Id of the confirmation asked for before a reload discards unsaved editor changes.
+const RELOAD_CONFIRMATION_ID: &str = "reload_project";
+
+/// Id of the confirmation asked for before the loaded PSU is rewritten with a renamed root.
+const RENAME_ROOT_CONFIRMATION_ID: &str = "rename_loaded_psu_root";
+
+#[cfg(target_os = "windows")]
+const FILE_MANAGER_OPENER: &str = "explorer";
+#[cfg(target_os = "macos")]
+const FILE_MANAGER_OPENER: &str = "open";
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const FILE_MANAGER_OPENER: &str = "xdg-open";
+
+/// Launches the platform file manager on `folder`. The opener is waited on from a background
+/// thread so it does not linger as a zombie process once it exits.
+fn open_in_file_manager(folder: &Path) -> io::Result<()> {
+    let mut child = std::process::Command::new(FILE_MANAGER_OPENER)
+        .arg(folder)
+        .spawn()?;
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
 fn load_text_file_into_editor(folder: &Path, file_name: &str, editor: &mut TextFileEditor) {
     let path = folder.join(file_name);
     match fs::read_to_string(&path) {
@@ -1634,6 +2379,60 @@ mod packer_app_tests {
         }
     }
 
+    #[test]
+    fn reload_project_action_picks_up_external_title_cfg_edits() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        fs::write(project_dir.join("title.cfg"), "title=Before\n").expect("write title.cfg");
+
+        let mut app = PackerApp::default();
+        assert!(!app.is_action_enabled(Action::ReloadProject));
+
+        app.packer_state.folder = Some(project_dir.clone());
+        app.reload_project_files();
+        assert_eq!(app.title_cfg_editor.content, "title=Before\n");
+
+        fs::write(project_dir.join("title.cfg"), "title=After\n").expect("rewrite title.cfg");
+        assert!(app.is_action_enabled(Action::ReloadProject));
+        app.trigger_action(Action::ReloadProject);
+
+        assert_eq!(app.title_cfg_editor.content, "title=After\n");
+        assert!(!app.title_cfg_editor.modified);
+    }
+
+    #[test]
+    fn reload_project_asks_before_discarding_unsaved_edits() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        fs::write(project_dir.join("title.cfg"), "title=Saved\n").expect("write title.cfg");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir);
+        app.reload_project_files();
+        app.title_cfg_editor
+            .set_content("title=Edited\n".to_string());
+        app.title_cfg_editor.modified = true;
+
+        app.trigger_action(Action::ReloadProject);
+        assert_eq!(app.title_cfg_editor.content, "title=Edited\n");
+        let confirmation = app
+            .packer_state
+            .pending_confirmation
+            .clone()
+            .expect("reload confirmation");
+        assert_eq!(confirmation.id, RELOAD_CONFIRMATION_ID);
+
+        let action = app
+            .packer_state
+            .resolve_confirmation(&confirmation.id, true)
+            .expect("confirmed action");
+        app.trigger_action(action);
+        assert_eq!(app.title_cfg_editor.content, "title=Saved\n");
+        assert!(!app.title_cfg_editor.modified);
+    }
+
     #[test]
     fn metadata_inputs_fill_missing_psu_filename() {
         let workspace = tempdir().expect("temp workspace");
@@ -1707,7 +2506,18 @@ mod packer_app_tests {
             "missing files should be tracked"
         );
 
+        let confirmation = app
+            .packer_state
+            .pending_confirmation
+            .clone()
+            .expect("pack confirmation is shown");
+        assert!(confirmation.message.contains("Pack anyway?"));
+
         let missing_before = app.packer_state.missing_required_project_files.clone();
+        let answer = app
+            .packer_state
+            .resolve_confirmation(&confirmation.id, false);
+        assert_eq!(answer, Some(Action::CancelPack));
         app.trigger_action(Action::CancelPack);
 
         assert!(
@@ -1746,6 +2556,7 @@ mod packer_app_tests {
             app.packer_state.pending_pack_action.is_none(),
             "confirmation accepted"
         );
+        assert!(app.packer_state.pending_confirmation.is_none());
         assert!(
             app.test_pack_job_started,
             "pack job should start after acceptance"
@@ -1789,6 +2600,79 @@ mod packer_app_tests {
         assert!(app.zoom_factor < 1.0);
     }
 
+    #[test]
+    fn validate_reports_missing_required_file_without_packing() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+        fs::remove_file(project_dir.join("icon.sys")).expect("remove icon.sys");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir);
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = workspace.path().join("SAVE.psu").display().to_string();
+
+        assert!(app.is_action_enabled(Action::ValidateProject));
+        app.trigger_action(Action::ValidateProject);
+
+        let error = app
+            .packer_state
+            .error_message
+            .as_deref()
+            .expect("validation should fail");
+        assert!(error.contains("Validation failed"), "{error}");
+        assert!(error.contains("icon.sys is missing"), "{error}");
+        assert!(app.packer_state.status.is_empty());
+        assert!(!app.test_pack_job_started);
+        assert!(app.packer_state.pending_pack_action.is_none());
+    }
+
+    #[test]
+    fn packing_over_existing_output_asks_for_confirmation() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+
+        let existing_output = workspace.path().join("existing.psu");
+        fs::write(&existing_output, b"old").expect("create placeholder output");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir);
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_psu_file_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = existing_output.display().to_string();
+
+        app.handle_pack_request();
+
+        let pending = app
+            .packer_state
+            .pending_pack_action
+            .as_ref()
+            .expect("overwrite confirmation should be pending");
+        assert_eq!(
+            pending.overwritten_output(),
+            Some(existing_output.as_path())
+        );
+        assert!(pending.missing_files().is_empty());
+        assert!(pending
+            .confirmation_message()
+            .contains("will be overwritten"));
+        assert!(!app.test_pack_job_started);
+        assert_eq!(fs::read(&existing_output).expect("read output"), b"old");
+
+        app.trigger_action(Action::ConfirmPack);
+        assert!(
+            app.test_pack_job_started,
+            "pack job should start after acceptance"
+        );
+        wait_for_pack_completion(&mut app);
+        assert_ne!(fs::read(&existing_output).expect("read output"), b"old");
+    }
+
     #[test]
     fn update_psu_overwrites_existing_file() {
         let workspace = tempdir().expect("temp workspace");
@@ -1824,6 +2708,365 @@ mod packer_app_tests {
         assert!(metadata.len() > 0, "packed PSU should not be empty");
     }
 
+    #[test]
+    fn read_only_output_is_made_writable_for_update_and_protected_again() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+
+        let existing_output = workspace.path().join("existing.psu");
+        fs::write(&existing_output, b"old").expect("create placeholder output");
+        set_file_read_only(&existing_output, true).expect("mark read-only");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir);
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_psu_file_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = existing_output.display().to_string();
+        app.packer_state.loaded_psu_path = Some(existing_output.clone());
+        app.packer_state.make_output_read_only = true;
+
+        app.handle_update_psu_request();
+        assert!(app.pack_job_active(), "pack job should start");
+        wait_for_pack_completion(&mut app);
+
+        assert!(
+            app.packer_state.error_message.is_none(),
+            "unexpected error: {:?}",
+            app.packer_state.error_message
+        );
+        assert!(app.packer_state.status.contains("marked read-only"));
+        assert_ne!(fs::read(&existing_output).expect("read output"), b"old");
+        let permissions = fs::metadata(&existing_output)
+            .expect("output metadata")
+            .permissions();
+        assert!(permissions.readonly());
+
+        set_file_read_only(&existing_output, false).expect("make writable");
+    }
+
+    #[test]
+    fn read_only_output_is_protected_again_when_the_update_fails() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+        let extra_dir = workspace.path().join("extra");
+        fs::create_dir_all(&extra_dir).expect("create merge folder");
+        fs::write(extra_dir.join("title.cfg"), b"conflict").expect("write conflicting file");
+
+        let existing_output = workspace.path().join("existing.psu");
+        fs::write(&existing_output, b"old").expect("create placeholder output");
+        set_file_read_only(&existing_output, true).expect("mark read-only");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir);
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_psu_file_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = existing_output.display().to_string();
+        app.packer_state.loaded_psu_path = Some(existing_output.clone());
+        app.packer_state.merge_folders = vec![extra_dir];
+        app.packer_state.merge_conflict_policy = psu_packer::MergeConflictPolicy::Error;
+
+        app.handle_update_psu_request();
+        assert!(app.pack_job_active(), "pack job should start");
+        wait_for_pack_completion(&mut app);
+
+        assert!(app.packer_state.error_message.is_some());
+        assert_eq!(fs::read(&existing_output).expect("read output"), b"old");
+        let permissions = fs::metadata(&existing_output)
+            .expect("output metadata")
+            .permissions();
+        assert!(permissions.readonly());
+
+        set_file_read_only(&existing_output, false).expect("make writable");
+    }
+
+    #[test]
+    fn successful_pack_writes_psu_toml_when_enabled() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+        let output = workspace.path().join("output.psu");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir.clone());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_psu_file_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = output.display().to_string();
+        app.packer_state.write_config_on_pack = true;
+
+        let config = app.build_config().expect("build config");
+        app.start_pack_job(project_dir.clone(), output, config);
+        wait_for_pack_completion(&mut app);
+
+        assert!(app.packer_state.error_message.is_none());
+        assert!(app.packer_state.status.contains("saved configuration"));
+        let saved = psu_packer::load_config(&project_dir).expect("load saved psu.toml");
+        assert_eq!(saved.name, "APP_SAVE");
+    }
+
+    #[test]
+    fn reveal_output_is_enabled_once_a_pack_writes_the_output() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+        let output = workspace.path().join("output.psu");
+
+        let mut app = PackerApp::default();
+        assert!(app.packer_state.output.is_empty());
+        assert!(!app.is_action_enabled(Action::RevealOutput));
+
+        app.packer_state.folder = Some(project_dir.clone());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_psu_file_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = output.display().to_string();
+        assert!(!app.is_action_enabled(Action::RevealOutput));
+
+        let config = app.build_config().expect("build config");
+        app.start_pack_job(project_dir, output, config);
+        wait_for_pack_completion(&mut app);
+
+        assert!(app.packer_state.status.starts_with("Packed to"));
+        assert!(app.is_action_enabled(Action::RevealOutput));
+    }
+
+    #[test]
+    fn queued_state_events_are_applied_by_the_event_loop() {
+        let mut app = PackerApp::default();
+        app.packer_state
+            .events
+            .push(AppEvent::SetFolderBaseName("GAME".to_string()));
+        app.packer_state
+            .events
+            .push(AppEvent::SelectPrefix(SasPrefix::Emu));
+
+        app.process_events();
+
+        assert!(app.packer_state.events.is_empty());
+        assert_eq!(app.packer_state.folder_name(), "EMU_GAME");
+    }
+
+    #[test]
+    fn finished_pack_event_refreshes_the_compared_changes() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+        let output = workspace.path().join("APP_SAVE.psu");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir.clone());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = output.display().to_string();
+
+        let config = app.build_config().expect("build config");
+        app.start_pack_job(project_dir.clone(), output.clone(), config.clone());
+        wait_for_pack_completion(&mut app);
+        fs::write(project_dir.join("EXTRA.BIN"), b"extra").expect("write extra file");
+        app.refresh_pending_changes();
+        assert!(matches!(&app.pending_changes, Some((_, Ok(changes))) if !changes.is_empty()));
+
+        app.start_pack_job(project_dir, output, config);
+        wait_for_pack_completion(&mut app);
+        app.process_events();
+
+        assert!(app.packer_state.events.is_empty());
+        assert!(matches!(&app.pending_changes, Some((_, Ok(changes))) if changes.is_empty()));
+    }
+
+    #[test]
+    fn entry_list_info_describes_project_and_loaded_psu() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+
+        let mut app = PackerApp::default();
+        assert!(app.entry_list_info().is_err());
+
+        app.packer_state.folder = Some(project_dir.clone());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        let planned = app.entry_list_info().expect("project entries");
+        assert_eq!(planned.entries[0].name, "APP_SAVE");
+        assert_eq!(planned.entries.len(), 3 + REQUIRED_PROJECT_FILES.len());
+
+        let output = workspace.path().join("APP_SAVE.psu");
+        let config = app.build_config().expect("build config");
+        psu_packer::pack_with_config(&project_dir, &output, config).expect("pack");
+        app.packer_state.loaded_psu_path = Some(output);
+        let packed = app.entry_list_info().expect("packed entries");
+        assert_eq!(packed.to_csv().lines().count(), planned.entries.len() + 1);
+    }
+
+    #[test]
+    fn renaming_the_loaded_psu_root_asks_before_rewriting_it() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir.clone());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        let output = workspace.path().join("APP_SAVE.psu");
+        let config = app.build_config().expect("build config");
+        psu_packer::pack_with_config(&project_dir, &output, config).expect("pack");
+        let original = fs::read(&output).expect("read packed PSU");
+
+        app.packer_state.loaded_psu_path = Some(output.clone());
+        app.packer_state.set_folder_base_name("RENAMED".to_string());
+        app.trigger_action(Action::RenameLoadedPsuRoot);
+        assert_eq!(fs::read(&output).expect("read PSU"), original);
+        let confirmation = app
+            .packer_state
+            .pending_confirmation
+            .clone()
+            .expect("rename confirmation");
+        assert_eq!(confirmation.id, RENAME_ROOT_CONFIRMATION_ID);
+
+        let action = app
+            .packer_state
+            .resolve_confirmation(&confirmation.id, true)
+            .expect("confirmed action");
+        app.trigger_action(action);
+        assert_ne!(fs::read(&output).expect("read PSU"), original);
+        assert_eq!(
+            app.packer_state.loaded_psu_root_name.as_deref(),
+            Some("APP_RENAMED")
+        );
+    }
+
+    #[test]
+    fn pending_changes_compare_project_with_existing_output() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir.clone());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        let output = workspace.path().join("APP_SAVE.psu");
+        app.packer_state.output = output.display().to_string();
+
+        app.refresh_pending_changes();
+        assert!(app.pending_changes.is_none());
+
+        let config = app.build_config().expect("build config");
+        psu_packer::pack_with_config(&project_dir, &output, config).expect("pack");
+        fs::write(project_dir.join("EXTRA.BIN"), b"extra").expect("write extra file");
+
+        app.refresh_pending_changes();
+        let (path, result) = app.pending_changes.as_ref().expect("comparison");
+        assert_eq!(path, &output);
+        let changes = result.as_ref().expect("changes");
+        assert!(changes.iter().any(
+            |change| change.name == "EXTRA.BIN" && change.kind == psu_packer::ChangeKind::Added
+        ));
+    }
+
+    #[test]
+    fn estimated_pack_size_is_cached_until_the_project_changes() {
+        let workspace = tempdir().expect("temp workspace");
+        write_required_files(workspace.path());
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(workspace.path().to_path_buf());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+
+        let before = app.estimated_pack_size().expect("estimate");
+        fs::write(workspace.path().join("EXTRA.BIN"), b"extra").expect("write extra file");
+        assert_eq!(app.estimated_pack_size(), Some(before));
+
+        app.packer_state.timestamp =
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1).and_then(|date| date.and_hms_opt(0, 0, 0));
+        let after = app.estimated_pack_size().expect("estimate");
+        assert!(after > before, "{after} should include EXTRA.BIN");
+
+        fs::write(workspace.path().join("MORE.BIN"), b"more").expect("write another file");
+        app.reload_project_files();
+        assert!(app.estimated_pack_size().expect("estimate") > after);
+    }
+
+    #[test]
+    fn pack_request_rejects_case_insensitive_duplicates() {
+        let workspace = tempdir().expect("temp workspace");
+        let project_dir = workspace.path().join("project");
+        fs::create_dir_all(&project_dir).expect("create project folder");
+        write_required_files(&project_dir);
+        fs::write(project_dir.join("DATA.BIN"), b"upper").expect("write file");
+        fs::write(project_dir.join("data.bin"), b"lower").expect("write file");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project_dir);
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state.output = workspace.path().join("SAVE.psu").display().to_string();
+
+        app.build_config()
+            .expect("duplicates are only checked when packing");
+        app.handle_pack_request();
+
+        let err = app
+            .packer_state
+            .error_message
+            .as_deref()
+            .expect("duplicate names");
+        assert!(err.contains("DATA.BIN"), "unexpected error: {err}");
+        assert!(!app.test_pack_job_started);
+    }
+
+    #[test]
+    fn file_list_warnings_report_names_in_both_lists() {
+        let workspace = tempdir().expect("temp workspace");
+        write_required_files(workspace.path());
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(workspace.path().to_path_buf());
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.packer_state.set_selected_prefix(SasPrefix::App);
+        app.packer_state
+            .set_file_list_entries(FileListKind::Include, vec!["DATA.BIN".to_string()]);
+        app.packer_state
+            .set_file_list_entries(FileListKind::Exclude, vec!["data.bin".to_string()]);
+
+        app.build_config().expect("overlap is not an error");
+        assert_eq!(
+            app.file_list_warnings(),
+            vec![psu_packer::ConfigWarning::IncludedAndExcluded {
+                name: "DATA.BIN".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn build_config_breaks_single_line_icon_sys_title_at_its_end() {
+        let mut app = PackerApp::default();
+        app.packer_state.set_folder_base_name("SAVE".to_string());
+        app.icon_sys_enabled = true;
+        app.icon_sys_use_existing = false;
+        app.icon_sys_title_line1 = "Memory Card".to_string();
+        app.icon_sys_title_line2 = " ".to_string();
+
+        let config = app.build_config().expect("config");
+        let icon_sys = config.icon_sys.expect("generated icon.sys");
+        assert_eq!(icon_sys.title, "Memory Card");
+        assert_eq!(icon_sys.linebreak_pos, Some(11));
+    }
+
     #[test]
     fn update_psu_reports_missing_destination() {
         let workspace = tempdir().expect("temp workspace");
@@ -1865,6 +3108,7 @@ mod packer_app_tests {
             include: None,
             exclude: None,
             icon_sys: None,
+            ..Default::default()
         };
         psu_packer::pack_with_config(&project_dir, &existing_output, config)
             .expect("pack source PSU");
@@ -1917,6 +3161,7 @@ mod packer_app_tests {
             include: None,
             exclude: None,
             icon_sys: None,
+            ..Default::default()
         };
         psu_packer::pack_with_config(&project_dir, &psu_path, config).expect("pack source PSU");
 
@@ -1949,7 +3194,7 @@ mod packer_app_tests {
 
         let result = app.export_psu_to_folder(Path::new("/nonexistent.psu"), destination);
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("does not exist"));
+        assert!(result.unwrap_err().to_string().contains("does not exist"));
     }
 }
 
@@ -2331,6 +3576,21 @@ pub(crate) fn title_cfg_form_ui(
                 editor.modified = true;
             }
         }
+
+        let summary = editor
+            .title_cfg_cache()
+            .map(|cache| cache.cfg.to_summary())
+            .filter(|summary| !summary.is_empty());
+        if let Some(summary) = summary {
+            ui.add_space(8.0);
+            if ui
+                .button("Copy summary")
+                .on_hover_text("Copy the title, version, developer, genre, and description.")
+                .clicked()
+            {
+                ui.ctx().copy_text(summary);
+            }
+        }
     }
 
     ui.add_space(8.0);
@@ -2343,6 +3603,7 @@ mod tests {
     #[cfg(feature = "psu-toml-editor")]
     use icon_sys_ui::IconFlagSelection;
     use psu_packer::shift_jis_byte_length;
+    use psu_packer::split_icon_sys_title;
     use psu_packer::{IconSysConfig, IconSysFlags};
     use std::fs;
     use tempfile::tempdir;
@@ -2488,6 +3749,42 @@ linebreak_pos = 5
         assert_eq!(app.icon_sys_title_line2, "カード");
     }
 
+    #[test]
+    fn icon_sys_title_char_limit_controls_loading_and_validation() {
+        let mut app = PackerApp::default();
+        let line1 = "A".repeat(20);
+        let icon_cfg = IconSysConfig {
+            flags: IconSysFlags::new(0),
+            title: format!("{line1}B"),
+            linebreak_pos: Some(20),
+            preset: None,
+            background_transparency: None,
+            background_colors: None,
+            light_directions: None,
+            light_colors: None,
+            ambient_color: None,
+        };
+
+        app.apply_icon_sys_config(icon_cfg.clone(), None);
+        assert_eq!(
+            app.icon_sys_title_line1,
+            "A".repeat(ICON_SYS_TITLE_CHAR_LIMIT)
+        );
+        assert_eq!(app.icon_sys_title_line2, "AAAAB");
+
+        app.icon_sys_title_char_limit = 24;
+        app.apply_icon_sys_config(icon_cfg, None);
+        assert_eq!(app.icon_sys_title_line1, line1);
+        assert_eq!(app.icon_sys_title_line2, "B");
+        assert_eq!(app.validate_icon_sys_settings(), Ok(()));
+
+        app.icon_sys_title_char_limit = 8;
+        let err = app
+            .validate_icon_sys_settings()
+            .expect_err("line 1 over limit");
+        assert!(err.contains("8 characters"), "unexpected message: {err}");
+    }
+
     #[test]
     fn apply_icon_sys_config_preserves_multibyte_characters() {
         let mut app = PackerApp::default();
@@ -2524,6 +3821,7 @@ linebreak_pos = 5
             include: None,
             exclude: None,
             icon_sys: None,
+            ..Default::default()
         };
         let config_toml = config.to_toml_string().expect("serialize minimal psu.toml");
         fs::write(folder.join("psu.toml"), config_toml).expect("write psu.toml");
@@ -2597,6 +3895,36 @@ linebreak_pos = 5
         assert_eq!(app.icon_sys_title_line2, "WORLD");
     }
 
+    #[test]
+    fn explicit_no_timestamp_keeps_the_none_strategy() {
+        let workspace = tempdir().expect("temp workspace");
+        let folder = workspace.path().join("APP_SAVE");
+        fs::create_dir_all(&folder).expect("create project folder");
+
+        fs::write(folder.join("psu.toml"), "[config]\nname = \"APP_SAVE\"\n")
+            .expect("write psu.toml");
+        let mut app = PackerApp::default();
+        crate::ui::file_picker::load_project_files(&mut app, &folder);
+        assert_ne!(app.packer_state.timestamp_strategy, TimestampStrategy::None);
+
+        fs::write(
+            folder.join("psu.toml"),
+            "[config]\nname = \"APP_SAVE\"\ntimestamp = false\n",
+        )
+        .expect("write psu.toml");
+        let mut app = PackerApp::default();
+        crate::ui::file_picker::load_project_files(&mut app, &folder);
+        assert_eq!(app.packer_state.timestamp_strategy, TimestampStrategy::None);
+        assert_eq!(app.packer_state.timestamp, None);
+
+        let config = app.build_config().expect("build config");
+        assert!(config.no_timestamp);
+        assert!(config
+            .to_toml_string()
+            .expect("serialize config")
+            .contains("timestamp = false"));
+    }
+
     #[test]
     fn split_icon_sys_title_replaces_control_characters() {
         let (line1, line2) = split_icon_sys_title("A\u{0001}B\rC", 3);
@@ -2669,6 +3997,23 @@ linebreak_pos = 5
             .is_some_and(|message| message.contains("missing mandatory")));
     }
 
+    #[test]
+    fn apply_title_cfg_reports_invalid_values() {
+        let mut app = PackerApp::default();
+        app.title_cfg_editor.set_content(format!(
+            "{}Aspect=aspect/x\n",
+            templates::TITLE_CFG_TEMPLATE
+        ));
+        app.title_cfg_editor.modified = true;
+
+        assert!(!app.apply_title_cfg_edits());
+        assert!(app
+            .packer_state
+            .error_message
+            .as_ref()
+            .is_some_and(|message| message.contains("Aspect") && message.contains("aspect/x")));
+    }
+
     #[test]
     fn load_warning_flags_missing_required_files() {
         let temp_dir = tempdir().expect("temporary directory");
@@ -4,26 +4,37 @@ use crate::PackerApp;
 use gui_core::actions::Action;
 use gui_core::ActionDispatcher;
 
-pub(crate) fn pack_confirmation(app: &mut PackerApp, ctx: &egui::Context) {
-    if let Some(missing) = app.packer_state.pending_pack_missing_files() {
-        let message = PackerApp::format_missing_required_files_message(missing);
-        egui::Window::new("Confirm Packing")
-            .collapsible(false)
-            .resizable(false)
-            .show(ctx, |ui| {
-                ui.label(&message);
-                ui.add_space(12.0);
-                ui.label("Pack anyway?");
-                ui.add_space(8.0);
-                ui.horizontal(|ui| {
-                    if ui.button("Proceed").clicked() {
-                        app.trigger_action(Action::ConfirmPack);
-                    }
-                    if ui.button("Go Back").clicked() {
-                        app.trigger_action(Action::CancelPack);
-                    }
-                });
+/// Renders the confirmation requested through `PackerState::request_confirmation` or
+/// `PackerState::request_pack_confirmation`.
+pub(crate) fn pending_confirmation(app: &mut PackerApp, ctx: &egui::Context) {
+    let Some(pending) = app.packer_state.pending_confirmation.clone() else {
+        return;
+    };
+
+    let mut answer = None;
+    egui::Window::new("Please Confirm")
+        .id(egui::Id::new(("pending_confirmation", pending.id.as_str())))
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.label(&pending.message);
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("Continue").clicked() {
+                    answer = Some(true);
+                }
+                if ui.button("Cancel").clicked() {
+                    answer = Some(false);
+                }
             });
+        });
+
+    if let Some(accepted) = answer {
+        if let Some(action) = app.packer_state.resolve_confirmation(&pending.id, accepted) {
+            if app.is_action_enabled(action.clone()) {
+                app.trigger_action(action);
+            }
+        }
     }
 }
 
@@ -19,12 +19,20 @@ pub(crate) fn file_menu(app: &mut PackerApp, ui: &mut egui::Ui) {
     });
 }
 
+/// Shows the application-wide shortcut for `descriptor`'s action next to its menu item.
+fn with_global_shortcut(descriptor: ActionDescriptor) -> ActionDescriptor {
+    match crate::view::shortcut_for(&descriptor.action) {
+        Some(shortcut) => descriptor.with_shortcut(shortcut),
+        None => descriptor,
+    }
+}
+
 fn file_menu_contents(
     app: &mut PackerApp,
     ui: &mut egui::Ui,
     mut recorder: Option<&mut dyn FileMenuRecorder>,
 ) {
-    let pack_descriptor = ActionDescriptor::new(Action::PackPsu, "Pack PSU");
+    let pack_descriptor = with_global_shortcut(ActionDescriptor::new(Action::PackPsu, "Pack PSU"));
     let pack_psu_response = actions::action_button(ui, app, &pack_descriptor)
         .on_hover_text("Create the PSU archive using the settings above.");
     if let Some(recorder) = recorder.as_mut() {
@@ -34,9 +42,17 @@ fn file_menu_contents(
     let save_descriptor = ActionDescriptor::new(Action::ChooseOutputDestination, "Save PSU As...");
     actions::action_button(ui, app, &save_descriptor);
 
-    let open_descriptor = ActionDescriptor::new(Action::OpenProject, "Open PSU...");
+    let open_descriptor =
+        with_global_shortcut(ActionDescriptor::new(Action::OpenProject, "Open PSU..."));
     actions::action_button(ui, app, &open_descriptor);
 
+    let reload_descriptor = ActionDescriptor::new(Action::ReloadProject, "Reload project files");
+    let reload_response = actions::action_button(ui, app, &reload_descriptor)
+        .on_hover_text("Re-read psu.toml and title.cfg after editing them outside the app.");
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.record(FileMenuItem::ReloadProject, reload_response.enabled());
+    }
+
     #[cfg(feature = "psu-toml-editor")]
     {
         let edit_psu_descriptor = ActionDescriptor::new(
@@ -100,6 +116,7 @@ fn file_menu_contents(
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum FileMenuItem {
     PackPsu,
+    ReloadProject,
     #[cfg_attr(not(feature = "psu-toml-editor"), allow(dead_code))]
     EditPsuToml,
     EditTitleCfg,
@@ -134,7 +151,10 @@ mod tests {
         });
         let _ = ctx.end_frame();
 
-        assert!(recorder.is_enabled(FileMenuItem::PackPsu));
+        assert!(recorder.has_entry(FileMenuItem::PackPsu));
+        assert!(!recorder.is_enabled(FileMenuItem::PackPsu));
+        assert!(recorder.has_entry(FileMenuItem::ReloadProject));
+        assert!(!recorder.is_enabled(FileMenuItem::ReloadProject));
         #[cfg(feature = "psu-toml-editor")]
         assert!(recorder.is_enabled(FileMenuItem::EditPsuToml));
         #[cfg(not(feature = "psu-toml-editor"))]
@@ -194,6 +214,9 @@ pub(crate) fn folder_section(app: &mut PackerApp, ui: &mut egui::Ui) {
 
         if let Some(folder) = &app.packer_state.folder {
             ui.label(format!("Folder: {}", folder.display()));
+            for warning in &app.packer_state.config_warnings {
+                ui.colored_label(egui::Color32::YELLOW, format!("psu.toml: {warning}"));
+            }
             if let Some(statuses) = app.packer_state.project_requirement_statuses() {
                 ui.add_space(4.0);
                 ui.small("Required project asset checklist:");
@@ -203,13 +226,27 @@ pub(crate) fn folder_section(app: &mut PackerApp, ui: &mut egui::Ui) {
     });
 }
 
-pub(crate) fn loaded_psu_section(app: &PackerApp, ui: &mut egui::Ui) {
+pub(crate) fn loaded_psu_section(app: &mut PackerApp, ui: &mut egui::Ui) {
     ui.group(|ui| {
         ui.heading(theme::display_heading_text(ui, "Loaded PSU"));
         ui.small("Review the files discovered in the opened PSU archive.");
         if let Some(path) = &app.packer_state.loaded_psu_path {
             ui.label(format!("File: {}", path.display()));
         }
+        if let Some(root_name) = &app.packer_state.loaded_psu_root_name {
+            ui.label(format!("Internal name: {root_name}"));
+        }
+        if let Some(message) = app.packer_state.loaded_psu_name_mismatch() {
+            ui.colored_label(egui::Color32::LIGHT_BLUE, message);
+        }
+        let target_name = app.packer_state.folder_name();
+        if app.packer_state.loaded_psu_root_name.as_deref() != Some(target_name.as_str()) {
+            let rename_descriptor =
+                ActionDescriptor::new(Action::RenameLoadedPsuRoot, "Rename internal folder");
+            actions::action_button(ui, app, &rename_descriptor).on_hover_text(format!(
+                "Rewrite the loaded PSU so it installs as {target_name} without repacking."
+            ));
+        }
         egui::ScrollArea::vertical()
             .max_height(150.0)
             .show(ui, |ui| {
@@ -225,15 +262,28 @@ pub(crate) fn loaded_psu_section(app: &PackerApp, ui: &mut egui::Ui) {
 }
 
 pub(crate) fn load_project_files(app: &mut PackerApp, folder: &Path) {
+    app.project_scan_cache.invalidate();
     app.packer_state.load_timestamp_rules_from_folder(folder);
-    match psu_packer::load_config(folder) {
-        Ok(config) => {
+    app.packer_state.config_warnings.clear();
+    match psu_packer::load_config_with_warnings(folder) {
+        Ok((config, warnings)) => {
+            app.packer_state.config_warnings = warnings;
             let psu_packer::Config {
                 name,
                 timestamp,
+                no_timestamp,
                 include,
                 exclude,
                 icon_sys,
+                icon_sys_variants,
+                icon_sys_variant,
+                root_size_excludes_dot_entries,
+                file_timestamps,
+                preserve_include_order,
+                include_from_manifest,
+                verify_after_pack,
+                root_dir_name,
+                generate_icon_sys,
             } = config;
 
             app.set_folder_name_from_full(&name);
@@ -252,6 +302,7 @@ pub(crate) fn load_project_files(app: &mut PackerApp, folder: &Path) {
                 } else {
                     TimestampStrategy::None
                 });
+            app.packer_state.no_timestamp_requested = no_timestamp;
             app.packer_state
                 .set_file_list_entries(FileListKind::Include, include.unwrap_or_default());
             app.packer_state
@@ -262,11 +313,11 @@ pub(crate) fn load_project_files(app: &mut PackerApp, folder: &Path) {
             let mut parsed_icon_sys = None;
             if let Some(icon_sys_path) = find_icon_sys_path(folder) {
                 match fs::read(&icon_sys_path) {
-                    Ok(bytes) => match std::panic::catch_unwind(|| IconSys::new(bytes)) {
-                        Ok(icon_sys) => parsed_icon_sys = Some(icon_sys),
-                        Err(_) => {
+                    Ok(bytes) => match IconSys::validate(&bytes) {
+                        Ok(()) => parsed_icon_sys = Some(IconSys::new(bytes)),
+                        Err(err) => {
                             app.set_error_message(format!(
-                                "Failed to parse {} as an icon.sys file.",
+                                "Failed to parse {} as an icon.sys file: {err}.",
                                 icon_sys_path.display()
                             ));
                         }
@@ -288,6 +339,15 @@ pub(crate) fn load_project_files(app: &mut PackerApp, folder: &Path) {
             } else {
                 app.reset_icon_sys_fields();
             }
+            app.icon_sys_variants = icon_sys_variants;
+            app.icon_sys_variant = icon_sys_variant;
+            app.root_size_excludes_dot_entries = root_size_excludes_dot_entries;
+            app.file_timestamps = file_timestamps;
+            app.preserve_include_order = preserve_include_order;
+            app.include_from_manifest = include_from_manifest;
+            app.verify_after_pack = verify_after_pack;
+            app.root_dir_name = root_dir_name;
+            app.generate_icon_sys = generate_icon_sys;
 
             app.icon_sys_existing = parsed_icon_sys;
         }
@@ -297,10 +357,19 @@ pub(crate) fn load_project_files(app: &mut PackerApp, folder: &Path) {
             app.packer_state.output.clear();
             app.packer_state.reset_metadata_fields();
             app.reset_icon_sys_fields();
+            app.clear_icon_sys_variants();
+            app.root_size_excludes_dot_entries = false;
+            app.file_timestamps.clear();
+            app.preserve_include_order = false;
+            app.include_from_manifest = None;
+            app.verify_after_pack = false;
+            app.root_dir_name = None;
+            app.generate_icon_sys = true;
         }
     }
     app.packer_state.loaded_psu_path = None;
     app.packer_state.loaded_psu_files.clear();
+    app.packer_state.loaded_psu_root_name = None;
     app.packer_state.folder = Some(folder.to_path_buf());
     app.sync_timestamp_after_source_update();
     app.reload_project_files();
@@ -322,18 +391,33 @@ fn find_icon_sys_path(folder: &Path) -> Option<PathBuf> {
 }
 
 impl PackerApp {
+    pub(crate) fn pick_merge_folder(&mut self) {
+        let Some(path) = rfd::FileDialog::new().pick_folder() else {
+            return;
+        };
+
+        match self.packer_state.add_merge_folder(path) {
+            Ok(()) => self.clear_error_message(),
+            Err(message) => self.set_error_message(message),
+        }
+    }
+
     pub(crate) fn handle_open_psu(&mut self) {
         let Some(path) = rfd::FileDialog::new()
-            .add_filter("PSU", &["psu"])
+            .add_filter("PSU", &["psu", "gz"])
             .pick_file()
         else {
             return;
         };
 
-        let data = match std::fs::read(&path) {
+        self.open_psu_file(path);
+    }
+
+    pub(crate) fn open_psu_file(&mut self, path: PathBuf) {
+        let data = match gui_core::state::read_psu_bytes(&path) {
             Ok(bytes) => bytes,
             Err(err) => {
-                self.set_error_message(format!("Failed to read {}: {err}", path.display()));
+                self.set_error_message(err);
                 return;
             }
         };
@@ -348,7 +432,6 @@ impl PackerApp {
 
         let entries = parsed.entries();
         let mut root_name: Option<String> = None;
-        let mut root_timestamp = None;
         let mut files = Vec::new();
         let mut psu_toml_bytes: Option<Vec<u8>> = None;
         let mut title_cfg_bytes: Option<Vec<u8>> = None;
@@ -359,7 +442,6 @@ impl PackerApp {
                 PSUEntryKind::Directory => {
                     if entry.name != "." && entry.name != ".." && root_name.is_none() {
                         root_name = Some(entry.name.clone());
-                        root_timestamp = Some(entry.created);
                     }
                 }
                 PSUEntryKind::File => {
@@ -390,14 +472,19 @@ impl PackerApp {
         };
 
         self.set_folder_name_from_full(&name);
-        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+        self.packer_state.loaded_psu_root_name = Some(name);
+        self.packer_state.config_warnings.clear();
+        self.clear_icon_sys_variants();
+        if let Some(stem) = gui_core::state::psu_file_stem(&path) {
             self.set_psu_file_base_from_full(stem);
         } else {
             self.packer_state.psu_file_base_name = self.packer_state.folder_base_name.clone();
         }
-        self.packer_state.source_timestamp = root_timestamp;
         self.packer_state.loaded_psu_files = files;
         self.packer_state.loaded_psu_path = Some(path.clone());
+        if self.packer_state.load_source_timestamp_from_psu().is_err() {
+            self.packer_state.source_timestamp = None;
+        }
         self.clear_error_message();
         self.packer_state.status = format!("Loaded PSU from {}", path.display());
         self.packer_state.folder = None;
@@ -432,14 +519,14 @@ impl PackerApp {
         self.psu_toml_sync_blocked = false;
 
         if let Some(bytes) = icon_sys_bytes {
-            match std::panic::catch_unwind(|| IconSys::new(bytes)) {
-                Ok(parsed_icon_sys) => {
-                    self.apply_icon_sys_file(&parsed_icon_sys);
+            match IconSys::validate(&bytes) {
+                Ok(()) => {
+                    self.apply_icon_sys_file(&IconSys::new(bytes));
                 }
-                Err(_) => {
+                Err(err) => {
                     self.reset_icon_sys_fields();
                     self.set_error_message(format!(
-                        "Failed to parse icon.sys from {}.",
+                        "Failed to parse icon.sys from {}: {err}.",
                         path.display()
                     ));
                 }
@@ -461,6 +548,9 @@ fn format_load_error(folder: &Path, err: psu_packer::Error) -> String {
         psu_packer::Error::ConfigError(message) => {
             format!("The psu.toml file is invalid: {message}")
         }
+        err @ (psu_packer::Error::ReservedEntryName(_) | psu_packer::Error::FileError { .. }) => {
+            err.to_string()
+        }
         psu_packer::Error::IOError(io_err) => {
             let config_path = folder.join("psu.toml");
             match io_err.kind() {
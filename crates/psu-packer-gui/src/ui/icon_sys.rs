@@ -1,6 +1,6 @@
 use eframe::egui;
 
-use crate::{ui::theme, PackerApp};
+use crate::{ui::theme, PackerApp, ICON_SYS_TITLE_CHAR_LIMIT};
 use gui_core::actions::{Action, IconSysAction};
 use gui_core::ActionDispatcher;
 use icon_sys_ui::{
@@ -8,6 +8,7 @@ use icon_sys_ui::{
     BackgroundSectionState, FlagSectionState, IconSysState, LightingSectionState,
     PresetPreviewData, PresetSectionState, PresetSelection, TitleSectionIds, TitleSectionState,
 };
+use psu_packer::ICON_SYS_TITLE_BYTE_LIMIT;
 
 fn dispatch_icon_sys_action(app: &mut PackerApp, action: IconSysAction) -> bool {
     let wrapped = Action::IconSys(action);
@@ -72,10 +73,28 @@ pub(crate) fn icon_sys_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
                     "The existing icon.sys file will be packed without modification. ",
                     "Switch to \"Generate new icon.sys\" to edit metadata.",
                 ));
+            } else if app.icon_sys_differs_from_existing() {
+                let warn_color = ui.visuals().warn_fg_color;
+                ui.colored_label(warn_color, "● Differs from the project's icon.sys")
+                    .on_hover_text(concat!(
+                        "Packing writes these settings into the PSU. ",
+                        "The icon.sys file in the project folder is left unchanged.",
+                    ));
             }
         }
     }
 
+    for warning in app.missing_icon_file_warnings() {
+        ui.colored_label(egui::Color32::YELLOW, warning.to_string());
+    }
+
+    if !app.icon_sys_variants.is_empty()
+        && !app.icon_sys_use_existing
+        && icon_sys_variant_selector(app, ui)
+    {
+        config_changed = true;
+    }
+
     ui.add_space(8.0);
 
     let enabled = app.icon_sys_enabled && !app.icon_sys_use_existing;
@@ -84,9 +103,21 @@ pub(crate) fn icon_sys_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
 
         let title_response = ui.group(|ui| {
             ui.heading(theme::display_heading_text(ui, "Title"));
-            ui.small(
-                "Each line supports up to 16 characters that must round-trip through Shift-JIS",
-            );
+            ui.small(format!(
+                "Each line supports up to {} characters that must round-trip through Shift-JIS",
+                app.icon_sys_title_char_limit
+            ));
+            ui.horizontal(|ui| {
+                ui.label("Characters per line");
+                ui.add(
+                    egui::DragValue::new(&mut app.icon_sys_title_char_limit)
+                        .range(1..=ICON_SYS_TITLE_BYTE_LIMIT),
+                )
+                .on_hover_text(format!(
+                    "The standard PS2 browser shows {ICON_SYS_TITLE_CHAR_LIMIT} characters per \
+                     line. Change this only for launchers that render titles differently."
+                ));
+            });
             title_editor(
                 ui,
                 TitleSectionIds {
@@ -96,6 +127,7 @@ pub(crate) fn icon_sys_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
                 TitleSectionState {
                     line1: &mut app.icon_sys_title_line1,
                     line2: &mut app.icon_sys_title_line2,
+                    char_limit: app.icon_sys_title_char_limit,
                 },
             )
         });
@@ -125,6 +157,7 @@ pub(crate) fn icon_sys_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
         {
             let preset_preview = PresetPreviewData {
                 background_colors: &app.icon_sys_state.background_colors,
+                background_transparency: app.icon_sys_state.background_transparency,
                 light_colors: &app.icon_sys_state.light_colors,
                 ambient_color: &app.icon_sys_state.ambient_color,
             };
@@ -156,6 +189,12 @@ pub(crate) fn icon_sys_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
                             inner_changed = true;
                         }
                     }
+                    PresetSelection::Import => {
+                        if app.import_icon_sys_preset() {
+                            inner_changed = true;
+                        }
+                    }
+                    PresetSelection::Export => app.export_icon_sys_preset(),
                 }
             }
             if preset_response.changed {
@@ -211,6 +250,31 @@ pub(crate) fn icon_sys_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
     }
 }
 
+/// Picks which `[icon_sys.<label>]` variant from psu.toml is packed as `icon.sys`.
+fn icon_sys_variant_selector(app: &mut PackerApp, ui: &mut egui::Ui) -> bool {
+    const BASE_LABEL: &str = "Settings below";
+
+    let mut selected = app.icon_sys_variant.clone();
+    ui.horizontal(|ui| {
+        ui.label("Embed variant:");
+        egui::ComboBox::from_id_salt("icon_sys_variant_combo")
+            .selected_text(selected.as_deref().unwrap_or(BASE_LABEL))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut selected, None, BASE_LABEL);
+                for label in app.icon_sys_variants.keys() {
+                    ui.selectable_value(&mut selected, Some(label.clone()), label);
+                }
+            });
+    });
+    ui.small("Variants come from [icon_sys.<label>] tables in psu.toml. Only the selected one is packed as icon.sys.");
+
+    if selected == app.icon_sys_variant {
+        return false;
+    }
+    app.icon_sys_variant = selected;
+    true
+}
+
 pub fn render_icon_sys_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
     icon_sys_editor(app, ui);
 }
@@ -8,6 +8,10 @@ use gui_core::{
     actions::{self, Action, ActionDescriptor, FileListAction, FileListKind, MetadataAction},
     ActionDispatcher,
 };
+use psu_packer::{ChangeKind, MergeConflictPolicy};
+
+/// Saves above this size take up a noticeable share of an 8 MB memory card.
+const LARGE_SAVE_WARNING_BYTES: u64 = 2 * 1024 * 1024;
 
 pub(crate) fn metadata_section(app: &mut PackerApp, ui: &mut egui::Ui) {
     ui.set_width(ui.available_width());
@@ -54,11 +58,21 @@ pub(crate) fn metadata_section(app: &mut PackerApp, ui: &mut egui::Ui) {
                     }
                 });
                 let mut folder_base_name = app.packer_state.folder_base_name.clone();
-                if ui.text_edit_singleline(&mut folder_base_name).changed() {
-                    app.trigger_action(Action::Metadata(MetadataAction::SetFolderBaseName(
-                        folder_base_name,
-                    )));
-                }
+                ui.vertical(|ui| {
+                    if ui.text_edit_singleline(&mut folder_base_name).changed() {
+                        app.trigger_action(Action::Metadata(MetadataAction::SetFolderBaseName(
+                            folder_base_name,
+                        )));
+                    }
+                    if let Some(warning) = app.packer_state.double_prefix_warning() {
+                        ui.colored_label(egui::Color32::YELLOW, warning);
+                        if ui.button("Strip prefix").clicked() {
+                            app.trigger_action(Action::Metadata(
+                                MetadataAction::StripRedundantPrefix,
+                            ));
+                        }
+                    }
+                });
                 ui.end_row();
 
                 let output_preview = app.packer_state.default_output_file_name();
@@ -128,6 +142,9 @@ pub(crate) fn file_filters_section(app: &mut PackerApp, ui: &mut egui::Ui) {
             file_list_ui(app, &mut columns[0], ListKind::Include);
             file_list_ui(app, &mut columns[1], ListKind::Exclude);
         });
+        for warning in app.file_list_warnings() {
+            ui.colored_label(egui::Color32::YELLOW, warning.to_string());
+        }
     });
 }
 
@@ -165,33 +182,18 @@ pub(crate) fn packaging_section(app: &mut PackerApp, ui: &mut egui::Ui) {
     ui.group(|ui| {
         ui.heading(theme::display_heading_text(ui, "Packaging"));
         ui.small("Validate the configuration and generate the PSU archive.");
-        let pack_in_progress = app.is_pack_running();
-        let missing_requirements = !app.packer_state.missing_required_project_files.is_empty();
-        let missing_summary = if missing_requirements {
-            Some(
-                app.packer_state
-                    .missing_required_project_files
-                    .iter()
-                    .map(|entry| entry.name.as_str())
-                    .collect::<Vec<_>>()
-                    .join(", "),
-            )
-        } else {
-            None
-        };
+        let readiness = app.packer_state.pack_readiness();
+        let pack_in_progress = readiness.pack_running;
         let requirement_statuses = app.packer_state.project_requirement_statuses();
         let required_asset_list = REQUIRED_PROJECT_FILES.join(", ");
 
         if let Some(ref statuses) = requirement_statuses {
-            if missing_requirements {
-                let details = missing_summary
-                    .as_ref()
-                    .filter(|summary| !summary.trim().is_empty())
-                    .cloned()
-                    .unwrap_or_else(|| required_asset_list.clone());
+            if !readiness.required_files_present() {
                 ui.colored_label(
                     egui::Color32::YELLOW,
-                    format!("Add the missing project assets before packing: {details}."),
+                    readiness
+                        .blocking_reason("packing")
+                        .unwrap_or_default(),
                 );
             } else {
                 ui.weak(format!(
@@ -203,6 +205,51 @@ pub(crate) fn packaging_section(app: &mut PackerApp, ui: &mut egui::Ui) {
         } else {
             ui.weak("Select a project folder to verify the required assets.");
         }
+        if let Some(size) = app.estimated_pack_size() {
+            let kib = size.div_ceil(1024);
+            if size > LARGE_SAVE_WARNING_BYTES {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    format!("Estimated PSU size: {kib} KB. This save is unusually large and may not fit alongside other saves on a memory card."),
+                );
+            } else {
+                ui.weak(format!("Estimated PSU size: {kib} KB."));
+            }
+        }
+        merge_folders_ui(app, ui, pack_in_progress);
+        pending_changes_ui(app, ui);
+        ui.add_enabled(
+            !pack_in_progress,
+            egui::Checkbox::new(
+                &mut app.packer_state.write_config_on_pack,
+                "Write psu.toml after packing",
+            ),
+        )
+        .on_hover_text(
+            "Save the packed configuration to psu.toml in the project folder once packing succeeds. Unrecognised keys in an existing psu.toml are kept.",
+        );
+        ui.add_enabled(
+            !pack_in_progress,
+            egui::Checkbox::new(
+                &mut app.packer_state.make_output_read_only,
+                "Make the PSU read-only after packing",
+            ),
+        )
+        .on_hover_text(
+            "Protect finished saves from being overwritten by a stray repack. Update PSU makes the file writable again before rewriting it.",
+        );
+        if app.packer_state.loaded_psu_path.is_some() {
+            ui.add_enabled(
+                !pack_in_progress,
+                egui::Checkbox::new(
+                    &mut app.packer_state.keep_temp_workspace_on_failure,
+                    "Keep extracted files if the update fails",
+                ),
+            )
+            .on_hover_text(
+                "Debug option: leave the temporary folder the loaded PSU was extracted to in place when repacking it fails, so its contents can be inspected.",
+            );
+        }
         let pack_descriptor = ActionDescriptor::new(Action::PackPsu, "Pack PSU");
         let update_descriptor = ActionDescriptor::new(Action::UpdatePsu, "Update PSU");
         let export_descriptor =
@@ -217,55 +264,44 @@ pub(crate) fn packaging_section(app: &mut PackerApp, ui: &mut egui::Ui) {
             ],
         );
         ui.horizontal_wrapped(|ui| {
-            let pack_response = actions::action_button(ui, app, &pack_descriptor);
-            if pack_in_progress {
-                pack_response.on_hover_text("Packing in progress…");
-            } else if missing_requirements {
-                let details = missing_summary
-                    .as_ref()
-                    .filter(|summary| !summary.trim().is_empty())
-                    .cloned()
-                    .unwrap_or_else(|| required_asset_list.clone());
-                pack_response.on_hover_text(format!(
-                    "Add the missing project assets before packing: {details}."
-                ));
-            } else {
-                pack_response.on_hover_text("Create the PSU archive using the settings above.");
-            }
+            let validate_descriptor = ActionDescriptor::new(Action::ValidateProject, "Validate");
+            actions::action_button(ui, app, &validate_descriptor).on_hover_text(
+                "Check the settings, required files, icon.sys title and title.cfg without packing.",
+            );
 
-            let update_response = actions::action_button(ui, app, &update_descriptor);
-            if pack_in_progress {
-                update_response.on_hover_text("Packing in progress…");
-            } else if missing_requirements {
-                let details = missing_summary
-                    .as_ref()
-                    .filter(|summary| !summary.trim().is_empty())
-                    .cloned()
-                    .unwrap_or_else(|| required_asset_list.clone());
-                update_response.on_hover_text(format!(
-                    "Add the missing project assets before updating: {details}."
-                ));
-            } else {
-                update_response
-                    .on_hover_text("Repack the current project into the existing PSU file.");
+            for (descriptor, verb, ready_hint) in [
+                (
+                    &pack_descriptor,
+                    "packing",
+                    "Create the PSU archive using the settings above.",
+                ),
+                (
+                    &update_descriptor,
+                    "updating",
+                    "Repack the current project into the existing PSU file.",
+                ),
+                (
+                    &export_descriptor,
+                    "exporting",
+                    "Export the contents of the current PSU archive to a folder.",
+                ),
+            ] {
+                let response = actions::action_button(ui, app, descriptor);
+                match readiness.blocking_reason(verb) {
+                    Some(reason) => response.on_disabled_hover_text(reason),
+                    None => response.on_hover_text(ready_hint),
+                };
             }
 
-            let export_response = actions::action_button(ui, app, &export_descriptor);
-            if pack_in_progress {
-                export_response.on_hover_text("Packing in progress…");
-            } else if missing_requirements {
-                let details = missing_summary
-                    .as_ref()
-                    .filter(|summary| !summary.trim().is_empty())
-                    .cloned()
-                    .unwrap_or_else(|| required_asset_list.clone());
-                export_response.on_hover_text(format!(
-                    "Add the missing project assets before exporting: {details}."
-                ));
-            } else {
-                export_response
-                    .on_hover_text("Export the contents of the current PSU archive to a folder.");
-            }
+            let csv_descriptor =
+                ActionDescriptor::new(Action::ExportEntryListCsv, "Export entry list (CSV)");
+            actions::action_button(ui, app, &csv_descriptor).on_hover_text(
+                "Save the name, kind, size, and timestamps of every entry for spreadsheet review.",
+            );
+
+            let reveal_descriptor = ActionDescriptor::new(Action::RevealOutput, "Show in folder");
+            actions::action_button(ui, app, &reveal_descriptor)
+                .on_hover_text("Open the folder containing the packed PSU.");
         });
 
         if pack_in_progress {
@@ -281,6 +317,101 @@ pub(crate) fn packaging_section(app: &mut PackerApp, ui: &mut egui::Ui) {
     });
 }
 
+fn pending_changes_ui(app: &mut PackerApp, ui: &mut egui::Ui) {
+    if app.packer_state.folder.is_none() {
+        return;
+    }
+    let Some(output) = app.existing_output_path() else {
+        return;
+    };
+
+    egui::CollapsingHeader::new("Changes since last pack")
+        .id_salt("pending_changes")
+        .show(ui, |ui| {
+            if ui
+                .button("Compare with existing PSU")
+                .on_hover_text(format!(
+                    "Compare the project folder with {}.",
+                    output.display()
+                ))
+                .clicked()
+            {
+                app.refresh_pending_changes();
+            }
+
+            match &app.pending_changes {
+                Some((path, Ok(changes))) if *path == output => {
+                    if changes.is_empty() {
+                        ui.weak("No changes since the last pack.");
+                    }
+                    for change in changes {
+                        let kind = match change.kind {
+                            ChangeKind::Added => "Added",
+                            ChangeKind::Removed => "Removed",
+                            ChangeKind::Modified => "Modified",
+                        };
+                        ui.label(format!("{kind}: {}", change.name));
+                    }
+                }
+                Some((path, Err(message))) if *path == output => {
+                    ui.colored_label(egui::Color32::YELLOW, message);
+                }
+                _ => {}
+            }
+        });
+}
+
+fn merge_folders_ui(app: &mut PackerApp, ui: &mut egui::Ui, pack_in_progress: bool) {
+    egui::CollapsingHeader::new("Merge other folders")
+        .id_salt("merge_folders")
+        .show(ui, |ui| {
+            ui.small(
+                "Files from these folders are packed alongside the project using its metadata.",
+            );
+            let mut remove = None;
+            for (index, folder) in app.packer_state.merge_folders.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(folder.display().to_string());
+                    if ui
+                        .add_enabled(!pack_in_progress, egui::Button::new("Remove"))
+                        .clicked()
+                    {
+                        remove = Some(index);
+                    }
+                });
+            }
+            if let Some(index) = remove {
+                app.packer_state.remove_merge_folder(index);
+            }
+
+            if ui
+                .add_enabled(!pack_in_progress, egui::Button::new("Add folder…"))
+                .clicked()
+            {
+                app.pick_merge_folder();
+            }
+
+            let mut fail_on_conflict =
+                app.packer_state.merge_conflict_policy == MergeConflictPolicy::Error;
+            if ui
+                .add_enabled(
+                    !pack_in_progress,
+                    egui::Checkbox::new(&mut fail_on_conflict, "Stop on duplicate file names"),
+                )
+                .on_hover_text(
+                    "When unchecked, the project folder's file wins, then earlier merged folders.",
+                )
+                .changed()
+            {
+                app.packer_state.merge_conflict_policy = if fail_on_conflict {
+                    MergeConflictPolicy::Error
+                } else {
+                    MergeConflictPolicy::PrimaryWins
+                };
+            }
+        });
+}
+
 #[derive(Copy, Clone)]
 pub(crate) enum ListKind {
     Include,
@@ -382,6 +513,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn config_from_state_keeps_icon_sys_variants() {
+        let mut app = app_with_prefix(SasPrefix::App);
+        let variant = psu_packer::IconSysConfig {
+            flags: psu_packer::IconSysFlags::new(0),
+            title: "Japan".to_string(),
+            linebreak_pos: None,
+            preset: None,
+            background_transparency: None,
+            background_colors: None,
+            light_directions: None,
+            light_colors: None,
+            ambient_color: None,
+        };
+        app.icon_sys_variants.insert("jp".to_string(), variant);
+        app.icon_sys_variant = Some("jp".to_string());
+
+        let config = app.build_config().expect("configuration should build");
+        assert!(config.icon_sys_variants.contains_key("jp"));
+        assert_eq!(config.icon_sys_variant.as_deref(), Some("jp"));
+
+        app.icon_sys_enabled = true;
+        app.icon_sys_use_existing = true;
+        let config = app.build_config().expect("configuration should build");
+        assert!(config.icon_sys_variants.contains_key("jp"));
+        assert_eq!(
+            config.icon_sys_variant, None,
+            "an existing icon.sys takes precedence over variants"
+        );
+    }
+
     #[test]
     fn build_config_uses_loaded_psu_edits() {
         let mut app = app_with_prefix(SasPrefix::Emu);
@@ -502,6 +664,8 @@ fn file_list_ui(app: &mut PackerApp, ui: &mut egui::Ui, kind: ListKind) {
         Action::FileList(FileListAction::RemoveSelected(file_list_kind)),
         "➖",
     );
+    let undo_descriptor = ActionDescriptor::new(Action::FileList(FileListAction::Undo), "⟲");
+    let redo_descriptor = ActionDescriptor::new(Action::FileList(FileListAction::Redo), "⟳");
 
     let shortcut_descriptors = [
         browse_descriptor.clone(),
@@ -519,8 +683,26 @@ fn file_list_ui(app: &mut PackerApp, ui: &mut egui::Ui, kind: ListKind) {
 
         actions::action_button(ui, app, &remove_descriptor)
             .on_hover_text("Remove the selected file from this list.");
+
+        actions::action_button(ui, app, &undo_descriptor)
+            .on_hover_text("Undo the last include/exclude list edit.");
+
+        actions::action_button(ui, app, &redo_descriptor)
+            .on_hover_text("Redo the last undone include/exclude list edit.");
     });
 
+    if let (ListKind::Include, Some(manifest)) = (kind, &app.include_from_manifest) {
+        let warn_color = ui.visuals().warn_fg_color;
+        ui.colored_label(
+            warn_color,
+            format!(
+                "psu.toml reads the include list from {}. The entries below are not packed.",
+                manifest.display()
+            ),
+        )
+        .on_hover_text("Remove include_from_manifest from psu.toml to pack this list instead.");
+    }
+
     ui.horizontal(|ui| {
         let response = {
             let manual_entry = app.packer_state_mut().manual_entry_mut(file_list_kind);
@@ -540,19 +722,33 @@ fn file_list_ui(app: &mut PackerApp, ui: &mut egui::Ui, kind: ListKind) {
         }
     });
 
+    ui.horizontal(|ui| {
+        let mut filter = app
+            .packer_state()
+            .file_list_filter(file_list_kind)
+            .to_string();
+        let response = ui.add(egui::TextEdit::singleline(&mut filter).hint_text("Filter"));
+        if response.changed() {
+            app.packer_state_mut()
+                .set_file_list_filter(file_list_kind, filter);
+        }
+    });
+
     let files = app
         .packer_state()
-        .file_list_entries(file_list_kind)
-        .to_vec();
+        .filtered_file_list(file_list_kind)
+        .into_iter()
+        .map(|(idx, file)| (idx, file.to_string()))
+        .collect::<Vec<_>>();
     let selected_index = app.packer_state().file_list_selection(file_list_kind);
 
     egui::ScrollArea::vertical()
         .max_height(150.0)
         .show(ui, |ui| {
-            for (idx, file) in files.iter().enumerate() {
+            for (idx, file) in files {
                 ui.horizontal(|ui| {
                     let is_selected = Some(idx) == selected_index;
-                    if ui.selectable_label(is_selected, file).clicked() {
+                    if ui.selectable_label(is_selected, &file).clicked() {
                         app.trigger_action(Action::FileList(FileListAction::SelectEntry(
                             file_list_kind,
                             Some(idx),
@@ -5,6 +5,32 @@ use eframe::egui::{
 
 pub const DISPLAY_FONT_NAME: &str = "ps2_display";
 
+/// The palettes the user can pick from the View menu.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum PaletteId {
+    #[default]
+    Neon,
+    HighContrast,
+}
+
+impl PaletteId {
+    pub const ALL: [PaletteId; 2] = [PaletteId::Neon, PaletteId::HighContrast];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PaletteId::Neon => "Neon",
+            PaletteId::HighContrast => "High contrast",
+        }
+    }
+
+    pub fn palette(self) -> Palette {
+        match self {
+            PaletteId::Neon => Palette::default(),
+            PaletteId::HighContrast => Palette::high_contrast(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Palette {
     pub background: Color32,
@@ -38,6 +64,29 @@ impl Default for Palette {
     }
 }
 
+impl Palette {
+    pub fn high_contrast() -> Self {
+        Self {
+            background: Color32::from_rgb(0, 0, 0),
+            panel: Color32::from_rgb(24, 24, 24),
+            input_background: Color32::from_rgb(40, 40, 40),
+            header_top: Color32::from_rgb(0, 0, 0),
+            header_bottom: Color32::from_rgb(32, 32, 32),
+            footer_top: Color32::from_rgb(32, 32, 32),
+            footer_bottom: Color32::from_rgb(0, 0, 0),
+            neon_accent: Color32::from_rgb(255, 214, 0),
+            soft_accent: Color32::from_rgb(120, 200, 255),
+            separator: Color32::from_rgb(255, 255, 255),
+            text_primary: Color32::from_rgb(255, 255, 255),
+        }
+    }
+}
+
+/// Switches the colors of an already installed theme to `palette`.
+pub fn apply_palette(ctx: &egui::Context, palette: &Palette) {
+    apply_visuals(ctx, palette);
+}
+
 pub fn install(ctx: &egui::Context, palette: &Palette) {
     install_fonts(ctx);
     apply_visuals(ctx, palette);
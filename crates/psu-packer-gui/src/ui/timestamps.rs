@@ -4,7 +4,8 @@ use egui_extras::DatePickerButton;
 
 use crate::{ui::theme, PackerApp, TimestampStrategy, TIMESTAMP_FORMAT};
 use gui_core::actions::{Action, TimestampAction, TimestampRulesAction, TimestampStrategyAction};
-use gui_core::ActionDispatcher;
+use gui_core::{ActionDispatcher, TimestampCollision};
+use psu_packer::sas::TIMESTAMP_RULES_PRESETS;
 
 fn dispatch_timestamp_action(app: &mut PackerApp, action: TimestampAction) -> bool {
     let wrapped = Action::Timestamp(action);
@@ -140,6 +141,43 @@ pub(crate) fn metadata_timestamp_section(app: &mut PackerApp, ui: &mut egui::Ui)
 
         ui.add_space(6.0);
 
+        ui.group(|ui| {
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    let response = ui.radio_value(
+                        &mut strategy,
+                        TimestampStrategy::NewestFile,
+                        "Use newest file timestamp",
+                    );
+                    if response.changed()
+                        && app.packer_state.timestamp_strategy != TimestampStrategy::NewestFile
+                        && strategy == TimestampStrategy::NewestFile
+                    {
+                        dispatch_timestamp_action(
+                            app,
+                            TimestampAction::SelectStrategy(
+                                TimestampStrategyAction::NewestFile,
+                            ),
+                        );
+                    }
+                });
+                ui.label("• Use when the archive should carry the time of the most recent edit.");
+                ui.label("• Relies on: modification times of the files that will be packed.");
+                if app.packer_state.timestamp_strategy == TimestampStrategy::NewestFile {
+                    match app.packer_state.timestamp {
+                        Some(ts) => {
+                            ui.small(format!("  Newest file: {}", ts.format(TIMESTAMP_FORMAT)));
+                        }
+                        None => {
+                            ui.small("  No files to pack; the timestamp will be omitted.");
+                        }
+                    }
+                }
+            });
+        });
+
+        ui.add_space(6.0);
+
         ui.group(|ui| {
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
@@ -256,6 +294,7 @@ pub(crate) fn metadata_timestamp_section(app: &mut PackerApp, ui: &mut egui::Ui)
                 TimestampStrategy::None => TimestampStrategyAction::None,
                 TimestampStrategy::InheritSource => TimestampStrategyAction::InheritSource,
                 TimestampStrategy::SasRules => TimestampStrategyAction::SasRules,
+                TimestampStrategy::NewestFile => TimestampStrategyAction::NewestFile,
                 TimestampStrategy::Manual => TimestampStrategyAction::Manual,
             };
             dispatch_timestamp_action(
@@ -274,6 +313,11 @@ pub(crate) fn metadata_timestamp_section(app: &mut PackerApp, ui: &mut egui::Ui)
             ui.label(egui::RichText::new(summary_text).strong());
         });
 
+        if let Some(warning) = app.packer_state.future_timestamp_warning() {
+            ui.add_space(6.0);
+            ui.colored_label(egui::Color32::YELLOW, warning);
+        }
+
         ui.add_space(6.0);
     });
 }
@@ -317,6 +361,7 @@ fn current_strategy_title(strategy: TimestampStrategy) -> &'static str {
         TimestampStrategy::None => "No timestamp",
         TimestampStrategy::InheritSource => "Inherited source timestamp",
         TimestampStrategy::SasRules => "SAS rules timestamp",
+        TimestampStrategy::NewestFile => "Newest file timestamp",
         TimestampStrategy::Manual => "Manual timestamp",
     }
 }
@@ -345,6 +390,13 @@ fn current_strategy_reason(
             ),
             None => "automatic SAS rules could not determine a timestamp".to_string(),
         },
+        TimestampStrategy::NewestFile => match app.packer_state.timestamp {
+            Some(ts) => format!(
+                "the newest file to pack was modified at {}",
+                ts.format(TIMESTAMP_FORMAT)
+            ),
+            None => "there are no files to take a timestamp from".to_string(),
+        },
         TimestampStrategy::Manual => match app.packer_state.manual_timestamp {
             Some(ts) => format!("you entered {}", ts.format(TIMESTAMP_FORMAT)),
             None => "a manual timestamp is required until other data is provided".to_string(),
@@ -366,6 +418,25 @@ pub(crate) fn timestamp_rules_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
         ui.add_space(6.0);
         ui.colored_label(egui::Color32::YELLOW, error);
     }
+    if !app.packer_state.timestamp_rules_warnings.is_empty() {
+        ui.add_space(6.0);
+        for warning in &app.packer_state.timestamp_rules_warnings {
+            ui.colored_label(egui::Color32::YELLOW, format!("{warning}."));
+        }
+    }
+    let collisions: Vec<TimestampCollision> = app.packer_state.timestamp_collisions().to_vec();
+    if !collisions.is_empty() {
+        ui.add_space(6.0);
+        for (first, second, timestamp) in &collisions {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!(
+                    "{first} and {second} are both scheduled at {}.",
+                    timestamp.format("%Y-%m-%d %H:%M:%S")
+                ),
+            );
+        }
+    }
 
     if let Some(path) = app.packer_state.timestamp_rules_path() {
         ui.label(format!("Configuration file: {}", path.display()));
@@ -377,6 +448,39 @@ pub(crate) fn timestamp_rules_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
         ui.colored_label(egui::Color32::LIGHT_YELLOW, "Unsaved changes");
     }
 
+    ui.checkbox(
+        &mut app.packer_state.include_timestamp_rules_in_psu,
+        "Pack timestamp_rules.json into the PSU",
+    )
+    .on_hover_text(
+        "When the SAS rules set the timestamp, embed the rules file so whoever receives the save can see how it was scheduled.",
+    );
+
+    ui.add_space(8.0);
+    ui.horizontal(|ui| {
+        ui.label("Start from preset");
+        let mut chosen: Option<&'static str> = None;
+        egui::ComboBox::from_id_salt("timestamp_rules_preset_combo")
+            .selected_text("Choose a preset…")
+            .show_ui(ui, |ui| {
+                for preset in TIMESTAMP_RULES_PRESETS {
+                    if ui
+                        .selectable_label(false, preset.label)
+                        .on_hover_text(preset.description)
+                        .clicked()
+                    {
+                        chosen = Some(preset.id);
+                    }
+                }
+            });
+        if let Some(preset_id) = chosen {
+            dispatch_timestamp_action(
+                app,
+                TimestampAction::ApplyRulesPreset(preset_id.to_string()),
+            );
+        }
+    });
+
     ui.add_space(8.0);
     egui::Grid::new("timestamp_rules_settings")
         .num_columns(2)
@@ -469,6 +573,25 @@ pub(crate) fn timestamp_rules_editor(app: &mut PackerApp, ui: &mut egui::Ui) {
                 if available_aliases.is_empty() {
                     ui.small("No canonical aliases are defined for this category.");
                 } else {
+                    ui.horizontal(|ui| {
+                        if ui.small_button("Select all").clicked() {
+                            dispatch_timestamp_action(
+                                app,
+                                TimestampAction::Rules(TimestampRulesAction::SelectAllAliases(
+                                    index,
+                                )),
+                            );
+                        }
+                        if ui.small_button("Clear all").clicked() {
+                            dispatch_timestamp_action(
+                                app,
+                                TimestampAction::Rules(TimestampRulesAction::ClearAllAliases(
+                                    index,
+                                )),
+                            );
+                        }
+                    });
+
                     for alias in &available_aliases {
                         let mut is_selected = app
                             .packer_state
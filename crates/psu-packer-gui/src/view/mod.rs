@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use eframe::egui;
 
 use crate::{
@@ -6,9 +8,83 @@ use crate::{
 };
 use gui_core::actions::{self, Action, ActionDescriptor};
 use gui_core::ActionDispatcher;
+use serde::{Deserialize, Serialize};
 
 mod shell;
 
+/// View preferences remembered between sessions through eframe's storage.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DisplaySettings {
+    pub(crate) zoom_factor: f32,
+    pub(crate) palette: theme::PaletteId,
+}
+
+impl DisplaySettings {
+    const STORAGE_KEY: &'static str = "psu_packer_display";
+
+    /// Reads the saved settings, clamping the zoom to the range the View menu allows.
+    pub(crate) fn load(storage: &dyn eframe::Storage) -> Option<Self> {
+        let settings: Self = eframe::get_value(storage, Self::STORAGE_KEY)?;
+        Some(Self {
+            zoom_factor: settings.zoom_factor.clamp(0.5, 2.0),
+            ..settings
+        })
+    }
+
+    pub(crate) fn save(&self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, Self::STORAGE_KEY, self);
+    }
+}
+
+/// Application-wide keyboard shortcuts, checked once per frame before any panel is drawn.
+pub(crate) const SHORTCUTS: &[(egui::KeyboardShortcut, Action)] = &[
+    (
+        egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::O),
+        Action::OpenProject,
+    ),
+    (
+        egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::P),
+        Action::PackPsu,
+    ),
+    (
+        egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S),
+        Action::SaveFile,
+    ),
+];
+
+/// The shortcut bound to `action` in [`SHORTCUTS`], for showing next to menu items.
+pub(crate) fn shortcut_for(action: &Action) -> Option<egui::KeyboardShortcut> {
+    SHORTCUTS
+        .iter()
+        .find(|(_, bound)| bound == action)
+        .map(|(shortcut, _)| *shortcut)
+}
+
+/// Triggers the action of the first shortcut in [`SHORTCUTS`] that was pressed this frame,
+/// if the dispatcher supports and enables it.
+pub(crate) fn handle_global_shortcuts(ctx: &egui::Context, dispatcher: &mut impl ActionDispatcher) {
+    let descriptors = SHORTCUTS
+        .iter()
+        .map(|(shortcut, action)| {
+            ActionDescriptor::new(action.clone(), "").with_shortcut(*shortcut)
+        })
+        .collect::<Vec<_>>();
+    actions::handle_shortcuts(ctx, dispatcher, &descriptors);
+}
+
+/// Paths of the files dropped onto the window this frame. Drops without a path, as on the
+/// web, are ignored.
+pub(crate) fn dropped_paths(ctx: &egui::Context) -> Vec<PathBuf> {
+    ctx.input(|input| {
+        input
+            .raw
+            .dropped_files
+            .iter()
+            .filter_map(|file| file.path.clone())
+            .collect()
+    })
+}
+
 pub trait View<TState> {
     fn show(&mut self, ui: &mut egui::Ui, state: &mut TState);
 }
@@ -23,8 +99,22 @@ impl View<PackerApp> for TimestampRulesPanel {
 }
 
 impl eframe::App for PackerApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        DisplaySettings {
+            zoom_factor: self.zoom_factor,
+            palette: self.palette_id,
+        }
+        .save(storage);
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.poll_pack_job();
+        self.process_events();
+        handle_global_shortcuts(ctx, self);
+        let dropped = dropped_paths(ctx);
+        if !dropped.is_empty() {
+            self.handle_dropped_files(dropped);
+        }
 
         if ctx.input(|i| i.viewport().close_requested()) && !self.exit_confirmed {
             self.trigger_action(Action::ShowExitConfirmation);
@@ -87,6 +177,17 @@ impl eframe::App for PackerApp {
                         actions::action_button(ui, self, &zoom_out);
                         let reset_zoom = ActionDescriptor::new(Action::ResetZoom, "Reset Zoom");
                         actions::action_button(ui, self, &reset_zoom);
+                        ui.separator();
+                        ui.label("Theme");
+                        for palette in theme::PaletteId::ALL {
+                            if ui
+                                .radio(self.palette_id == palette, palette.label())
+                                .clicked()
+                            {
+                                self.set_palette(ui.ctx(), palette);
+                                ui.close_menu();
+                            }
+                        }
                     });
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         ui.add_space(12.0);
@@ -98,7 +199,7 @@ impl eframe::App for PackerApp {
 
         shell::show_shell(ctx, self);
 
-        ui::dialogs::pack_confirmation(self, ctx);
+        ui::dialogs::pending_confirmation(self, ctx);
         ui::dialogs::exit_confirmation(self, ctx);
     }
 }
@@ -124,6 +225,138 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct RecordingDispatcher {
+        enabled: bool,
+        triggered: Vec<Action>,
+    }
+
+    impl ActionDispatcher for RecordingDispatcher {
+        fn is_action_enabled(&self, _action: Action) -> bool {
+            self.enabled
+        }
+
+        fn trigger_action(&mut self, action: Action) {
+            self.triggered.push(action);
+        }
+    }
+
+    fn press_with_command(key: egui::Key) -> egui::RawInput {
+        egui::RawInput {
+            modifiers: egui::Modifiers::COMMAND,
+            events: vec![egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers::COMMAND,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryStorage(std::collections::HashMap<String, String>);
+
+    impl eframe::Storage for MemoryStorage {
+        fn get_string(&self, key: &str) -> Option<String> {
+            self.0.get(key).cloned()
+        }
+
+        fn set_string(&mut self, key: &str, value: String) {
+            self.0.insert(key.to_string(), value);
+        }
+
+        fn flush(&mut self) {}
+    }
+
+    #[test]
+    fn display_settings_round_trip_through_storage() {
+        let mut storage = MemoryStorage::default();
+        assert_eq!(DisplaySettings::load(&storage), None);
+
+        let settings = DisplaySettings {
+            zoom_factor: 1.3,
+            palette: theme::PaletteId::HighContrast,
+        };
+        settings.save(&mut storage);
+        assert_eq!(DisplaySettings::load(&storage), Some(settings));
+
+        DisplaySettings {
+            zoom_factor: 7.0,
+            ..settings
+        }
+        .save(&mut storage);
+        let loaded = DisplaySettings::load(&storage).expect("settings saved");
+        assert_eq!(loaded.zoom_factor, 2.0);
+    }
+
+    #[test]
+    fn command_p_triggers_pack_when_enabled() {
+        let ctx = egui::Context::default();
+        let mut dispatcher = RecordingDispatcher {
+            enabled: true,
+            ..Default::default()
+        };
+        let _ = ctx.run(press_with_command(egui::Key::P), |ctx| {
+            handle_global_shortcuts(ctx, &mut dispatcher);
+        });
+        assert_eq!(dispatcher.triggered, vec![Action::PackPsu]);
+
+        let mut disabled = RecordingDispatcher::default();
+        let _ = ctx.run(press_with_command(egui::Key::P), |ctx| {
+            handle_global_shortcuts(ctx, &mut disabled);
+        });
+        assert!(disabled.triggered.is_empty());
+    }
+
+    fn drop_files(ctx: &egui::Context, paths: &[&Path]) -> Vec<PathBuf> {
+        let input = egui::RawInput {
+            dropped_files: paths
+                .iter()
+                .map(|path| egui::DroppedFile {
+                    path: Some(path.to_path_buf()),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut dropped = Vec::new();
+        let _ = ctx.run(input, |ctx| dropped = dropped_paths(ctx));
+        dropped
+    }
+
+    #[test]
+    fn dropped_files_are_added_to_the_include_list() {
+        let workspace = tempdir().expect("temp workspace");
+        let project = workspace.path().join("project");
+        let nested = project.join("nested");
+        fs::create_dir_all(&nested).expect("create project folders");
+        let data = project.join("DATA.BIN");
+        fs::write(&data, b"data").expect("write data file");
+
+        let mut app = PackerApp::default();
+        app.packer_state.folder = Some(project.clone());
+        let ctx = egui::Context::default();
+
+        app.handle_dropped_files(drop_files(&ctx, &[&data]));
+        assert_eq!(app.packer_state.include_files, vec!["DATA.BIN".to_string()]);
+
+        app.handle_dropped_files(drop_files(&ctx, &[&nested]));
+        assert_eq!(app.packer_state.include_files.len(), 1);
+        assert!(app.packer_state.status.contains("folders cannot be added"));
+    }
+
+    #[test]
+    fn shortcut_table_has_no_duplicates() {
+        for (index, (shortcut, action)) in SHORTCUTS.iter().enumerate() {
+            assert_eq!(shortcut_for(action), Some(*shortcut));
+            assert!(SHORTCUTS[..index]
+                .iter()
+                .all(|(earlier, _)| earlier != shortcut));
+        }
+    }
+
     fn dispatch_action(app: &mut PackerApp, descriptor: &ActionDescriptor) {
         let action = descriptor.action.clone();
         assert!(app.supports_action(action.clone()));
@@ -194,16 +194,7 @@ fn content_panes<'a>() -> Vec<ContentPane<'a, PackerApp, EditorAction>> {
             &mut app.psu_toml_editor,
         );
         if actions.save_clicked {
-            let folder = app.packer_state.folder.as_deref();
-            match state::save_editor_to_disk(folder, "psu.toml", &mut app.psu_toml_editor) {
-                Ok(path) => {
-                    app.packer_state.status = format!("Saved {}", path.display());
-                    app.clear_error_message();
-                }
-                Err(err) => {
-                    app.set_error_message(format!("Failed to save psu.toml: {err}"));
-                }
-            }
+            app.save_text_editor("psu.toml");
         }
         if actions.apply_clicked {
             app.apply_psu_toml_edits();
@@ -217,20 +208,7 @@ fn content_panes<'a>() -> Vec<ContentPane<'a, PackerApp, EditorAction>> {
         let actions =
             state::title_cfg_form_ui(ui, editing_enabled, save_enabled, &mut app.title_cfg_editor);
         if actions.save_clicked {
-            let folder = app.packer_state.folder.clone();
-            match state::save_editor_to_disk(
-                folder.as_deref(),
-                "title.cfg",
-                &mut app.title_cfg_editor,
-            ) {
-                Ok(path) => {
-                    app.packer_state.status = format!("Saved {}", path.display());
-                    app.clear_error_message();
-                }
-                Err(err) => {
-                    app.set_error_message(format!("Failed to save title.cfg: {err}"));
-                }
-            }
+            app.save_text_editor("title.cfg");
         }
         if actions.apply_clicked {
             app.apply_title_cfg_edits();
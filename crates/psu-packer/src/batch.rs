@@ -0,0 +1,235 @@
+use std::path::{Path, PathBuf};
+
+use ps2_filetypes::TitleCfg;
+
+use crate::{load_config, pack_with_config, Config, Error};
+
+/// Save name prefixes of the SAS folder layout, in menu order. `{prefix}` and `{base}` split
+/// names at these, and gui-core's `SasPrefix` is built on the same list.
+pub const SAVE_NAME_PREFIXES: [&str; 13] = [
+    "APP_", "APPS", "PS1_", "EMU_", "GME_", "DST_", "DBG_", "RAA_", "RTE_", "DEFAULT", "SYS_",
+    "ZZY_", "ZZZ_",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Prefix,
+    Base,
+    Name,
+    Version,
+}
+
+impl Field {
+    fn parse(placeholder: &str) -> Option<Self> {
+        match placeholder {
+            "prefix" => Some(Self::Prefix),
+            "base" => Some(Self::Base),
+            "name" => Some(Self::Name),
+            "version" => Some(Self::Version),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Field(Field),
+}
+
+/// Output file name template used by [`pack_all_with_pattern`], such as
+/// `{prefix}{base}_v{version}.psu`.
+///
+/// Supported placeholders are `{prefix}` and `{base}` (the save name split at its SAS prefix),
+/// `{name}` (the full save name) and `{version}` (the `Version` key of the project's title.cfg).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputNamePattern {
+    segments: Vec<Segment>,
+}
+
+impl OutputNamePattern {
+    /// Pattern that names each PSU after its save name.
+    pub const DEFAULT: &'static str = "{name}.psu";
+
+    /// Parses `pattern`, rejecting unknown placeholders and unbalanced braces.
+    pub fn parse(pattern: &str) -> Result<Self, Error> {
+        let mut segments = Vec::new();
+        let mut rest = pattern;
+        while !rest.is_empty() {
+            let literal_end = rest.find(['{', '}']).unwrap_or(rest.len());
+            if literal_end > 0 {
+                segments.push(Segment::Literal(rest[..literal_end].to_string()));
+                rest = &rest[literal_end..];
+                continue;
+            }
+
+            if rest.starts_with('}') {
+                return Err(Error::ConfigError(format!(
+                    "Unmatched '}}' in output name pattern '{pattern}'"
+                )));
+            }
+            let Some(close) = rest.find('}') else {
+                return Err(Error::ConfigError(format!(
+                    "Unclosed '{{' in output name pattern '{pattern}'"
+                )));
+            };
+            let placeholder = &rest[1..close];
+            let field = Field::parse(placeholder).ok_or_else(|| {
+                Error::ConfigError(format!(
+                    "Unknown placeholder '{{{placeholder}}}' in output name pattern '{pattern}'"
+                ))
+            })?;
+            segments.push(Segment::Field(field));
+            rest = &rest[close + 1..];
+        }
+
+        if segments.is_empty() {
+            return Err(Error::ConfigError(
+                "Output name pattern must not be empty".to_string(),
+            ));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Renders the file name for the project in `folder` configured by `cfg`.
+    ///
+    /// title.cfg is only read when the pattern uses `{version}`; a missing file or key is an
+    /// error, as is a result that is empty, `.` or `..`, or contains a path separator.
+    pub fn render(&self, folder: &Path, cfg: &Config) -> Result<String, Error> {
+        let (prefix, base) = split_prefix(&cfg.name);
+        let mut version = None;
+        let mut file_name = String::new();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(text) => file_name.push_str(text),
+                Segment::Field(Field::Prefix) => file_name.push_str(prefix),
+                Segment::Field(Field::Base) => file_name.push_str(base),
+                Segment::Field(Field::Name) => file_name.push_str(&cfg.name),
+                Segment::Field(Field::Version) => {
+                    if version.is_none() {
+                        version = Some(read_version(folder)?);
+                    }
+                    file_name.push_str(version.as_deref().unwrap_or_default());
+                }
+            }
+        }
+
+        if file_name.is_empty()
+            || file_name.contains(['/', '\\'])
+            || matches!(file_name.as_str(), "." | "..")
+        {
+            return Err(Error::ConfigError(format!(
+                "Output name pattern produced an invalid file name '{file_name}'"
+            )));
+        }
+
+        Ok(file_name)
+    }
+}
+
+impl Default for OutputNamePattern {
+    fn default() -> Self {
+        Self::parse(Self::DEFAULT).expect("default output name pattern is valid")
+    }
+}
+
+/// Finds the [`SAVE_NAME_PREFIXES`] entry `name` starts with and returns its index and the rest
+/// of the name. `DEFAULT_` counts as the `DEFAULT` prefix, underscore included.
+pub fn split_save_name_prefix(name: &str) -> Option<(usize, &str)> {
+    SAVE_NAME_PREFIXES
+        .iter()
+        .enumerate()
+        .find_map(|(index, prefix)| {
+            let rest = match *prefix {
+                "DEFAULT" => name
+                    .strip_prefix("DEFAULT_")
+                    .or_else(|| name.strip_prefix(prefix)),
+                _ => name.strip_prefix(prefix),
+            };
+            rest.map(|rest| (index, rest))
+        })
+}
+
+/// Splits a save name into its recognised prefix (or `""`) and the rest.
+pub(crate) fn split_prefix(name: &str) -> (&str, &str) {
+    match split_save_name_prefix(name) {
+        Some((_, rest)) => name.split_at(name.len() - rest.len()),
+        None => ("", name),
+    }
+}
+
+fn read_version(folder: &Path) -> Result<String, Error> {
+    let path = folder.join("title.cfg");
+    let contents = std::fs::read_to_string(&path).map_err(|err| {
+        Error::ConfigError(format!("{{version}} requires {}: {err}", path.display()))
+    })?;
+    TitleCfg::new(contents)
+        .index_map
+        .get("Version")
+        .filter(|version| !version.trim().is_empty())
+        .map(|version| version.trim().to_string())
+        .ok_or_else(|| {
+            Error::ConfigError(format!(
+                "{{version}} requires a Version entry in {}",
+                path.display()
+            ))
+        })
+}
+
+/// A project folder paired with the PSU written for it, or the error that stopped it.
+pub type BatchPackResult = (PathBuf, Result<PathBuf, Error>);
+
+/// Packs every immediate subfolder of `root` that contains a `psu.toml` into `output_dir`,
+/// naming each PSU after its save name. Subfolders without a `psu.toml` are skipped.
+///
+/// Like [`pack_all_with_pattern`] with [`OutputNamePattern::DEFAULT`].
+pub fn pack_all(root: &Path, output_dir: &Path) -> Vec<BatchPackResult> {
+    pack_all_with_pattern(root, output_dir, &OutputNamePattern::default())
+}
+
+/// Packs every immediate subfolder of `root` that contains a `psu.toml` into `output_dir`,
+/// naming each PSU with `pattern`.
+///
+/// Folders are processed in name order and one failure does not stop the batch. A failure to
+/// list `root` is reported as a result for `root` itself, ahead of the folders that could be
+/// listed.
+pub fn pack_all_with_pattern(
+    root: &Path,
+    output_dir: &Path,
+    pattern: &OutputNamePattern,
+) -> Vec<BatchPackResult> {
+    let entries = match std::fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) => return vec![(root.to_path_buf(), Err(err.into()))],
+    };
+
+    let mut results = Vec::new();
+    let mut folders = Vec::new();
+    for entry in entries {
+        match entry {
+            Ok(entry) => folders.push(entry.path()),
+            Err(err) => results.push((root.to_path_buf(), Err(err.into()))),
+        }
+    }
+    folders.retain(|path| path.is_dir() && path.join("psu.toml").is_file());
+    folders.sort();
+
+    results.extend(folders.into_iter().map(|folder| {
+        let result = pack_one(&folder, output_dir, pattern);
+        (folder, result)
+    }));
+    results
+}
+
+fn pack_one(
+    folder: &Path,
+    output_dir: &Path,
+    pattern: &OutputNamePattern,
+) -> Result<PathBuf, Error> {
+    let cfg = load_config(folder)?;
+    let output = output_dir.join(pattern.render(folder, &cfg)?);
+    pack_with_config(folder, &output, cfg)?;
+    Ok(output)
+}
@@ -0,0 +1,239 @@
+use std::path::{Path, PathBuf};
+
+use ps2_filetypes::{PSUEntry, PSUEntryKind, PSUWriter, FILE_ID, PSU};
+
+use crate::{plan_merged_pack, Config, Error, MergeConflictPolicy, PlannedEntry};
+
+/// How a file differs between a project folder and a previously packed PSU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The file would be packed but is missing from the PSU.
+    Added,
+    /// The PSU holds a file that would no longer be packed.
+    Removed,
+    /// The file is in both, but its contents or timestamps differ.
+    Modified,
+}
+
+/// A file whose packed form would change, as reported by [`pending_changes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingChange {
+    pub name: String,
+    pub kind: ChangeKind,
+}
+
+/// Lists the files that packing `folder` with `cfg` would change compared to the PSU at
+/// `psu_path`.
+///
+/// The folder side comes from [`plan_pack`], so include/exclude lists and a generated icon.sys
+/// are taken into account. Files are compared by contents and by their created and modified
+/// times. Packed files come first in archive order, followed by removed files in PSU order.
+pub fn pending_changes(
+    folder: &Path,
+    cfg: &Config,
+    psu_path: &Path,
+) -> Result<Vec<PendingChange>, Error> {
+    pending_merged_changes(folder, &[], cfg, MergeConflictPolicy::default(), psu_path)
+}
+
+/// [`pending_changes`] for a PSU packed with [`pack_merged`](crate::pack_merged): files from
+/// `secondaries` are planned the same way the merged pack would take them.
+pub fn pending_merged_changes(
+    folder: &Path,
+    secondaries: &[PathBuf],
+    cfg: &Config,
+    policy: MergeConflictPolicy,
+    psu_path: &Path,
+) -> Result<Vec<PendingChange>, Error> {
+    let psu = PSU::try_new(std::fs::read(psu_path)?)?;
+    Ok(compare(folder, secondaries, cfg, policy, &psu)?.changes)
+}
+
+/// Rewrites the PSU at `psu_path` into `output`, touching only the entries that
+/// [`pending_changes`] reports.
+///
+/// Unchanged entries keep their position, sector and timestamps, and the directory entries
+/// are copied as they are apart from the root directory size, which is adjusted by the
+/// number of files added or removed. Modified files are replaced where they stand, removed
+/// files are dropped and added files are appended after the existing entries. A generated
+/// icon.sys is embedded without being written to `folder`. Returns the applied changes.
+pub fn update_psu_in_place(
+    folder: &Path,
+    cfg: &Config,
+    psu_path: &Path,
+    output: &Path,
+) -> Result<Vec<PendingChange>, Error> {
+    update_merged_psu_in_place(
+        folder,
+        &[],
+        cfg,
+        MergeConflictPolicy::default(),
+        psu_path,
+        output,
+    )
+}
+
+/// [`update_psu_in_place`] for a PSU packed with [`pack_merged`](crate::pack_merged): files
+/// from `secondaries` are compared and written the same way the merged pack would take them.
+pub fn update_merged_psu_in_place(
+    folder: &Path,
+    secondaries: &[PathBuf],
+    cfg: &Config,
+    policy: MergeConflictPolicy,
+    psu_path: &Path,
+    output: &Path,
+) -> Result<Vec<PendingChange>, Error> {
+    cfg.validate_names()?;
+
+    let psu = PSU::try_new(std::fs::read(psu_path)?)?;
+    let Comparison {
+        changes,
+        planned,
+        generated_icon_sys,
+    } = compare(folder, secondaries, cfg, policy, &psu)?;
+
+    let planned_entry = |name: &str| planned.iter().find(|entry| entry.name == name);
+    let read_contents = |name: &str| -> Result<Vec<u8>, Error> {
+        match &generated_icon_sys {
+            Some(bytes) if name == "icon.sys" => Ok(bytes.clone()),
+            _ => Ok(std::fs::read(planned_source(
+                folder,
+                planned_entry(name),
+                name,
+            ))?),
+        }
+    };
+    let change_for = |name: &str| changes.iter().find(|change| change.name == name);
+
+    let added = changes
+        .iter()
+        .filter(|change| change.kind == ChangeKind::Added)
+        .count();
+    let removed = changes
+        .iter()
+        .filter(|change| change.kind == ChangeKind::Removed)
+        .count();
+
+    let mut entries = Vec::with_capacity(psu.entries.len() + added);
+    for (index, mut entry) in psu.entries.into_iter().enumerate() {
+        if index == 0 && matches!(entry.kind, PSUEntryKind::Directory) {
+            entry.name = cfg.root_entry_name().to_owned();
+            entry.size = (entry.size as usize + added).saturating_sub(removed) as u32;
+        } else if matches!(entry.kind, PSUEntryKind::File) {
+            match change_for(&entry.name).map(|change| change.kind) {
+                Some(ChangeKind::Removed) => continue,
+                Some(ChangeKind::Modified) => {
+                    let contents = read_contents(&entry.name)?;
+                    if let Some(plan) = planned_entry(&entry.name) {
+                        entry.created = plan.created;
+                        entry.modified = plan.modified;
+                    }
+                    entry.size = contents.len() as u32;
+                    entry.contents = Some(contents);
+                }
+                _ => {}
+            }
+        }
+        entries.push(entry);
+    }
+
+    for plan in &planned {
+        if change_for(&plan.name).map(|change| change.kind) != Some(ChangeKind::Added) {
+            continue;
+        }
+        let contents = read_contents(&plan.name)?;
+        entries.push(PSUEntry {
+            id: FILE_ID,
+            size: contents.len() as u32,
+            created: plan.created,
+            sector: 0,
+            modified: plan.modified,
+            name: plan.name.clone(),
+            kind: PSUEntryKind::File,
+            contents: Some(contents),
+        });
+    }
+
+    std::fs::write(output, PSUWriter::new(PSU { entries }).to_bytes()?)?;
+    Ok(changes)
+}
+
+struct Comparison {
+    changes: Vec<PendingChange>,
+    /// The packed files `plan_pack` reports for the folder.
+    planned: Vec<PlannedEntry>,
+    generated_icon_sys: Option<Vec<u8>>,
+}
+
+/// Where a planned file is read from. Entries without a source fall back to `folder`.
+fn planned_source(folder: &Path, entry: Option<&PlannedEntry>, name: &str) -> PathBuf {
+    entry
+        .and_then(|entry| entry.source.clone())
+        .unwrap_or_else(|| folder.join(name))
+}
+
+fn compare(
+    folder: &Path,
+    secondaries: &[PathBuf],
+    cfg: &Config,
+    policy: MergeConflictPolicy,
+    psu: &PSU,
+) -> Result<Comparison, Error> {
+    let packed = psu
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+        .collect::<Vec<_>>();
+    let generated_icon_sys = cfg
+        .generated_icon_sys()?
+        .map(|icon_config| icon_config.to_bytes())
+        .transpose()?;
+
+    let planned = plan_merged_pack(folder, secondaries, cfg, policy)?
+        .into_iter()
+        .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+        .collect::<Vec<_>>();
+
+    let mut changes = Vec::new();
+    for entry in &planned {
+        let Some(existing) = packed.iter().find(|packed| packed.name == entry.name) else {
+            changes.push(PendingChange {
+                name: entry.name.clone(),
+                kind: ChangeKind::Added,
+            });
+            continue;
+        };
+
+        let path = planned_source(folder, Some(entry), &entry.name);
+        let contents = match &generated_icon_sys {
+            Some(bytes) if entry.name == "icon.sys" => bytes.clone(),
+            _ => std::fs::read(&path)?,
+        };
+        // A generated icon.sys that is not on disk yet is stamped with the time of packing.
+        let times_known = cfg.timestamp.is_some() || path.exists();
+        let times_differ = times_known
+            && (existing.created != entry.created || existing.modified != entry.modified);
+
+        if existing.contents.as_deref() != Some(contents.as_slice()) || times_differ {
+            changes.push(PendingChange {
+                name: entry.name.clone(),
+                kind: ChangeKind::Modified,
+            });
+        }
+    }
+
+    for existing in &packed {
+        if !planned.iter().any(|entry| entry.name == existing.name) {
+            changes.push(PendingChange {
+                name: existing.name.clone(),
+                kind: ChangeKind::Removed,
+            });
+        }
+    }
+
+    Ok(Comparison {
+        changes,
+        planned,
+        generated_icon_sys,
+    })
+}
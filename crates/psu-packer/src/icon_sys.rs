@@ -1,8 +1,9 @@
 use ps2_filetypes::color::Color;
 use ps2_filetypes::{self, sjis, ColorF, IconSys, Vector};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct IconSysConfig {
     pub flags: IconSysFlags,
     pub title: String,
@@ -22,6 +23,42 @@ pub struct IconSysConfig {
     pub ambient_color: Option<ColorFConfig>,
 }
 
+impl From<&IconSys> for IconSysConfig {
+    /// Captures every field of a parsed icon.sys. The icon file names are not part of the
+    /// config; packing always writes [`ICON_FILE_NAME`].
+    fn from(icon_sys: &IconSys) -> Self {
+        IconSysConfig {
+            flags: IconSysFlags::new(icon_sys.flags),
+            title: icon_sys.title.clone(),
+            linebreak_pos: Some(icon_sys.linebreak_pos),
+            preset: None,
+            background_transparency: Some(icon_sys.background_transparency),
+            background_colors: Some(
+                icon_sys
+                    .background_colors
+                    .iter()
+                    .map(|color| (*color).into())
+                    .collect(),
+            ),
+            light_directions: Some(
+                icon_sys
+                    .light_directions
+                    .iter()
+                    .map(|direction| (*direction).into())
+                    .collect(),
+            ),
+            light_colors: Some(
+                icon_sys
+                    .light_colors
+                    .iter()
+                    .map(|color| (*color).into())
+                    .collect(),
+            ),
+            ambient_color: Some(icon_sys.ambient_color.into()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ResolvedIconSysConfig {
     pub background_transparency: u32,
@@ -33,6 +70,20 @@ pub struct ResolvedIconSysConfig {
 
 impl IconSysConfig {
     pub fn to_bytes(&self) -> Result<Vec<u8>, crate::Error> {
+        if shift_jis_byte_length(&self.title).is_err() {
+            let (position, character) = self
+                .title
+                .chars()
+                .enumerate()
+                .find(|(_, c)| sjis::encode_sjis(&c.to_string()).is_err())
+                .unwrap_or((0, '\u{FFFD}'));
+            return Err(crate::Error::ConfigError(format!(
+                "icon_sys.title contains '{character}' (U+{:04X}) at character {}, which cannot be encoded as Shift-JIS",
+                character as u32,
+                position + 1
+            )));
+        }
+
         let icon_sys = self.build_icon_sys()?;
         icon_sys
             .to_bytes()
@@ -110,6 +161,22 @@ impl IconSysConfig {
 }
 
 impl IconSysConfig {
+    /// Creates a config with every background and lighting field set to its default, ready
+    /// to be adjusted field by field.
+    pub fn new(flags: IconSysFlags, title: impl Into<String>, linebreak_pos: u16) -> Self {
+        Self {
+            flags,
+            title: title.into(),
+            linebreak_pos: Some(linebreak_pos),
+            preset: None,
+            background_transparency: Some(Self::default_background_transparency()),
+            background_colors: Some(Self::default_background_colors().to_vec()),
+            light_directions: Some(Self::default_light_directions().to_vec()),
+            light_colors: Some(Self::default_light_colors().to_vec()),
+            ambient_color: Some(Self::default_ambient_color()),
+        }
+    }
+
     pub const fn default_linebreak_pos() -> u16 {
         DEFAULT_LINEBREAK_POS
     }
@@ -242,6 +309,70 @@ impl IconSysConfig {
             .unwrap_or_else(Self::default_ambient_color)
     }
 
+    /// Byte offset of the line break for a title whose first line is `line1`.
+    ///
+    /// The offset is the Shift-JIS byte length of `line1`, not its character count.
+    pub fn compute_linebreak(line1: &str) -> Result<u16, String> {
+        let length = shift_jis_byte_length(line1).map_err(|_| {
+            "Icon.sys titles must contain characters representable in Shift-JIS".to_string()
+        })?;
+        u16::try_from(length)
+            .map_err(|_| format!("Icon.sys line 1 is {length} bytes, too long for a line break"))
+    }
+
+    /// Joins two title lines into the stored title and its line break offset.
+    ///
+    /// A blank `line2` is dropped, so a single-line title is stored without trailing spaces and
+    /// the break falls at the end of the title instead of before an empty second line.
+    pub fn title_from_lines(line1: &str, line2: &str) -> Result<(String, u16), String> {
+        let linebreak_pos = Self::compute_linebreak(line1)?;
+        if line2.trim().is_empty() {
+            return Ok((line1.to_string(), linebreak_pos));
+        }
+        Ok((format!("{line1}{line2}"), linebreak_pos))
+    }
+
+    /// Stores a title given as two separate lines, such as a pasted `"LINE1\nLINE2"`.
+    ///
+    /// Both lines are sanitized to [`ICON_SYS_TITLE_CHAR_LIMIT`] characters and the line break
+    /// is placed after the Shift-JIS bytes of the first line. Returns a warning for each line
+    /// that had to be truncated.
+    pub fn set_title_from_lines(&mut self, line1: &str, line2: &str) -> Vec<String> {
+        self.set_title_from_lines_with_limit(line1, line2, ICON_SYS_TITLE_CHAR_LIMIT)
+    }
+
+    /// Like [`set_title_from_lines`](Self::set_title_from_lines), but keeps up to `limit`
+    /// characters per line.
+    pub fn set_title_from_lines_with_limit(
+        &mut self,
+        line1: &str,
+        line2: &str,
+        limit: usize,
+    ) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let [line1, line2] = [line1, line2].map(|line| line.trim_end_matches(['\r', '\n']));
+        for (index, line) in [line1, line2].into_iter().enumerate() {
+            let length = line.chars().count();
+            if length > limit {
+                warnings.push(format!(
+                    "Line {} is {length} characters and was truncated to {limit}",
+                    index + 1
+                ));
+            }
+        }
+
+        let line1 = sanitize_icon_sys_line(line1, limit);
+        let line2 = sanitize_icon_sys_line(line2, limit);
+        match Self::title_from_lines(&line1, &line2) {
+            Ok((title, linebreak_pos)) => {
+                self.title = title;
+                self.linebreak_pos = Some(linebreak_pos);
+            }
+            Err(message) => warnings.push(message),
+        }
+        warnings
+    }
+
     pub fn linebreak_position(&self) -> u16 {
         self.linebreak_pos.unwrap_or(Self::default_linebreak_pos())
     }
@@ -295,6 +426,29 @@ impl IconSysConfig {
             ambient_color,
         }
     }
+
+    /// Captures the resolved colors and lights as a named preset that can be saved as JSON.
+    pub fn to_preset(&self, name: &str) -> IconSysPresetFile {
+        let resolved = self.resolved_with_fallback(None);
+        IconSysPresetFile {
+            name: name.to_string(),
+            background_transparency: resolved.background_transparency,
+            background_colors: resolved.background_colors,
+            light_directions: resolved.light_directions,
+            light_colors: resolved.light_colors,
+            ambient_color: resolved.ambient_color,
+        }
+    }
+
+    /// Overwrites the colors and lights with those of `preset`. Flags and title are kept.
+    pub fn apply_preset_file(&mut self, preset: &IconSysPresetFile) {
+        self.background_transparency = Some(preset.background_transparency);
+        self.background_colors = Some(preset.background_colors.to_vec());
+        self.light_directions = Some(preset.light_directions.to_vec());
+        self.light_colors = Some(preset.light_colors.to_vec());
+        self.ambient_color = Some(preset.ambient_color);
+        self.preset = None;
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
@@ -327,6 +481,18 @@ impl From<Color> for ColorConfig {
     }
 }
 
+impl ColorConfig {
+    /// Parses `#RRGGBB` or `#RRGGBBAA` (the `#` is optional). Six-digit values are opaque.
+    pub fn from_hex(value: &str) -> Result<ColorConfig, String> {
+        parse_hex_rgba(value).map(rgba_to_color_config)
+    }
+
+    /// Formats the color as `#RRGGBBAA`.
+    pub fn to_hex(&self) -> String {
+        format_hex_rgba(color_config_to_rgba(*self))
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
 pub struct ColorFConfig {
     pub r: f32,
@@ -357,6 +523,49 @@ impl From<ColorF> for ColorFConfig {
     }
 }
 
+impl ColorFConfig {
+    /// Parses `#RRGGBB` or `#RRGGBBAA` into components in the 0.0–1.0 range.
+    pub fn from_hex(value: &str) -> Result<ColorFConfig, String> {
+        parse_hex_rgba(value)
+            .map(|rgba| rgba_to_color_f_config(rgba.map(|component| component as f32 / 255.0)))
+    }
+
+    /// Formats the color as `#RRGGBBAA`, clamping each component to 0.0–1.0 first.
+    pub fn to_hex(&self) -> String {
+        let rgba = color_f_config_to_rgba(*self)
+            .map(|component| (component.clamp(0.0, 1.0) * 255.0).round() as u8);
+        format_hex_rgba(rgba)
+    }
+}
+
+fn parse_hex_rgba(value: &str) -> Result<[u8; 4], String> {
+    let trimmed = value.trim();
+    let digits = trimmed.strip_prefix('#').unwrap_or(trimmed);
+    if digits.len() != 6 && digits.len() != 8 {
+        return Err(format!(
+            "Expected 6 or 8 hex digits (#RRGGBB or #RRGGBBAA), got {}",
+            digits.len()
+        ));
+    }
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("\"{trimmed}\" is not a valid hex color"));
+    }
+
+    let component = |index: usize| u8::from_str_radix(&digits[index * 2..index * 2 + 2], 16);
+    let mut rgba = [0xFF; 4];
+    for (index, slot) in rgba.iter_mut().enumerate().take(digits.len() / 2) {
+        *slot = component(index).map_err(|err| err.to_string())?;
+    }
+    Ok(rgba)
+}
+
+fn format_hex_rgba(rgba: [u8; 4]) -> String {
+    format!(
+        "#{:02X}{:02X}{:02X}{:02X}",
+        rgba[0], rgba[1], rgba[2], rgba[3]
+    )
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
 pub struct VectorConfig {
     pub x: f32,
@@ -365,6 +574,33 @@ pub struct VectorConfig {
     pub w: f32,
 }
 
+impl VectorConfig {
+    /// How far a direction's length may stray from 1.0 and still count as normalized.
+    pub const NORMALIZED_EPSILON: f32 = 0.01;
+
+    /// Length of the x, y, and z components. `w` is not part of the direction.
+    pub fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    pub fn is_normalized(&self) -> bool {
+        (self.magnitude() - 1.0).abs() <= Self::NORMALIZED_EPSILON
+    }
+
+    /// Scales x, y, and z to unit length, leaving `w` alone. A zero or non-finite vector
+    /// becomes the default direction `(0, 0, 1, 0)`.
+    pub fn normalize(&mut self) {
+        let magnitude = self.magnitude();
+        if !magnitude.is_finite() || magnitude <= f32::EPSILON {
+            *self = VectorConfig::from(DEFAULT_LIGHT_DIRECTIONS[0]);
+            return;
+        }
+        self.x /= magnitude;
+        self.y /= magnitude;
+        self.z /= magnitude;
+    }
+}
+
 impl From<VectorConfig> for Vector {
     fn from(value: VectorConfig) -> Self {
         Vector {
@@ -487,6 +723,100 @@ pub struct IconSysPreset {
     pub ambient_color: ColorFConfig,
 }
 
+/// A user-authored preset stored as a standalone JSON file, shaped like [`IconSysPreset`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IconSysPresetFile {
+    pub name: String,
+    pub background_transparency: u32,
+    pub background_colors: [ColorConfig; 4],
+    pub light_directions: [VectorConfig; 3],
+    pub light_colors: [ColorFConfig; 3],
+    pub ambient_color: ColorFConfig,
+}
+
+impl IconSysPresetFile {
+    pub fn to_json(&self) -> Result<String, crate::Error> {
+        serde_json::to_string_pretty(self).map_err(|err| crate::Error::ConfigError(err.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, crate::Error> {
+        serde_json::from_str(json).map_err(|err| {
+            crate::Error::ConfigError(format!("Invalid icon.sys preset file: {err}"))
+        })
+    }
+}
+
+impl IconSysPresetFile {
+    /// Replaces the colors and lights of `icon_sys`, leaving its title, flags, and icon
+    /// filenames untouched.
+    pub fn apply_to(&self, icon_sys: &mut IconSys) {
+        icon_sys.background_transparency = self.background_transparency;
+        icon_sys.background_colors = self.background_colors.map(Color::from);
+        icon_sys.light_directions = self.light_directions.map(Vector::from);
+        icon_sys.light_colors = self.light_colors.map(ColorF::from);
+        icon_sys.ambient_color = self.ambient_color.into();
+    }
+}
+
+/// Outcome of [`recolor_icon_sys_projects`], with project folders sorted by path.
+#[derive(Debug, Default)]
+pub struct RecolorReport {
+    pub updated: Vec<PathBuf>,
+    pub missing_icon_sys: Vec<PathBuf>,
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+/// Applies the colors and lights of `preset` to the icon.sys of every project folder directly
+/// inside `projects_dir`.
+///
+/// Projects without an icon.sys are reported rather than given one, and a project whose
+/// icon.sys cannot be read or written does not stop the others from being updated.
+pub fn recolor_icon_sys_projects(
+    projects_dir: &Path,
+    preset: &IconSysPresetFile,
+) -> Result<RecolorReport, crate::Error> {
+    let mut projects = std::fs::read_dir(projects_dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    projects.sort();
+
+    let mut report = RecolorReport::default();
+    for project in projects {
+        let icon_sys_path = project.join("icon.sys");
+        if !icon_sys_path.is_file() {
+            report.missing_icon_sys.push(project);
+            continue;
+        }
+
+        let result = std::fs::read(&icon_sys_path).and_then(|bytes| {
+            let mut icon_sys = IconSys::try_new(bytes)?;
+            preset.apply_to(&mut icon_sys);
+            std::fs::write(&icon_sys_path, icon_sys.to_bytes()?)
+        });
+        match result {
+            Ok(()) => report.updated.push(project),
+            Err(err) => report.failed.push((project, err.to_string())),
+        }
+    }
+
+    Ok(report)
+}
+
+impl From<&IconSysPreset> for IconSysPresetFile {
+    fn from(preset: &IconSysPreset) -> Self {
+        IconSysPresetFile {
+            name: preset.label.to_string(),
+            background_transparency: preset.background_transparency,
+            background_colors: preset.background_colors,
+            light_directions: preset.light_directions,
+            light_colors: preset.light_colors,
+            ambient_color: preset.ambient_color,
+        }
+    }
+}
+
 pub const ICON_SYS_PRESETS: &[IconSysPreset] = &[
     IconSysPreset {
         id: "default",
@@ -658,10 +988,56 @@ pub const ICON_SYS_FLAG_OPTIONS: &[(u16, &str)] =
 
 pub const ICON_SYS_TITLE_CHAR_LIMIT: usize = 16;
 
+/// Size of the Shift-JIS title field in icon.sys; longer titles are truncated by the console.
+pub const ICON_SYS_TITLE_BYTE_LIMIT: usize = 68;
+
+/// Checks that a full (two-line) title fits in the icon.sys title field and returns its
+/// Shift-JIS byte length.
+pub fn check_icon_sys_title_length(title: &str) -> Result<usize, String> {
+    let length = shift_jis_byte_length(title)
+        .map_err(|_| "Icon.sys titles must contain characters representable in Shift-JIS")?;
+    if length > ICON_SYS_TITLE_BYTE_LIMIT {
+        return Err(format!(
+            "Icon.sys title is {length} bytes in Shift-JIS but cannot exceed {ICON_SYS_TITLE_BYTE_LIMIT} bytes"
+        ));
+    }
+    Ok(length)
+}
+
+/// Punctuation that word processors substitute for plain ASCII, mapped back to it. The
+/// quotes and ellipsis have full-width Shift-JIS forms, but those take two bytes of the title
+/// and render as wide glyphs; the dashes cannot be encoded at all.
+const TRANSLITERATIONS: &[(char, &str)] = &[
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{2026}', "..."),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "-"),
+];
+
+fn transliterate(ch: char) -> Option<&'static str> {
+    TRANSLITERATIONS
+        .iter()
+        .find(|(from, _)| *from == ch)
+        .map(|(_, to)| *to)
+}
+
+/// Keeps up to `limit` characters of `value` that survive a Shift-JIS round trip. Smart quotes,
+/// ellipses and dashes are first replaced with their ASCII equivalents; everything else,
+/// including Japanese text, is kept or dropped as is.
 pub fn sanitize_icon_sys_line(value: &str, limit: usize) -> String {
     let mut sanitized = String::new();
     let mut accepted_chars = 0usize;
-    for ch in value.chars() {
+    let chars = value.chars().flat_map(|ch| {
+        let replacement = transliterate(ch);
+        let original = replacement.is_none().then_some(ch);
+        replacement.into_iter().flat_map(str::chars).chain(original)
+    });
+    for ch in chars {
         if ch.is_control() {
             continue;
         }
@@ -682,6 +1058,15 @@ pub fn sanitize_icon_sys_line(value: &str, limit: usize) -> String {
 }
 
 pub fn split_icon_sys_title(title: &str, break_index: usize) -> (String, String) {
+    split_icon_sys_title_with_limit(title, break_index, ICON_SYS_TITLE_CHAR_LIMIT)
+}
+
+/// Like [`split_icon_sys_title`], but keeps up to `limit` characters per line.
+pub fn split_icon_sys_title_with_limit(
+    title: &str,
+    break_index: usize,
+    limit: usize,
+) -> (String, String) {
     const UNSUPPORTED_CHAR_PLACEHOLDER: char = '\u{FFFD}';
 
     let sanitized_chars: Vec<char> = title
@@ -717,14 +1102,14 @@ pub fn split_icon_sys_title(title: &str, break_index: usize) -> (String, String)
     }
 
     let break_index = break_in_chars.min(sanitized_chars.len());
-    let line1_count = break_index.min(ICON_SYS_TITLE_CHAR_LIMIT);
+    let line1_count = break_index.min(limit);
     let skip_count = line1_count;
 
     let line1: String = sanitized_chars.iter().take(line1_count).copied().collect();
     let line2: String = sanitized_chars
         .iter()
         .skip(skip_count)
-        .take(ICON_SYS_TITLE_CHAR_LIMIT)
+        .take(limit)
         .copied()
         .collect();
 
@@ -915,12 +1300,216 @@ mod tests {
     use super::*;
     use ps2_filetypes::{color::Color, ColorF, Vector};
 
+    #[test]
+    fn new_fills_every_field_with_defaults() {
+        let config = IconSysConfig::new(IconSysFlags::new(0), "SAVE DATA", 4);
+        assert_eq!(config.linebreak_pos, Some(4));
+        assert_eq!(config.preset, None);
+
+        let sparse = IconSysConfig {
+            background_transparency: None,
+            background_colors: None,
+            light_directions: None,
+            light_colors: None,
+            ambient_color: None,
+            ..config.clone()
+        };
+        assert_eq!(
+            config.resolved_with_fallback(None),
+            sparse.resolved_with_fallback(None)
+        );
+        assert_eq!(
+            config.to_bytes().expect("encode"),
+            sparse.to_bytes().expect("encode sparse")
+        );
+    }
+
+    #[test]
+    fn preset_file_export_then_import_keeps_colors() {
+        let mut source = IconSysConfig {
+            flags: IconSysFlags::new(0),
+            title: "Source".to_string(),
+            linebreak_pos: None,
+            preset: None,
+            background_transparency: Some(7),
+            background_colors: Some(vec![
+                ColorConfig {
+                    r: 1,
+                    g: 2,
+                    b: 3,
+                    a: 4,
+                };
+                4
+            ]),
+            light_directions: None,
+            light_colors: None,
+            ambient_color: Some(ColorFConfig {
+                r: 0.5,
+                g: 0.25,
+                b: 0.125,
+                a: 1.0,
+            }),
+        };
+        let json = source.to_preset("Mine").to_json().expect("serialize");
+
+        let preset = IconSysPresetFile::from_json(&json).expect("parse");
+        let mut target = IconSysConfig {
+            title: "Target".to_string(),
+            preset: Some("default".to_string()),
+            ..source.clone()
+        };
+        target.background_colors = None;
+        target.apply_preset_file(&preset);
+
+        assert_eq!(target.title, "Target");
+        assert_eq!(target.preset, None);
+        source.preset = None;
+        assert_eq!(
+            target.resolved_with_fallback(None),
+            source.resolved_with_fallback(None)
+        );
+    }
+
+    #[test]
+    fn preset_file_rejects_malformed_json() {
+        let err = IconSysPresetFile::from_json("{\"name\": \"x\"}").expect_err("missing fields");
+        assert!(matches!(err, crate::Error::ConfigError(message) if message.contains("preset")));
+    }
+
+    #[test]
+    fn normalize_scales_direction_to_unit_length() {
+        let mut direction = VectorConfig {
+            x: 3.0,
+            y: 0.0,
+            z: 4.0,
+            w: 0.5,
+        };
+        assert!(!direction.is_normalized());
+
+        direction.normalize();
+        assert!(direction.is_normalized());
+        assert!((direction.x - 0.6).abs() < 1e-6);
+        assert!((direction.z - 0.8).abs() < 1e-6);
+        assert_eq!(direction.w, 0.5);
+    }
+
+    #[test]
+    fn normalize_turns_zero_vector_into_default_direction() {
+        let mut direction = VectorConfig {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        };
+        assert!(!direction.is_normalized());
+
+        direction.normalize();
+        assert_eq!(
+            direction,
+            VectorConfig {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+                w: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn title_from_lines_breaks_single_line_titles_at_the_end() {
+        assert_eq!(
+            IconSysConfig::title_from_lines("Memory Card", "  "),
+            Ok(("Memory Card".to_string(), 11))
+        );
+        assert_eq!(
+            IconSysConfig::title_from_lines("SAVE", "DATA"),
+            Ok(("SAVEDATA".to_string(), 4))
+        );
+
+        let full_line = "あ".repeat(ICON_SYS_TITLE_CHAR_LIMIT);
+        let (title, linebreak) =
+            IconSysConfig::title_from_lines(&full_line, "").expect("full-width line fits");
+        assert_eq!(title, full_line);
+        assert_eq!(
+            usize::from(linebreak),
+            shift_jis_byte_length(&full_line).unwrap()
+        );
+    }
+
+    #[test]
+    fn set_title_from_lines_stores_title_and_linebreak() {
+        let mut config = IconSysConfig::new(IconSysFlags::new(0), "Old", 3);
+        let warnings = config.set_title_from_lines("セーブ", "DATA\r\n");
+        assert!(warnings.is_empty());
+        assert_eq!(config.title, "セーブDATA");
+        assert_eq!(config.linebreak_pos, Some(6));
+
+        let long_line = "A".repeat(ICON_SYS_TITLE_CHAR_LIMIT + 4);
+        let warnings = config.set_title_from_lines(&long_line, "DATA");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Line 1"));
+        assert_eq!(
+            config.title,
+            format!("{}DATA", "A".repeat(ICON_SYS_TITLE_CHAR_LIMIT))
+        );
+        assert_eq!(config.linebreak_pos, Some(ICON_SYS_TITLE_CHAR_LIMIT as u16));
+    }
+
+    #[test]
+    fn set_title_from_lines_with_limit_truncates_at_that_limit() {
+        let mut config = IconSysConfig::new(IconSysFlags::new(0), "", 0);
+        let line1 = "A".repeat(20);
+        let warnings = config.set_title_from_lines_with_limit(&line1, "DATA", 24);
+        assert!(warnings.is_empty());
+        assert_eq!(config.title, format!("{line1}DATA"));
+        assert_eq!(config.linebreak_pos, Some(20));
+
+        let warnings = config.set_title_from_lines_with_limit(&line1, "DATA", 8);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("truncated to 8"));
+        assert_eq!(config.title, "AAAAAAAADATA");
+        assert_eq!(config.linebreak_pos, Some(8));
+    }
+
+    #[test]
+    fn compute_linebreak_counts_shift_jis_bytes() {
+        assert_eq!(IconSysConfig::compute_linebreak("SAVE"), Ok(4));
+        assert_eq!(IconSysConfig::compute_linebreak("メモリー"), Ok(8));
+        assert_eq!(IconSysConfig::compute_linebreak(""), Ok(0));
+        assert!(IconSysConfig::compute_linebreak("😀").is_err());
+    }
+
+    #[test]
+    fn check_icon_sys_title_length_enforces_byte_limit() {
+        assert_eq!(check_icon_sys_title_length("SAVE DATA"), Ok(9));
+
+        let full = "あ".repeat(ICON_SYS_TITLE_BYTE_LIMIT / 2);
+        assert_eq!(
+            check_icon_sys_title_length(&full),
+            Ok(ICON_SYS_TITLE_BYTE_LIMIT)
+        );
+
+        let too_long = format!("{full}A");
+        let err = check_icon_sys_title_length(&too_long).expect_err("title over limit");
+        assert!(err.contains("69 bytes"), "unexpected message: {err}");
+    }
+
     #[test]
     fn sanitize_icon_sys_line_filters_control_chars_and_roundtrips() {
         let sanitized = sanitize_icon_sys_line("AB\u{0007}Cあいうえお", 8);
         assert_eq!(sanitized, "ABCあいうえお");
     }
 
+    #[test]
+    fn sanitize_icon_sys_line_transliterates_smart_punctuation() {
+        let sanitized =
+            sanitize_icon_sys_line("\u{201C}Ada\u{2019}s\u{201D} \u{2014} Part 2\u{2026}", 32);
+        assert_eq!(sanitized, "\"Ada's\" - Part 2...");
+
+        let japanese = "セーブ「データ」―テスト";
+        assert_eq!(sanitize_icon_sys_line(japanese, 32), japanese);
+    }
+
     #[test]
     fn split_icon_sys_title_handles_multibyte_breaks() {
         let title = "セーブデータこんにちは";
@@ -930,6 +1519,20 @@ mod tests {
         assert_eq!(line2, "こんにちは");
     }
 
+    #[test]
+    fn split_icon_sys_title_with_limit_keeps_longer_lines() {
+        let line1 = "A".repeat(20);
+        let title = format!("{line1}BB");
+        assert_eq!(
+            split_icon_sys_title(&title, 20),
+            ("A".repeat(16), "AAAABB".to_string())
+        );
+        assert_eq!(
+            split_icon_sys_title_with_limit(&title, 20, 24),
+            (line1, "BB".to_string())
+        );
+    }
+
     #[test]
     fn resolved_with_fallback_uses_defaults_without_icon_sys() {
         let config = IconSysConfig {
@@ -0,0 +1,147 @@
+use std::path::Path;
+
+use chrono::NaiveDateTime;
+use ps2_filetypes::{PSUEntry, PSUEntryKind, PSU};
+
+use crate::{plan_pack, Config, Error};
+
+const CSV_HEADER: &str = "name,kind,size,created,modified";
+const CSV_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// One entry of a PSU archive, or of the archive a project would produce.
+#[derive(Debug, Clone)]
+pub struct PsuEntryInfo {
+    pub name: String,
+    pub kind: PSUEntryKind,
+    pub size: u32,
+    pub created: NaiveDateTime,
+    pub modified: NaiveDateTime,
+}
+
+/// The entry list of a PSU archive, in archive order.
+#[derive(Debug, Clone, Default)]
+pub struct PsuInfo {
+    pub entries: Vec<PsuEntryInfo>,
+}
+
+impl PsuInfo {
+    /// Inspects an existing PSU archive.
+    pub fn from_psu_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            entries: list_psu_entries(bytes)?,
+        })
+    }
+
+    /// Describes the archive `pack_with_config` would write for `folder`.
+    pub fn from_project(folder: &Path, cfg: &Config) -> Result<Self, Error> {
+        let entries = plan_pack(folder, cfg)?
+            .into_iter()
+            .map(|entry| PsuEntryInfo {
+                name: entry.name,
+                kind: entry.kind,
+                size: entry.size,
+                created: entry.created,
+                modified: entry.modified,
+            })
+            .collect();
+        Ok(Self { entries })
+    }
+
+    /// Renders the entries as CSV with the columns name, kind, size, created, modified.
+    ///
+    /// Names are written as UTF-8 and quoted whenever they contain a delimiter, quote, or line
+    /// break, so Shift-JIS names decoded from an archive stay intact in spreadsheets.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push_str("\r\n");
+        for entry in &self.entries {
+            let kind = match entry.kind {
+                PSUEntryKind::Directory => "directory",
+                PSUEntryKind::File => "file",
+            };
+            csv.push_str(&format!(
+                "{},{kind},{},{},{}\r\n",
+                csv_field(&entry.name),
+                entry.size,
+                entry.created.format(CSV_TIMESTAMP_FORMAT),
+                entry.modified.format(CSV_TIMESTAMP_FORMAT),
+            ));
+        }
+        csv
+    }
+}
+
+/// Lists the entries of a PSU archive in archive order without reading file contents.
+pub fn list_psu_entries(psu_bytes: &[u8]) -> Result<Vec<PsuEntryInfo>, Error> {
+    Ok(PSU::read_entry_headers(psu_bytes)?
+        .into_iter()
+        .map(|entry| PsuEntryInfo {
+            name: entry.name,
+            kind: entry.kind,
+            size: entry.size,
+            created: entry.created,
+            modified: entry.modified,
+        })
+        .collect())
+}
+
+pub(crate) fn entry_infos(entries: &[PSUEntry]) -> Vec<PsuEntryInfo> {
+    entries
+        .iter()
+        .map(|entry| PsuEntryInfo {
+            name: entry.name.clone(),
+            kind: entry.kind,
+            size: entry.size,
+            created: entry.created,
+            modified: entry.modified,
+        })
+        .collect()
+}
+
+/// Re-reads the PSU at `path` and checks that it holds `expected`: the same number of entries,
+/// in the same order, with matching names and sizes. Any difference, including an archive that
+/// no longer parses, is reported as [`Error::ConfigError`].
+pub fn verify_psu_file(path: &Path, expected: &[PsuEntryInfo]) -> Result<(), Error> {
+    let failure = |detail: String| {
+        Error::ConfigError(format!(
+            "Verification of {} failed: {detail}",
+            path.display()
+        ))
+    };
+    let psu = PSU::try_new(std::fs::read(path)?).map_err(|err| {
+        failure(format!(
+            "the written archive could not be read back ({err})"
+        ))
+    })?;
+
+    if psu.entries.len() != expected.len() {
+        return Err(failure(format!(
+            "expected {} entries but found {}",
+            expected.len(),
+            psu.entries.len()
+        )));
+    }
+    for (written, expected) in psu.entries.iter().zip(expected) {
+        if written.name != expected.name {
+            return Err(failure(format!(
+                "expected entry {} but found {}",
+                expected.name, written.name
+            )));
+        }
+        if written.size != expected.size {
+            return Err(failure(format!(
+                "{} is {} bytes instead of {}",
+                expected.name, written.size, expected.size
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -1,36 +1,171 @@
 use chrono::{DateTime, Local, NaiveDateTime};
 use colored::Colorize;
+use ps2_filetypes::psu::format::entry_stride;
 use ps2_filetypes::{PSUEntry, PSUEntryKind, PSUWriter, DIR_ID, FILE_ID, PSU};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+mod batch;
+mod changes;
 pub mod icon_sys;
+mod info;
 pub mod sas;
+mod warnings;
 
+pub use batch::{
+    pack_all, pack_all_with_pattern, split_save_name_prefix, BatchPackResult, OutputNamePattern,
+    SAVE_NAME_PREFIXES,
+};
+pub use changes::{
+    pending_changes, pending_merged_changes, update_merged_psu_in_place, update_psu_in_place,
+    ChangeKind, PendingChange,
+};
 pub use icon_sys::{
-    color_config_to_rgba, color_f_config_to_rgba, color_f_to_rgba, color_to_normalized_rgba,
-    color_to_rgba, normalized_rgba_to_color, rgba_to_color, rgba_to_color_config, rgba_to_color_f,
-    rgba_to_color_f_config, sanitize_icon_sys_line, shift_jis_byte_length, split_icon_sys_title,
-    ColorConfig, ColorFConfig, IconSysConfig, IconSysFlags, IconSysPreset, VectorConfig,
-    ICON_SYS_FLAG_OPTIONS, ICON_SYS_PRESETS, ICON_SYS_TITLE_CHAR_LIMIT,
+    check_icon_sys_title_length, color_config_to_rgba, color_f_config_to_rgba, color_f_to_rgba,
+    color_to_normalized_rgba, color_to_rgba, normalized_rgba_to_color, recolor_icon_sys_projects,
+    rgba_to_color, rgba_to_color_config, rgba_to_color_f, rgba_to_color_f_config,
+    sanitize_icon_sys_line, shift_jis_byte_length, split_icon_sys_title,
+    split_icon_sys_title_with_limit, ColorConfig, ColorFConfig, IconSysConfig, IconSysFlags,
+    IconSysPreset, IconSysPresetFile, RecolorReport, VectorConfig, ICON_SYS_FLAG_OPTIONS,
+    ICON_SYS_PRESETS, ICON_SYS_TITLE_BYTE_LIMIT, ICON_SYS_TITLE_CHAR_LIMIT,
 };
+pub use info::{list_psu_entries, verify_psu_file, PsuEntryInfo, PsuInfo};
+pub use warnings::{include_exclude_overlap_warnings, missing_icon_file_warnings, ConfigWarning};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Config {
     pub name: String,
     pub timestamp: Option<NaiveDateTime>,
+    /// psu.toml deliberately sets no timestamp (`timestamp = false` or `"none"`), as opposed to
+    /// leaving the key out. Packing is the same either way; editors use it to keep the choice
+    /// instead of picking a timestamp themselves. Ignored while `timestamp` is set.
+    pub no_timestamp: bool,
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
     pub icon_sys: Option<IconSysConfig>,
+    /// Alternate icon.sys configurations keyed by label, written as `[icon_sys.<label>]`.
+    pub icon_sys_variants: BTreeMap<String, IconSysConfig>,
+    /// Label of the variant embedded as `icon.sys`; `None` embeds `icon_sys` itself.
+    pub icon_sys_variant: Option<String>,
+    /// Write the root directory size as the plain file count, without counting `.` and `..`.
+    /// See [`psu_root_dir_size`].
+    pub root_size_excludes_dot_entries: bool,
+    /// Per-file timestamps keyed by file name, written as `[timestamps."<name>"]`.
+    pub file_timestamps: BTreeMap<String, EntryTimestamps>,
+    /// Pack files in the order of `include` instead of sorting them by name. Files matched by
+    /// one wildcard pattern stay sorted by name. A generated icon.sys goes where `include`
+    /// lists it, or after every other file when it is not listed. Has no effect without an
+    /// `include` list.
+    pub preserve_include_order: bool,
+    /// Text file listing the files to pack, one name per line, used instead of `include`.
+    /// Relative paths are resolved against the project folder. Blank lines and lines starting
+    /// with `#` are ignored.
+    pub include_from_manifest: Option<PathBuf>,
+    /// Re-read the written PSU after packing and check that its entry count and file sizes
+    /// match what was packed. A mismatch, such as a short write on a full disk, is an error.
+    pub verify_after_pack: bool,
+    /// Name of the root directory entry inside the PSU, when it should differ from `name`.
+    /// `name` still names the output file.
+    pub root_dir_name: Option<String>,
+    /// Embed an icon.sys generated from `icon_sys`. When `false`, the icon.sys already in the
+    /// folder is packed as-is and `icon_sys` is only validated.
+    pub generate_icon_sys: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            timestamp: None,
+            no_timestamp: false,
+            include: None,
+            exclude: None,
+            icon_sys: None,
+            icon_sys_variants: BTreeMap::new(),
+            icon_sys_variant: None,
+            root_size_excludes_dot_entries: false,
+            file_timestamps: BTreeMap::new(),
+            preserve_include_order: false,
+            include_from_manifest: None,
+            verify_after_pack: false,
+            root_dir_name: None,
+            generate_icon_sys: true,
+        }
+    }
+}
+
+/// Timestamps forced onto a single packed file. A field left unset falls back to
+/// [`Config::timestamp`], or to the file's own metadata when no timestamp is configured.
+///
+/// In psu.toml an entry is either a `[timestamps."<name>"]` table with `created` and/or
+/// `modified`, or a plain `"<name>" = "<time>"` under `[timestamps]` that sets both.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct EntryTimestamps {
+    #[serde(default, with = "date_format", skip_serializing_if = "Option::is_none")]
+    pub created: Option<NaiveDateTime>,
+    #[serde(default, with = "date_format", skip_serializing_if = "Option::is_none")]
+    pub modified: Option<NaiveDateTime>,
+}
+
+#[derive(Deserialize)]
+struct EntryTimestampFields {
+    #[serde(default, with = "date_format")]
+    created: Option<NaiveDateTime>,
+    #[serde(default, with = "date_format")]
+    modified: Option<NaiveDateTime>,
+}
+
+impl<'de> Deserialize<'de> for EntryTimestamps {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct EntryTimestampsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for EntryTimestampsVisitor {
+            type Value = EntryTimestamps;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a timestamp or a table with `created` and `modified`")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let timestamp =
+                    NaiveDateTime::parse_from_str(value, date_format::FORMAT).map_err(E::custom)?;
+                Ok(EntryTimestamps {
+                    created: Some(timestamp),
+                    modified: Some(timestamp),
+                })
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let fields = EntryTimestampFields::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(EntryTimestamps {
+                    created: fields.created,
+                    modified: fields.modified,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(EntryTimestampsVisitor)
+    }
 }
 
 mod date_format {
     use chrono::NaiveDateTime;
     use serde::{self, Deserialize, Deserializer, Serializer};
 
-    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+    pub(super) const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 
     pub fn serialize<S>(value: &Option<NaiveDateTime>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -57,34 +192,214 @@ mod date_format {
     }
 }
 
+/// The `timestamp` key of `[config]`: a date, or `false`/`"none"` for an explicit "no timestamp".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimestampSetting {
+    Disabled,
+    At(NaiveDateTime),
+}
+
+mod timestamp_setting {
+    use chrono::NaiveDateTime;
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    use super::{date_format::FORMAT, TimestampSetting};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawSetting {
+        Flag(bool),
+        Text(String),
+    }
+
+    pub fn serialize<S>(value: &Option<TimestampSetting>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(TimestampSetting::Disabled) => serializer.serialize_bool(false),
+            Some(TimestampSetting::At(value)) => {
+                serializer.serialize_some(&value.format(FORMAT).to_string())
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<TimestampSetting>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<RawSetting>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(RawSetting::Flag(false)) => Ok(Some(TimestampSetting::Disabled)),
+            Some(RawSetting::Flag(true)) => Err(serde::de::Error::custom(
+                "timestamp must be a date or false",
+            )),
+            Some(RawSetting::Text(text)) if text.trim().eq_ignore_ascii_case("none") => {
+                Ok(Some(TimestampSetting::Disabled))
+            }
+            Some(RawSetting::Text(text)) => NaiveDateTime::parse_from_str(&text, FORMAT)
+                .map(|timestamp| Some(TimestampSetting::At(timestamp)))
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 struct ConfigFile {
     config: ConfigSection,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    icon_sys: Option<IconSysConfig>,
+    icon_sys: Option<IconSysSection>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    timestamps: BTreeMap<String, EntryTimestamps>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 struct ConfigSection {
     name: String,
-    #[serde(default, with = "date_format", skip_serializing_if = "Option::is_none")]
-    timestamp: Option<NaiveDateTime>,
+    #[serde(
+        default,
+        with = "timestamp_setting",
+        skip_serializing_if = "Option::is_none"
+    )]
+    timestamp: Option<TimestampSetting>,
     #[serde(skip_serializing_if = "Option::is_none")]
     include: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     exclude: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    root_size_excludes_dot_entries: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    preserve_include_order: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    include_from_manifest: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    verify_after_pack: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    root_dir_name: Option<String>,
+    #[serde(default = "default_generate_icon_sys", skip_serializing_if = "is_true")]
+    generate_icon_sys: bool,
 }
 
-impl From<ConfigFile> for Config {
-    fn from(file: ConfigFile) -> Self {
-        let ConfigFile { config, icon_sys } = file;
-        Self {
+fn default_generate_icon_sys() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+/// The `[icon_sys]` table: the base icon.sys fields plus any `[icon_sys.<label>]` variants.
+#[derive(Debug, Deserialize, Serialize)]
+struct IconSysSection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_variant: Option<String>,
+    #[serde(flatten)]
+    entries: toml::Table,
+}
+
+impl IconSysSection {
+    fn from_config(config: &Config) -> Result<Option<Self>, toml::ser::Error> {
+        if config.icon_sys.is_none()
+            && config.icon_sys_variants.is_empty()
+            && config.icon_sys_variant.is_none()
+        {
+            return Ok(None);
+        }
+
+        let mut entries = match &config.icon_sys {
+            Some(icon_sys) => toml::Table::try_from(icon_sys)?,
+            None => toml::Table::new(),
+        };
+        for (label, variant) in &config.icon_sys_variants {
+            entries.insert(
+                label.clone(),
+                toml::Value::Table(toml::Table::try_from(variant)?),
+            );
+        }
+
+        Ok(Some(Self {
+            default_variant: config.icon_sys_variant.clone(),
+            entries,
+        }))
+    }
+
+    /// Splits the table into the base config and its labelled variants. Any table-valued key
+    /// that is not an icon.sys field is treated as a variant.
+    fn into_parts(self) -> Result<(Option<IconSysConfig>, BTreeMap<String, IconSysConfig>), Error> {
+        let parse = |context: &str, table: toml::Table| {
+            IconSysConfig::deserialize(toml::Value::Table(table))
+                .map_err(|e| Error::ConfigError(format!("{context}: {e}")))
+        };
+
+        let mut base = toml::Table::new();
+        let mut variants = BTreeMap::new();
+        for (key, value) in self.entries {
+            match value {
+                toml::Value::Table(table) if !warnings::ICON_SYS_KEYS.contains(&key.as_str()) => {
+                    let variant = parse(&format!("icon_sys.{key}"), table)?;
+                    variants.insert(key, variant);
+                }
+                value => {
+                    base.insert(key, value);
+                }
+            }
+        }
+
+        let base = if base.is_empty() {
+            None
+        } else {
+            Some(parse("icon_sys", base)?)
+        };
+        Ok((base, variants))
+    }
+}
+
+impl ConfigFile {
+    fn into_config(self) -> Result<Config, Error> {
+        let ConfigFile {
+            config,
+            icon_sys,
+            timestamps,
+        } = self;
+        let (icon_sys, icon_sys_variants, icon_sys_variant) = match icon_sys {
+            Some(section) => {
+                let default_variant = section.default_variant.clone();
+                let (base, variants) = section.into_parts()?;
+                (base, variants, default_variant)
+            }
+            None => (None, BTreeMap::new(), None),
+        };
+
+        if let Some(root_dir_name) = &config.root_dir_name {
+            if !check_name(root_dir_name) {
+                return Err(Error::ConfigError(format!(
+                    "root_dir_name `{root_dir_name}` is not a valid PSU name; {}",
+                    Error::NameError
+                )));
+            }
+        }
+
+        Ok(Config {
             name: config.name,
-            timestamp: config.timestamp,
+            timestamp: match config.timestamp {
+                Some(TimestampSetting::At(timestamp)) => Some(timestamp),
+                _ => None,
+            },
+            no_timestamp: config.timestamp == Some(TimestampSetting::Disabled),
             include: config.include,
             exclude: config.exclude,
             icon_sys,
-        }
+            icon_sys_variants,
+            icon_sys_variant,
+            root_size_excludes_dot_entries: config.root_size_excludes_dot_entries,
+            file_timestamps: timestamps,
+            preserve_include_order: config.preserve_include_order,
+            include_from_manifest: config.include_from_manifest,
+            verify_after_pack: config.verify_after_pack,
+            root_dir_name: config.root_dir_name,
+            generate_icon_sys: config.generate_icon_sys,
+        })
     }
 }
 
@@ -92,18 +407,136 @@ impl Config {
     pub fn to_toml_string(&self) -> Result<String, toml::ser::Error> {
         let config_section = ConfigSection {
             name: self.name.clone(),
-            timestamp: self.timestamp,
+            timestamp: match self.timestamp {
+                Some(timestamp) => Some(TimestampSetting::At(timestamp)),
+                None if self.no_timestamp => Some(TimestampSetting::Disabled),
+                None => None,
+            },
             include: self.include.clone(),
             exclude: self.exclude.clone(),
+            root_size_excludes_dot_entries: self.root_size_excludes_dot_entries,
+            preserve_include_order: self.preserve_include_order,
+            include_from_manifest: self.include_from_manifest.clone(),
+            verify_after_pack: self.verify_after_pack,
+            root_dir_name: self.root_dir_name.clone(),
+            generate_icon_sys: self.generate_icon_sys,
         };
 
         let config_file = ConfigFile {
             config: config_section,
-            icon_sys: self.icon_sys.clone(),
+            icon_sys: IconSysSection::from_config(self)?,
+            timestamps: self.file_timestamps.clone(),
         };
 
         toml::to_string_pretty(&config_file)
     }
+
+    /// Name written to the PSU's root directory entry: `root_dir_name` when set, otherwise
+    /// `name`.
+    pub fn root_entry_name(&self) -> &str {
+        self.root_dir_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Returns [`Error::NameError`] unless `name` and the root entry name are valid PSU names.
+    /// Every packing and updating path calls this, so neither name can introduce `/`, `\`,
+    /// `.` or `..` into an archive.
+    pub fn validate_names(&self) -> Result<(), Error> {
+        if check_name(&self.name) && check_name(self.root_entry_name()) {
+            Ok(())
+        } else {
+            Err(Error::NameError)
+        }
+    }
+
+    /// The include list used when packing `folder`: the names read from
+    /// `include_from_manifest` when one is set, otherwise `include`.
+    pub fn resolved_include(&self, folder: &Path) -> Result<Option<Vec<String>>, Error> {
+        let Some(manifest) = &self.include_from_manifest else {
+            return Ok(self.include.clone());
+        };
+        let path = folder.join(manifest);
+        let contents =
+            std::fs::read_to_string(&path).map_err(|source| Error::file(&path, source))?;
+        Ok(Some(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string)
+                .collect(),
+        ))
+    }
+
+    /// The icon.sys configuration to embed when packing, honouring `icon_sys_variant`.
+    pub fn selected_icon_sys(&self) -> Result<Option<&IconSysConfig>, Error> {
+        match &self.icon_sys_variant {
+            Some(label) => {
+                self.icon_sys_variants.get(label).map(Some).ok_or_else(|| {
+                    Error::ConfigError(format!("Unknown icon_sys variant `{label}`"))
+                })
+            }
+            None => Ok(self.icon_sys.as_ref()),
+        }
+    }
+
+    /// The icon.sys configuration packing generates an icon.sys from: the selected one, or
+    /// `None` when `generate_icon_sys` is off.
+    pub fn generated_icon_sys(&self) -> Result<Option<&IconSysConfig>, Error> {
+        let selected = self.selected_icon_sys()?;
+        Ok(selected.filter(|_| self.generate_icon_sys))
+    }
+
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+}
+
+/// Builds a [`Config`] in code, as an alternative to loading a psu.toml.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.config.name = name.into();
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: NaiveDateTime) -> Self {
+        self.config.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn include<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.include = Some(patterns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn exclude<I, S>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.config.exclude = Some(patterns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn icon_sys(mut self, icon_sys: IconSysConfig) -> Self {
+        self.config.icon_sys = Some(icon_sys);
+        self
+    }
+
+    /// Returns the config, or [`Error::NameError`] if the name or root directory name is not a
+    /// valid PSU name.
+    pub fn build(self) -> Result<Config, Error> {
+        self.config.validate_names()?;
+        Ok(self.config)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -128,12 +561,96 @@ impl MetadataReader for FsMetadataReader {
     }
 }
 
+/// Reports the same times for every path, so packing an unchanged folder twice gives
+/// byte-identical archives. Pass it to [`pack_with_config_and_metadata_reader`] for
+/// reproducible builds. It only matters for files whose times are not already forced by
+/// [`Config::timestamp`] or a `[timestamps]` entry.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedTimeMetadataReader {
+    times: FileTimes,
+}
+
+impl FixedTimeMetadataReader {
+    pub fn new(times: FileTimes) -> Self {
+        Self { times }
+    }
+
+    /// Uses `time` as both the created and modified time.
+    pub fn at(time: SystemTime) -> Self {
+        Self::new(FileTimes {
+            created: Some(time),
+            modified: time,
+        })
+    }
+}
+
+impl MetadataReader for FixedTimeMetadataReader {
+    fn file_times(&self, _path: &Path) -> std::io::Result<FileTimes> {
+        Ok(self.times)
+    }
+}
+
 pub fn load_config(folder: &Path) -> Result<Config, Error> {
+    load_config_with_warnings(folder).map(|(config, _)| config)
+}
+
+/// Loads `psu.toml` and reports non-fatal issues alongside the parsed configuration.
+pub fn load_config_with_warnings(folder: &Path) -> Result<(Config, Vec<ConfigWarning>), Error> {
     let config_file = folder.join("psu.toml");
-    let str = std::fs::read_to_string(&config_file)?;
+    let contents = std::fs::read_to_string(&config_file)?;
+    let str = strip_bom(&contents);
+    let raw = toml::from_str::<toml::Value>(str).map_err(|e| config_parse_error(str, e))?;
     let config_file =
-        toml::from_str::<ConfigFile>(&str).map_err(|e| Error::ConfigError(e.to_string()))?;
-    Ok(config_file.into())
+        toml::from_str::<ConfigFile>(str).map_err(|e| Error::ConfigError(e.to_string()))?;
+    let config = config_file.into_config()?;
+    let mut warnings = warnings::collect_config_warnings(&raw, &config);
+    // Only scans the folder when psu.toml has `[timestamps]` entries.
+    warnings.extend(warnings::unpacked_timestamp_warnings(folder, &config));
+    Ok((config, warnings))
+}
+
+/// Drops the UTF-8 byte order mark some editors put at the start of text files.
+fn strip_bom(contents: &str) -> &str {
+    contents.strip_prefix('\u{feff}').unwrap_or(contents)
+}
+
+fn config_parse_error(contents: &str, err: toml::de::Error) -> Error {
+    match contents.lines().position(|line| line.contains('\u{feff}')) {
+        Some(index) => Error::ConfigError(format!(
+            "psu.toml contains a byte order mark on line {}; save it as UTF-8 without BOM ({err})",
+            index + 1
+        )),
+        None => Error::ConfigError(err.to_string()),
+    }
+}
+
+/// Writes `config` to `folder/psu.toml` and returns the path written.
+///
+/// Keys that psu-packer does not understand are carried over from an existing psu.toml so
+/// hand-written additions survive.
+pub fn save_config(folder: &Path, config: &Config) -> Result<PathBuf, Error> {
+    let serialized = config
+        .to_toml_string()
+        .map_err(|e| Error::ConfigError(e.to_string()))?;
+    let path = folder.join("psu.toml");
+
+    let existing = match std::fs::read_to_string(&path) {
+        Ok(contents) => toml::from_str::<toml::Table>(strip_bom(&contents)).ok(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+    let contents = match existing {
+        Some(existing) if warnings::has_unknown_keys(&existing) => {
+            let mut merged = toml::from_str::<toml::Table>(&serialized)
+                .map_err(|e| Error::ConfigError(e.to_string()))?;
+            warnings::carry_over_unknown_keys(&existing, &mut merged);
+            toml::to_string_pretty(&merged).map_err(|e| Error::ConfigError(e.to_string()))?
+        }
+        _ => serialized,
+    };
+
+    std::fs::write(&path, contents)?;
+    Ok(path)
 }
 
 pub fn pack_psu(folder: &Path, output: &Path) -> Result<(), Error> {
@@ -141,9 +658,49 @@ pub fn pack_psu(folder: &Path, output: &Path) -> Result<(), Error> {
     pack_with_config(folder, output, config)
 }
 
+/// Reported once for every file written into the archive.
+#[derive(Debug, Clone)]
+pub struct PackProgressEvent {
+    /// Zero-based position of the file that was just added.
+    pub index: usize,
+    /// Number of files that will be added in total.
+    pub total: usize,
+    pub name: String,
+}
+
+impl PackProgressEvent {
+    /// Fraction of files packed so far, in the range `0.0..=1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            (self.index + 1) as f32 / self.total as f32
+        }
+    }
+}
+
 pub fn pack_with_config(folder: &Path, output: &Path, cfg: Config) -> Result<(), Error> {
-    let metadata_reader = FsMetadataReader::default();
-    pack_with_config_and_metadata_reader(folder, output, cfg, &metadata_reader)
+    pack_with_config_with_progress(folder, output, cfg, &mut print_added_file)
+}
+
+/// Progress callback used by [`pack_with_config`], which lists each file as it is added.
+fn print_added_file(event: PackProgressEvent) {
+    println!("+ Adding {}", event.name.green());
+}
+
+pub fn pack_with_config_with_progress(
+    folder: &Path,
+    output: &Path,
+    cfg: Config,
+    progress: &mut dyn FnMut(PackProgressEvent),
+) -> Result<(), Error> {
+    pack_with_config_and_metadata_reader_with_progress(
+        folder,
+        output,
+        cfg,
+        &FsMetadataReader,
+        progress,
+    )
 }
 
 pub fn pack_with_config_and_metadata_reader<M: MetadataReader>(
@@ -152,133 +709,628 @@ pub fn pack_with_config_and_metadata_reader<M: MetadataReader>(
     cfg: Config,
     metadata_reader: &M,
 ) -> Result<(), Error> {
+    pack_with_config_and_metadata_reader_with_progress(
+        folder,
+        output,
+        cfg,
+        metadata_reader,
+        &mut |_| {},
+    )
+}
+
+fn pack_with_config_and_metadata_reader_with_progress<M: MetadataReader>(
+    folder: &Path,
+    output: &Path,
+    cfg: Config,
+    metadata_reader: &M,
+    progress: &mut dyn FnMut(PackProgressEvent),
+) -> Result<(), Error> {
+    pack_folders(
+        folder,
+        &[],
+        MergeConflictPolicy::default(),
+        output,
+        cfg,
+        metadata_reader,
+        progress,
+    )
+}
+
+/// How [`pack_merged`] treats a file name that appears in more than one folder.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MergeConflictPolicy {
+    /// Keep the file from the primary folder, or from the earliest secondary folder.
+    #[default]
+    PrimaryWins,
+    /// Refuse to pack when two folders provide the same file name.
+    Error,
+}
+
+/// Packs the files of `primary` together with every file of `secondaries` into one PSU.
+///
+/// The archive takes its name, timestamp, and icon.sys from `cfg`. `include` applies to the
+/// primary folder only, while `exclude` applies to every folder.
+pub fn pack_merged(
+    primary: &Path,
+    secondaries: &[PathBuf],
+    output: &Path,
+    cfg: Config,
+    policy: MergeConflictPolicy,
+) -> Result<(), Error> {
+    pack_merged_with_progress(primary, secondaries, output, cfg, policy, &mut |_| {})
+}
+
+pub fn pack_merged_with_progress(
+    primary: &Path,
+    secondaries: &[PathBuf],
+    output: &Path,
+    cfg: Config,
+    policy: MergeConflictPolicy,
+    progress: &mut dyn FnMut(PackProgressEvent),
+) -> Result<(), Error> {
+    pack_folders(
+        primary,
+        secondaries,
+        policy,
+        output,
+        cfg,
+        &FsMetadataReader,
+        progress,
+    )
+}
+
+fn pack_folders<M: MetadataReader>(
+    folder: &Path,
+    secondaries: &[PathBuf],
+    policy: MergeConflictPolicy,
+    output: &Path,
+    cfg: Config,
+    metadata_reader: &M,
+    progress: &mut dyn FnMut(PackProgressEvent),
+) -> Result<(), Error> {
+    cfg.validate_names()?;
+    let icon_sys = cfg.selected_icon_sys()?.cloned();
+    let include = cfg.resolved_include(folder)?;
+    let root_name = cfg.root_entry_name().to_owned();
     let Config {
-        name,
         timestamp,
-        include,
         exclude,
-        icon_sys,
+        root_size_excludes_dot_entries,
+        file_timestamps,
+        preserve_include_order,
+        verify_after_pack,
+        generate_icon_sys,
+        ..
     } = cfg;
 
-    if !check_name(&name) {
-        return Err(Error::NameError);
+    let mut psu = PSU::default();
+
+    // A generated icon.sys is embedded directly, leaving any icon.sys in the folder untouched.
+    // The config is encoded even when generation is off so that it is still validated.
+    let icon_sys_path = folder.join("icon.sys");
+    let generated_icon_sys = icon_sys
+        .as_ref()
+        .map(|icon_config| icon_config.to_bytes())
+        .transpose()?
+        .filter(|_| generate_icon_sys);
+    let pending_icon_sys = (generated_icon_sys.is_some() && !icon_sys_path.exists())
+        .then_some(icon_sys_path.as_path());
+
+    let mut files = select_files(
+        folder,
+        include,
+        exclude.clone(),
+        generated_icon_sys.as_ref().map(|_| icon_sys_path.as_path()),
+        pending_icon_sys,
+        preserve_include_order,
+    )?;
+    for secondary in secondaries {
+        let secondary_files = select_files(secondary, None, exclude.clone(), None, None, false)?;
+        merge_files(&mut files, secondary_files, policy, !preserve_include_order)?;
     }
+    check_duplicate_names(&files)?;
+    check_reserved_names(&files, &root_name)?;
 
-    let mut psu = PSU::default();
+    let timestamp_value = timestamp.unwrap_or_default();
+    let root_size = psu_root_dir_size(files.len(), root_size_excludes_dot_entries);
+    add_psu_defaults(&mut psu, &root_name, root_size, timestamp_value);
+    add_files_to_psu(
+        &mut psu,
+        &files,
+        generated_icon_sys
+            .as_deref()
+            .map(|bytes| (icon_sys_path.as_path(), bytes)),
+        timestamp,
+        &file_timestamps,
+        metadata_reader,
+        progress,
+    )?;
+    let expected = verify_after_pack.then(|| info::entry_infos(&psu.entries));
+    std::fs::write(output, PSUWriter::new(psu).to_bytes()?)?;
+    if let Some(expected) = expected {
+        verify_psu_file(output, &expected)?;
+    }
+    Ok(())
+}
+
+/// A single entry `pack_with_config` would write, as reported by [`plan_pack`].
+#[derive(Debug, Clone)]
+pub struct PlannedEntry {
+    pub name: String,
+    pub size: u32,
+    pub kind: PSUEntryKind,
+    pub created: NaiveDateTime,
+    pub modified: NaiveDateTime,
+    /// The file the entry is read from, which may sit in a merged folder. A generated icon.sys
+    /// points at the project's icon.sys even when that file does not exist. `None` for
+    /// directory entries.
+    pub source: Option<PathBuf>,
+}
+
+/// Reports the entries `pack_with_config` would write, in archive order, without touching
+/// the folder or writing any output.
+pub fn plan_pack(folder: &Path, cfg: &Config) -> Result<Vec<PlannedEntry>, Error> {
+    plan_merged_pack(folder, &[], cfg, MergeConflictPolicy::default())
+}
+
+/// Reports the entries [`pack_merged`] would write, in archive order, without touching any
+/// folder or writing any output.
+pub fn plan_merged_pack(
+    folder: &Path,
+    secondaries: &[PathBuf],
+    cfg: &Config,
+    policy: MergeConflictPolicy,
+) -> Result<Vec<PlannedEntry>, Error> {
+    cfg.validate_names()?;
 
     let icon_sys_path = folder.join("icon.sys");
-    if let Some(icon_config) = &icon_sys {
-        let bytes = icon_config.to_bytes()?;
-        std::fs::write(&icon_sys_path, bytes)?;
+    let icon_sys_bytes = cfg
+        .generated_icon_sys()?
+        .map(|icon_config| icon_config.to_bytes())
+        .transpose()?;
+    let pending_icon_sys =
+        (icon_sys_bytes.is_some() && !icon_sys_path.exists()).then_some(icon_sys_path.as_path());
+
+    let mut files = select_files(
+        folder,
+        cfg.resolved_include(folder)?,
+        cfg.exclude.clone(),
+        icon_sys_bytes.as_ref().map(|_| icon_sys_path.as_path()),
+        pending_icon_sys,
+        cfg.preserve_include_order,
+    )?;
+    for secondary in secondaries {
+        let secondary_files =
+            select_files(secondary, None, cfg.exclude.clone(), None, None, false)?;
+        merge_files(
+            &mut files,
+            secondary_files,
+            policy,
+            !cfg.preserve_include_order,
+        )?;
+    }
+    check_duplicate_names(&files)?;
+    check_reserved_names(&files, cfg.root_entry_name())?;
+
+    let mut psu = PSU::default();
+    add_psu_defaults(
+        &mut psu,
+        cfg.root_entry_name(),
+        psu_root_dir_size(files.len(), cfg.root_size_excludes_dot_entries),
+        cfg.timestamp.unwrap_or_default(),
+    );
+    let mut planned = psu
+        .entries
+        .into_iter()
+        .map(|entry| PlannedEntry {
+            name: entry.name,
+            size: entry.size,
+            kind: entry.kind,
+            created: entry.created,
+            modified: entry.modified,
+            source: None,
+        })
+        .collect::<Vec<_>>();
+
+    let metadata_reader = FsMetadataReader;
+    for file in &files {
+        let name = file.file_name().unwrap().to_str().unwrap().to_owned();
+        let is_icon_sys = icon_sys_bytes.is_some() && *file == icon_sys_path;
+
+        let size = match &icon_sys_bytes {
+            Some(bytes) if is_icon_sys => bytes.len() as u32,
+            _ => std::fs::metadata(file)?.len() as u32,
+        };
+        let overrides = cfg.file_timestamps.get(&name);
+        let (created, modified) = if Some(file.as_path()) == pending_icon_sys {
+            let reader = GeneratedFileMetadataReader(&metadata_reader);
+            resolve_file_times(file, cfg.timestamp, overrides, &reader)?
+        } else {
+            resolve_file_times(file, cfg.timestamp, overrides, &metadata_reader)?
+        };
+
+        planned.push(PlannedEntry {
+            name,
+            size,
+            kind: PSUEntryKind::File,
+            created,
+            modified,
+            source: Some(file.clone()),
+        });
+    }
+
+    Ok(planned)
+}
+
+/// Returns the newest modification time among the files `pack_with_config` would take from
+/// `folder` with the given include/exclude lists, or `None` when no file would be packed.
+pub fn newest_file_timestamp<M: MetadataReader>(
+    folder: &Path,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    metadata_reader: &M,
+) -> Result<Option<NaiveDateTime>, Error> {
+    let files = select_files(folder, include, exclude, None, None, false)?;
+    let mut newest = None;
+    for file in &files {
+        let modified = convert_timestamp(metadata_reader.file_times(file)?.modified);
+        newest = newest.max(Some(modified));
+    }
+    Ok(newest)
+}
+
+/// Estimates the size in bytes of the PSU `pack_with_config` would write for `folder`.
+///
+/// Every entry contributes its 512-byte header, and file contents are rounded up to the
+/// 1024-byte data alignment, so the result matches the packed archive length.
+pub fn estimate_packed_size(folder: &Path, cfg: &Config) -> Result<u64, Error> {
+    let planned = plan_pack(folder, cfg)?;
+    Ok(planned
+        .iter()
+        .map(|entry| match entry.kind {
+            PSUEntryKind::Directory => entry_stride(None),
+            PSUEntryKind::File => entry_stride(Some(entry.size)),
+        })
+        .sum())
+}
+
+/// Extracts a PSU archive into a new folder beneath `destination_parent`.
+///
+/// Returns the created root directory. `psu.toml` entries are never written back out.
+pub fn unpack_psu(psu_bytes: &[u8], destination_parent: &Path) -> Result<PathBuf, Error> {
+    let psu = PSU::try_new(psu_bytes.to_vec())?;
+    let invalid = |message: String| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+
+    let Some((root, children)) = psu.entries.split_first() else {
+        return Err(invalid("PSU archive is empty".to_string()).into());
+    };
+    if !matches!(root.kind, PSUEntryKind::Directory) || root.name.trim().is_empty() {
+        return Err(invalid("PSU does not start with a root directory entry".to_string()).into());
+    }
+    if !check_name(&root.name) {
+        return Err(Error::NameError);
+    }
+    if children.len() != root.size as usize {
+        return Err(invalid(format!(
+            "PSU root directory lists {} entries but the archive contains {}",
+            root.size,
+            children.len()
+        ))
+        .into());
+    }
+
+    let export_root = destination_parent.join(&root.name);
+    std::fs::create_dir_all(&export_root)?;
+
+    for entry in children {
+        if entry.name == "." || entry.name == ".." {
+            continue;
+        }
+        if entry.name.contains(['\\', '/']) {
+            return Err(
+                invalid(format!("PSU entry {} is not a plain file name", entry.name)).into(),
+            );
+        }
+
+        match entry.kind {
+            PSUEntryKind::Directory => std::fs::create_dir_all(export_root.join(&entry.name))?,
+            PSUEntryKind::File => {
+                if entry.name.eq_ignore_ascii_case("psu.toml") {
+                    continue;
+                }
+                let contents = entry.contents.as_deref().ok_or_else(|| {
+                    invalid(format!(
+                        "{} is missing file data in the PSU archive",
+                        entry.name
+                    ))
+                })?;
+                std::fs::write(export_root.join(&entry.name), contents)?;
+            }
+        }
+    }
+
+    Ok(export_root)
+}
+
+/// Files [`extract_metadata_only`] copies out of an archive.
+const METADATA_FILES: [&str; 2] = ["title.cfg", "icon.sys"];
+
+/// Writes only `title.cfg` and `icon.sys` from a PSU archive into `destination`, creating it
+/// if needed. Either file is skipped when the archive does not contain it.
+pub fn extract_metadata_only(psu_bytes: &[u8], destination: &Path) -> Result<(), Error> {
+    let psu = PSU::try_new(psu_bytes.to_vec())?;
+    std::fs::create_dir_all(destination)?;
+
+    for name in METADATA_FILES {
+        let Some(entry) = psu.entries.iter().find(|entry| {
+            matches!(entry.kind, PSUEntryKind::File) && entry.name.eq_ignore_ascii_case(name)
+        }) else {
+            continue;
+        };
+        let contents = entry.contents.as_deref().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{} is missing file data in the PSU archive", entry.name),
+            )
+        })?;
+        std::fs::write(destination.join(name), contents)?;
+    }
+
+    Ok(())
+}
+
+/// Rebuilds the configuration an existing PSU archive was most likely packed with.
+///
+/// The name and timestamp come from the root directory entry and the include list names every
+/// file except `psu.toml` and `icon.sys`. An embedded icon.sys is parsed into
+/// [`Config::icon_sys`]. Settings that leave no trace in the archive keep their defaults.
+pub fn config_from_psu(psu_bytes: &[u8]) -> Result<Config, Error> {
+    let psu = PSU::try_new(psu_bytes.to_vec())?;
+    let root = psu
+        .entries
+        .first()
+        .filter(|entry| {
+            matches!(entry.kind, PSUEntryKind::Directory) && entry.name != "." && entry.name != ".."
+        })
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "PSU does not start with a root directory entry",
+            )
+        })?;
+
+    let mut include = Vec::new();
+    let mut icon_sys = None;
+    for entry in &psu.entries {
+        if !matches!(entry.kind, PSUEntryKind::File) {
+            continue;
+        }
+        if entry.name.eq_ignore_ascii_case("icon.sys") {
+            if let Some(contents) = &entry.contents {
+                let parsed = ps2_filetypes::IconSys::try_new(contents.clone()).map_err(|err| {
+                    Error::ConfigError(format!("Failed to parse the embedded icon.sys: {err}"))
+                })?;
+                icon_sys = Some(IconSysConfig::from(&parsed));
+            }
+        } else if !entry.name.eq_ignore_ascii_case("psu.toml") {
+            include.push(entry.name.clone());
+        }
+    }
+
+    Ok(Config {
+        name: root.name.clone(),
+        timestamp: Some(root.modified),
+        include: (!include.is_empty()).then_some(include),
+        icon_sys,
+        ..Default::default()
+    })
+}
+
+/// Renames the root directory of an existing PSU archive without repacking its files.
+pub fn rename_root(psu_bytes: &[u8], new_name: &str) -> Result<Vec<u8>, Error> {
+    if !check_name(new_name) {
+        return Err(Error::NameError);
+    }
+
+    let mut psu = PSU::try_new(psu_bytes.to_vec())?;
+    let root = psu
+        .entries
+        .first_mut()
+        .filter(|entry| {
+            matches!(entry.kind, PSUEntryKind::Directory) && entry.name != "." && entry.name != ".."
+        })
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "PSU does not start with a root directory entry",
+            )
+        })?;
+    root.name = new_name.to_owned();
+
+    Ok(PSUWriter::new(psu).to_bytes()?)
+}
+
+/// Accepts names made only of `[a-zA-Z0-9._-\s]`, except the `.` and `..` directory markers.
+fn check_name(name: &str) -> bool {
+    if name == "." || name == ".." {
+        return false;
+    }
+    for c in name.chars() {
+        if !matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' | '.' | ' ') {
+            return false;
+        }
+    }
+    true
+}
+
+/// Resolves the files that end up in the archive, in packing order.
+///
+/// `pending_file` names a file that does not exist yet but will be written before packing
+/// (the generated `icon.sys` during planning); it is treated as present in the folder.
+fn select_files(
+    folder: &Path,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    icon_sys_path: Option<&Path>,
+    pending_file: Option<&Path>,
+    preserve_include_order: bool,
+) -> Result<Vec<PathBuf>, Error> {
+    let keep_include_order = preserve_include_order && include.is_some();
+    let mut folder_entries = std::fs::read_dir(folder)?
+        .flatten()
+        .map(|d| d.path())
+        .collect::<Vec<_>>();
+    if let Some(pending) = pending_file {
+        if !folder_entries.iter().any(|path| path == pending) {
+            folder_entries.push(pending.to_path_buf());
+        }
     }
 
     let raw_included_files = if let Some(include) = include {
-        include
-            .into_iter()
-            .filter_map(|file| {
-                if file.contains(|c| matches!(c, '\\' | '/')) {
-                    eprintln!(
-                        "{} {} {}",
-                        "File".dimmed(),
-                        file.dimmed(),
-                        "exists in subfolder, skipping".dimmed()
-                    );
-                    None
-                } else {
-                    let candidate = folder.join(&file);
-                    if !candidate.exists() {
-                        eprintln!(
-                            "{} {} {}",
-                            "File".dimmed(),
-                            file.dimmed(),
-                            "does not exist, skipping".dimmed()
-                        );
-                        None
-                    } else {
-                        Some(candidate)
-                    }
+        let mut included = Vec::new();
+        for pattern in include {
+            if pattern.contains(|c| matches!(c, '\\' | '/')) {
+                eprintln!(
+                    "{} {} {}",
+                    "File".dimmed(),
+                    pattern.dimmed(),
+                    "exists in subfolder, skipping".dimmed()
+                );
+                continue;
+            }
+
+            let mut matches = entries_matching(&folder_entries, &pattern);
+            matches.sort_by_key(|path| lowercase_file_name(path));
+            if matches.is_empty() {
+                eprintln!(
+                    "{} {} {}",
+                    "File".dimmed(),
+                    pattern.dimmed(),
+                    "does not exist, skipping".dimmed()
+                );
+                continue;
+            }
+
+            for candidate in matches {
+                if !included.contains(&candidate) {
+                    included.push(candidate);
                 }
-            })
-            .collect::<Vec<_>>()
+            }
+        }
+        included
     } else {
-        std::fs::read_dir(folder)?
-            .into_iter()
-            .flatten()
-            .map(|d| d.path())
-            .collect::<Vec<_>>()
+        folder_entries.clone()
     };
 
-    let mut files = filter_files(&raw_included_files);
-    files.sort_by_key(|path| {
-        path.file_name()
-            .and_then(|name| name.to_str())
-            .map(|name| name.to_ascii_lowercase())
-    });
+    let mut files = filter_files(&raw_included_files, pending_file);
+    if !keep_include_order {
+        files.sort_by_key(|path| lowercase_file_name(path));
+    }
 
     if let Some(exclude) = exclude {
-        let mut exclude_set = HashSet::new();
+        let mut exclude_patterns = Vec::new();
 
-        for file in exclude {
-            if file.contains(|c| matches!(c, '\\' | '/')) {
+        for pattern in exclude {
+            if pattern.contains(|c| matches!(c, '\\' | '/')) {
                 eprintln!(
                     "{} {} {}",
                     "File".dimmed(),
-                    file.dimmed(),
+                    pattern.dimmed(),
                     "exists in subfolder, skipping exclude".dimmed()
                 );
                 continue;
             }
 
-            let candidate = folder.join(&file);
-            if !candidate.exists() {
+            if entries_matching(&folder_entries, &pattern).is_empty() {
                 eprintln!(
                     "{} {} {}",
                     "File".dimmed(),
-                    file.dimmed(),
+                    pattern.dimmed(),
                     "does not exist, skipping exclude".dimmed()
                 );
                 continue;
             }
 
-            exclude_set.insert(file);
+            exclude_patterns.push(pattern);
         }
 
-        if !exclude_set.is_empty() {
-            files = files
-                .into_iter()
-                .filter(|path| {
-                    path.file_name()
-                        .and_then(|name| name.to_str())
-                        .map(|name| !exclude_set.contains(name))
-                        .unwrap_or(true)
-                })
-                .collect::<Vec<_>>();
+        if !exclude_patterns.is_empty() {
+            files.retain(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(|name| {
+                        !exclude_patterns
+                            .iter()
+                            .any(|pattern| file_name_matches(pattern, name))
+                    })
+                    .unwrap_or(true)
+            });
         }
     }
 
-    if icon_sys.is_some() {
-        if !files.iter().any(|path| path == &icon_sys_path) {
-            files.push(icon_sys_path);
+    if let Some(icon_sys_path) = icon_sys_path {
+        if !files.iter().any(|path| path == icon_sys_path) {
+            files.push(icon_sys_path.to_path_buf());
         }
     }
 
-    let timestamp_value = timestamp.unwrap_or_default();
-    add_psu_defaults(&mut psu, &name, files.len(), timestamp_value);
-    add_files_to_psu(&mut psu, &files, timestamp, metadata_reader)?;
-    std::fs::write(output, PSUWriter::new(psu).to_bytes()?)?;
-    Ok(())
+    Ok(files)
 }
 
-fn check_name(name: &str) -> bool {
-    for c in name.chars() {
-        if !matches!(c, 'a'..'z'|'A'..'Z'|'0'..'9'|'_'|'-'|' ') {
-            return false;
+/// Matches a file name against an include/exclude pattern.
+///
+/// Patterns support `*` (any run of characters) and `?` (exactly one character) and are
+/// compared case-insensitively, so a literal name such as `Thumbs.db` also matches
+/// `THUMBS.DB`.
+pub fn file_name_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let name: Vec<char> = name.chars().flat_map(char::to_lowercase).collect();
+
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some('?') => {
+                p += 1;
+                n += 1;
+            }
+            Some(c) if *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
         }
     }
-    true
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+fn entries_matching(entries: &[PathBuf], pattern: &str) -> Vec<PathBuf> {
+    entries
+        .iter()
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| file_name_matches(pattern, name))
+                .unwrap_or(false)
+        })
+        .cloned()
+        .collect()
 }
 
-fn filter_files(files: &[PathBuf]) -> Vec<PathBuf> {
+fn filter_files(files: &[PathBuf], pending_file: Option<&Path>) -> Vec<PathBuf> {
     files
         .iter()
         .filter_map(|f| {
@@ -288,7 +1340,7 @@ fn filter_files(files: &[PathBuf]) -> Vec<PathBuf> {
                 .unwrap_or(false)
             {
                 None
-            } else if !f.is_file() {
+            } else if !f.is_file() && Some(f.as_path()) != pending_file {
                 println!(
                     "{} {}",
                     f.display().to_string().dimmed(),
@@ -302,10 +1354,24 @@ fn filter_files(files: &[PathBuf]) -> Vec<PathBuf> {
         .collect()
 }
 
-fn add_psu_defaults(psu: &mut PSU, name: &str, file_count: usize, timestamp: NaiveDateTime) {
+/// Value written to the root directory entry's `size` field for a save holding `file_count`
+/// files.
+///
+/// The PS2 browser counts the `.` and `..` entries as children, so the default is
+/// `file_count + 2`. Some tools expect the plain file count instead, which
+/// `excludes_dot_entries` selects.
+pub fn psu_root_dir_size(file_count: usize, excludes_dot_entries: bool) -> u32 {
+    if excludes_dot_entries {
+        file_count as u32
+    } else {
+        file_count as u32 + 2
+    }
+}
+
+fn add_psu_defaults(psu: &mut PSU, name: &str, root_size: u32, timestamp: NaiveDateTime) {
     psu.entries.push(PSUEntry {
         id: DIR_ID,
-        size: file_count as u32 + 2,
+        size: root_size,
         created: timestamp,
         sector: 0,
         modified: timestamp,
@@ -335,30 +1401,146 @@ fn add_psu_defaults(psu: &mut PSU, name: &str, file_count: usize, timestamp: Nai
     });
 }
 
+/// Checks that the files packed from `folder` (plus any merged `secondaries`) map to distinct
+/// PSU entry names. Names are compared case-insensitively, as the PS2 treats `DATA.BIN` and
+/// `data.bin` as the same entry.
+pub fn check_duplicate_file_names(
+    folder: &Path,
+    secondaries: &[PathBuf],
+    cfg: &Config,
+) -> Result<(), Error> {
+    let icon_sys_path = folder.join("icon.sys");
+    let generates_icon_sys = cfg.generated_icon_sys()?.is_some();
+    let pending_icon_sys =
+        (generates_icon_sys && !icon_sys_path.exists()).then_some(icon_sys_path.as_path());
+
+    let mut files = select_files(
+        folder,
+        cfg.resolved_include(folder)?,
+        cfg.exclude.clone(),
+        generates_icon_sys.then_some(icon_sys_path.as_path()),
+        pending_icon_sys,
+        cfg.preserve_include_order,
+    )?;
+    for secondary in secondaries {
+        let secondary_files =
+            select_files(secondary, None, cfg.exclude.clone(), None, None, false)?;
+        merge_files(
+            &mut files,
+            secondary_files,
+            MergeConflictPolicy::PrimaryWins,
+            !cfg.preserve_include_order,
+        )?;
+    }
+    check_duplicate_names(&files)
+}
+
+fn check_duplicate_names(files: &[PathBuf]) -> Result<(), Error> {
+    let mut collisions = Vec::new();
+    for (index, file) in files.iter().enumerate() {
+        if let Some(other) = files[..index]
+            .iter()
+            .find(|earlier| same_entry_name(earlier, file))
+        {
+            collisions.push(format!("{} / {}", other.display(), file.display()));
+        }
+    }
+
+    if collisions.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::ConfigError(format!(
+            "Duplicate file names (the PS2 ignores case): {}",
+            collisions.join(", ")
+        )))
+    }
+}
+
+/// Rejects files that would be written under the name of a directory entry: `.`, `..`, or
+/// the archive's root directory.
+fn check_reserved_names(files: &[PathBuf], root_name: &str) -> Result<(), Error> {
+    for file in files {
+        let Some(name) = file.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if name == "." || name == ".." || name.eq_ignore_ascii_case(root_name) {
+            return Err(Error::ReservedEntryName(name.to_string()));
+        }
+    }
+    Ok(())
+}
+
+fn same_entry_name(a: &Path, b: &Path) -> bool {
+    match (
+        a.file_name().and_then(|name| name.to_str()),
+        b.file_name().and_then(|name| name.to_str()),
+    ) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => false,
+    }
+}
+
+fn merge_files(
+    files: &mut Vec<PathBuf>,
+    additional: Vec<PathBuf>,
+    policy: MergeConflictPolicy,
+    sort: bool,
+) -> Result<(), Error> {
+    for candidate in additional {
+        let existing = files.iter().find(|file| same_entry_name(file, &candidate));
+        match (existing, policy) {
+            (None, _) => files.push(candidate),
+            (Some(_), MergeConflictPolicy::PrimaryWins) => {}
+            (Some(existing), MergeConflictPolicy::Error) => {
+                return Err(Error::ConfigError(format!(
+                    "{} conflicts with {}",
+                    candidate.display(),
+                    existing.display()
+                )));
+            }
+        }
+    }
+
+    if sort {
+        files.sort_by_key(|path| lowercase_file_name(path));
+    }
+    Ok(())
+}
+
+fn lowercase_file_name(path: &Path) -> Option<String> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.to_ascii_lowercase())
+}
+
 fn add_files_to_psu<M: MetadataReader>(
     psu: &mut PSU,
     files: &[PathBuf],
+    generated_file: Option<(&Path, &[u8])>,
     timestamp: Option<NaiveDateTime>,
+    file_timestamps: &BTreeMap<String, EntryTimestamps>,
     metadata_reader: &M,
+    progress: &mut dyn FnMut(PackProgressEvent),
 ) -> Result<(), Error> {
-    for file in files {
+    let total = files.len();
+    for (index, file) in files.iter().enumerate() {
         let name = file.file_name().unwrap().to_str().unwrap();
 
-        let f = std::fs::read(file)?;
-        let (created, modified) = if let Some(timestamp) = timestamp {
-            (timestamp, timestamp)
+        let generated = generated_file
+            .filter(|(path, _)| path == file)
+            .map(|(_, bytes)| bytes);
+        let f = match generated {
+            Some(bytes) => bytes.to_vec(),
+            None => std::fs::read(file).map_err(|source| Error::file(file, source))?,
+        };
+        let overrides = file_timestamps.get(name);
+        let (created, modified) = if generated.is_some() && !file.exists() {
+            let reader = GeneratedFileMetadataReader(metadata_reader);
+            resolve_file_times(file, timestamp, overrides, &reader)?
         } else {
-            let file_times = metadata_reader.file_times(file)?;
-            let modified = convert_timestamp(file_times.modified);
-            let created = file_times
-                .created
-                .map(convert_timestamp)
-                .unwrap_or(modified);
-            (created, modified)
+            resolve_file_times(file, timestamp, overrides, metadata_reader)?
         };
 
-        println!("+ {} {}", "Adding", name.green());
-
         psu.entries.push(PSUEntry {
             id: FILE_ID,
             size: f.len() as u32,
@@ -368,12 +1550,68 @@ fn add_files_to_psu<M: MetadataReader>(
             name: name.to_owned(),
             kind: PSUEntryKind::File,
             contents: Some(f),
-        })
+        });
+
+        progress(PackProgressEvent {
+            index,
+            total,
+            name: name.to_owned(),
+        });
     }
 
     Ok(())
 }
 
+/// Resolves the created and modified times of a packed file. Per-file overrides win, then the
+/// global timestamp, then the file's metadata.
+fn resolve_file_times<M: MetadataReader>(
+    file: &Path,
+    timestamp: Option<NaiveDateTime>,
+    overrides: Option<&EntryTimestamps>,
+    metadata_reader: &M,
+) -> Result<(NaiveDateTime, NaiveDateTime), Error> {
+    let overrides = overrides.copied().unwrap_or_default();
+    if let (Some(created), Some(modified)) = (overrides.created, overrides.modified) {
+        return Ok((created, modified));
+    }
+
+    let (created, modified) = match timestamp {
+        Some(timestamp) => (timestamp, timestamp),
+        None => {
+            let file_times = metadata_reader
+                .file_times(file)
+                .map_err(|source| Error::file(file, source))?;
+            let modified = convert_timestamp(file_times.modified);
+            let created = file_times
+                .created
+                .map(convert_timestamp)
+                .unwrap_or(modified);
+            (created, modified)
+        }
+    };
+    Ok((
+        overrides.created.unwrap_or(created),
+        overrides.modified.unwrap_or(modified),
+    ))
+}
+
+/// Times for a file generated at pack time, which may not exist on disk yet. The wrapped
+/// reader is asked first, so a [`FixedTimeMetadataReader`] keeps the pack reproducible;
+/// when it cannot read the missing file, the current time is used.
+struct GeneratedFileMetadataReader<'a, M>(&'a M);
+
+impl<M: MetadataReader> MetadataReader for GeneratedFileMetadataReader<'_, M> {
+    fn file_times(&self, path: &Path) -> std::io::Result<FileTimes> {
+        self.0.file_times(path).or_else(|_| {
+            let now = SystemTime::now();
+            Ok(FileTimes {
+                created: Some(now),
+                modified: now,
+            })
+        })
+    }
+}
+
 fn convert_timestamp(time: SystemTime) -> NaiveDateTime {
     let duration = time.duration_since(UNIX_EPOCH).unwrap();
     DateTime::from_timestamp(duration.as_secs() as i64, duration.subsec_nanos())
@@ -387,6 +1625,22 @@ pub enum Error {
     NameError,
     IOError(std::io::Error),
     ConfigError(String),
+    /// A packed file would share its name with `.`, `..`, or the root directory entry.
+    ReservedEntryName(String),
+    /// Reading a file to pack failed.
+    FileError {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Error {
+    fn file(path: &Path, source: std::io::Error) -> Self {
+        Error::FileError {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -395,6 +1649,13 @@ impl std::fmt::Display for Error {
             Error::NameError => write!(f, "Name must match [a-zA-Z0-9._-\\s]+"),
             Error::IOError(err) => write!(f, "{err:?}"),
             Error::ConfigError(err) => write!(f, "{err}"),
+            Error::ReservedEntryName(name) => write!(
+                f,
+                "File name '{name}' is reserved for a directory entry of the PSU"
+            ),
+            Error::FileError { path, source } => {
+                write!(f, "Failed to read {}: {source}", path.display())
+            }
         }
     }
 }
@@ -404,3 +1665,32 @@ impl From<std::io::Error> for Error {
         Error::IOError(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_name_accepts_every_ascii_alphanumeric() {
+        for c in (0u8..=127)
+            .map(char::from)
+            .filter(char::is_ascii_alphanumeric)
+        {
+            assert!(check_name(&c.to_string()), "{c:?} should be accepted");
+        }
+    }
+
+    #[test]
+    fn check_name_accepts_range_endpoints_in_words() {
+        assert!(check_name("zoo"));
+        assert!(check_name("ZEBRA"));
+        assert!(check_name("SAVE9"));
+    }
+
+    #[test]
+    fn check_name_rejects_directory_markers_and_separators() {
+        for name in [".", "..", "../SAVE", "SAVE/DATA", "SAVE\\DATA"] {
+            assert!(!check_name(name), "{name:?} should be rejected");
+        }
+    }
+}
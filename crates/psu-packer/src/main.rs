@@ -2,7 +2,7 @@ use argh::FromArgs;
 use colored::Colorize;
 use std::path::PathBuf;
 
-use psu_packer::{load_config, pack_psu, Error};
+use psu_packer::{load_config, pack_psu, Error, PsuInfo};
 
 #[derive(Debug, FromArgs)]
 #[argh(
@@ -15,12 +15,25 @@ struct Args {
     /// output path
     #[argh(option, short = 'o')]
     output: Option<String>,
+    /// print the entry list as CSV instead of packing; accepts a folder or a .psu file
+    #[argh(switch)]
+    csv: bool,
 }
 
 fn main() -> Result<(), Error> {
     let args: Args = argh::from_env();
     let folder = PathBuf::from(&args.folder);
 
+    if args.csv {
+        let info = if folder.is_file() {
+            PsuInfo::from_psu_bytes(&std::fs::read(&folder)?)?
+        } else {
+            PsuInfo::from_project(&folder, &load_config(&folder)?)?
+        };
+        print!("{}", info.to_csv());
+        return Ok(());
+    }
+
     let config = load_config(&folder)?;
     let output_file = args.output.unwrap_or(format!("{}.psu", config.name));
     let output_path = PathBuf::from(&output_file);
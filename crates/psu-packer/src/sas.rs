@@ -1,4 +1,7 @@
-use std::{collections::HashSet, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
 
 use chrono::{
     DateTime, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
@@ -39,12 +42,25 @@ fn canonical_category_aliases() -> &'static [SharedCategory] {
     &shared_sas_data().categories
 }
 
-pub fn canonical_aliases_for_category(key: &str) -> Vec<String> {
+static CATEGORY_KEYS: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    canonical_category_aliases()
+        .iter()
+        .map(|group| group.key.as_str())
+        .collect()
+});
+
+/// Every known SAS category key, in canonical order.
+pub fn all_category_keys() -> &'static [&'static str] {
+    &CATEGORY_KEYS
+}
+
+/// Canonical aliases for the category `key`; empty for unknown keys.
+pub fn canonical_aliases_for_category(key: &str) -> &'static [String] {
     canonical_category_aliases()
         .iter()
         .find(|group| group.key == key)
-        .map(|group| group.aliases.clone())
-        .unwrap_or_default()
+        .map(|group| group.aliases.as_slice())
+        .unwrap_or(&[])
 }
 
 fn is_supported_alias(key: &str, alias: &str) -> bool {
@@ -107,7 +123,12 @@ impl TimestampRules {
             .collect()
     }
 
-    pub fn sanitize(&mut self) {
+    /// Normalizes the rules in place and returns a human-readable note for every value that
+    /// had to change, e.g. "Rounded interval from 7 to 8 seconds".
+    pub fn sanitize(&mut self) -> Vec<String> {
+        let mut adjustments = Vec::new();
+
+        let requested_interval = self.seconds_between_items;
         if self.seconds_between_items == 0 {
             self.seconds_between_items = Self::default_seconds_between_items();
         }
@@ -118,13 +139,27 @@ impl TimestampRules {
             max_value - (max_value % 2)
         };
         self.seconds_between_items = next_even.min(max_even) as u32;
+        if self.seconds_between_items != requested_interval {
+            adjustments.push(format!(
+                "Rounded interval from {requested_interval} to {} seconds",
+                self.seconds_between_items
+            ));
+        }
         // Slots per category are pinned to a single local day worth of two-second slots so
         // each category can occupy its own date on the timeline.
+        let requested_slots = self.slots_per_category;
         self.slots_per_category = Self::default_slots_per_category();
+        if self.slots_per_category != requested_slots {
+            adjustments.push(format!(
+                "Set slots per category from {requested_slots} to {}",
+                self.slots_per_category
+            ));
+        }
 
         if self.categories.is_empty() {
             *self = Self::default();
-            return;
+            adjustments.push("No categories were defined; restored the defaults".to_string());
+            return adjustments;
         }
 
         let mut sanitized = Vec::with_capacity(self.categories.len());
@@ -133,17 +168,21 @@ impl TimestampRules {
         for category in self.categories.drain(..) {
             let key = category.key.trim().to_ascii_uppercase();
             if key.is_empty() {
+                adjustments.push("Removed a category with an empty key".to_string());
                 continue;
             }
             if !seen_keys.insert(key.clone()) {
+                adjustments.push(format!("Removed duplicate category {key}"));
                 continue;
             }
 
-            let mut aliases: Vec<String> = category
-                .aliases
-                .into_iter()
-                .filter_map(|alias| sanitize_alias(alias, &key))
-                .collect();
+            let mut aliases: Vec<String> = Vec::with_capacity(category.aliases.len());
+            for alias in category.aliases {
+                match sanitize_alias(alias.clone(), &key) {
+                    Some(value) => aliases.push(value),
+                    None => adjustments.push(format!("Removed alias \"{alias}\" from {key}")),
+                }
+            }
 
             let mut seen_aliases = HashSet::new();
             aliases.retain(|alias| seen_aliases.insert(alias.clone()));
@@ -156,9 +195,11 @@ impl TimestampRules {
                 key: "DEFAULT".to_string(),
                 aliases: Vec::new(),
             });
+            adjustments.push("Added the missing DEFAULT category".to_string());
         }
 
         self.categories = sanitized;
+        adjustments
     }
 
     pub fn seconds_between_items_i64(&self) -> i64 {
@@ -168,6 +209,53 @@ impl TimestampRules {
     pub fn slots_per_category_i64(&self) -> i64 {
         i64::from(self.slots_per_category)
     }
+
+    /// Returns the first slot of `category` that none of `existing_names` is scheduled in,
+    /// together with the timestamp of that slot. `None` means the category is unknown or
+    /// every slot is taken.
+    pub fn next_free_slot(
+        &self,
+        existing_names: &[&str],
+        category: &str,
+    ) -> Option<(u32, NaiveDateTime)> {
+        let category_index = self
+            .categories
+            .iter()
+            .position(|rule| rule.key.eq_ignore_ascii_case(category.trim()))?;
+
+        let occupied = existing_names
+            .iter()
+            .filter_map(|name| normalize_name_for_rules(name, self))
+            .filter(|effective| category_priority_index(effective, self) == Some(category_index))
+            .map(|effective| slot_index_within_category(&effective, self))
+            .collect::<HashSet<_>>();
+
+        let slot = (0..self.slots_per_category_i64()).find(|slot| !occupied.contains(slot))?;
+        let timestamp =
+            timestamp_for_offset(category_index, slot * self.seconds_between_items_i64())?;
+        Some((slot as u32, timestamp))
+    }
+
+    /// Reports every pair of `names` whose planned timestamps are identical, with the shared
+    /// timestamp. Such saves cannot be told apart by their SAS ordering on the card. Names
+    /// without a planned timestamp are skipped.
+    pub fn find_collisions(&self, names: &[String]) -> Vec<(String, String, NaiveDateTime)> {
+        let mut by_timestamp: HashMap<NaiveDateTime, Vec<&String>> = HashMap::new();
+        let mut collisions = Vec::new();
+        for name in names {
+            let Some(timestamp) = planned_timestamp_for_name(name, self) else {
+                continue;
+            };
+            let earlier = by_timestamp.entry(timestamp).or_default();
+            collisions.extend(
+                earlier
+                    .iter()
+                    .map(|first| ((*first).clone(), name.clone(), timestamp)),
+            );
+            earlier.push(name);
+        }
+        collisions
+    }
 }
 
 impl Default for TimestampRules {
@@ -180,6 +268,76 @@ impl Default for TimestampRules {
     }
 }
 
+/// Built-in starting points for [`TimestampRules`].
+///
+/// `categories` lists the category keys to keep, in order. `None` keeps the canonical
+/// category order. Canonical aliases are re-applied when `include_aliases` is set.
+#[derive(Clone, Copy, Debug)]
+pub struct TimestampRulesPreset {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub categories: Option<&'static [&'static str]>,
+    pub include_aliases: bool,
+}
+
+impl TimestampRulesPreset {
+    pub fn rules(&self) -> TimestampRules {
+        let categories = match self.categories {
+            Some(keys) => keys.iter().map(|key| CategoryRule::new(key)).collect(),
+            None => canonical_category_aliases()
+                .iter()
+                .map(|group| CategoryRule::new(&group.key))
+                .collect::<Vec<_>>(),
+        };
+
+        let mut rules = TimestampRules {
+            seconds_between_items: TimestampRules::default_seconds_between_items(),
+            slots_per_category: TimestampRules::default_slots_per_category(),
+            categories,
+        };
+
+        if self.include_aliases {
+            for category in &mut rules.categories {
+                category.aliases = canonical_aliases_for_category(&category.key).to_vec();
+            }
+        }
+
+        rules.sanitize();
+        rules
+    }
+}
+
+pub const TIMESTAMP_RULES_PRESETS: &[TimestampRulesPreset] = &[
+    TimestampRulesPreset {
+        id: "spread_by_category",
+        label: "Spread by category",
+        description: "Every canonical category gets its own day, with canonical aliases enabled.",
+        categories: None,
+        include_aliases: true,
+    },
+    TimestampRulesPreset {
+        id: "compact",
+        label: "Compact",
+        description: "Only apps get their own days; everything else shares the default day.",
+        categories: Some(&["APP_", "APPS", "DEFAULT"]),
+        include_aliases: true,
+    },
+    TimestampRulesPreset {
+        id: "chronological_by_name",
+        label: "Chronological by name",
+        description: "A single category ordered purely by save name.",
+        categories: Some(&["DEFAULT"]),
+        include_aliases: false,
+    },
+];
+
+pub fn find_timestamp_rules_preset(id: &str) -> Option<&'static TimestampRulesPreset> {
+    TIMESTAMP_RULES_PRESETS
+        .iter()
+        .find(|preset| preset.id == id)
+}
+
 fn sanitize_alias(alias: String, key: &str) -> Option<String> {
     let mut value = alias.trim().to_ascii_uppercase();
     if value.is_empty() {
@@ -212,9 +370,32 @@ pub fn planned_timestamp_for_name(name: &str, rules: &TimestampRules) -> Option<
         return None;
     }
 
-    // Each category begins at ANCHOR_START + category_index days at local midnight and then
-    // advances forward in two-second slots within that day.
     let (category_index, slot_offset_seconds) = deterministic_offset_seconds(trimmed, rules)?;
+    timestamp_for_offset(category_index, slot_offset_seconds)
+}
+
+/// Lists every slot of the category `key` with its timestamp, in slot order, so a whole
+/// category's schedule can be reviewed at once. An unknown category yields an empty schedule.
+pub fn schedule_for_category(key: &str, rules: &TimestampRules) -> Vec<(u32, NaiveDateTime)> {
+    let Some(category_index) = rules
+        .categories
+        .iter()
+        .position(|rule| rule.key.eq_ignore_ascii_case(key.trim()))
+    else {
+        return Vec::new();
+    };
+
+    (0..rules.slots_per_category)
+        .filter_map(|slot| {
+            let offset = i64::from(slot) * rules.seconds_between_items_i64();
+            timestamp_for_offset(category_index, offset).map(|timestamp| (slot, timestamp))
+        })
+        .collect()
+}
+
+/// Each category begins at ANCHOR_START + category_index days at local midnight and then
+/// advances forward in two-second slots within that day.
+fn timestamp_for_offset(category_index: usize, slot_offset_seconds: i64) -> Option<NaiveDateTime> {
     let anchor_date = anchor_naive_date()?;
     let midnight = NaiveTime::from_hms_opt(0, 0, 0)?;
     let category_start_date =
@@ -359,6 +540,84 @@ mod tests {
         assert_eq!(timestamp.nanosecond(), 0);
     }
 
+    #[test]
+    fn category_schedule_spaces_every_slot_by_the_interval() {
+        let rules = TimestampRules {
+            seconds_between_items: 4,
+            slots_per_category: 16,
+            ..TimestampRules::default()
+        };
+
+        let schedule = schedule_for_category("app_", &rules);
+        assert_eq!(schedule.len(), 16);
+        for (index, (slot, _)) in schedule.iter().enumerate() {
+            assert_eq!(*slot as usize, index);
+        }
+        for pair in schedule.windows(2) {
+            assert_eq!((pair[1].1 - pair[0].1).num_seconds(), 4);
+        }
+
+        let name = planned_timestamp_for_name("APP_SAMPLE", &rules).expect("timestamp");
+        assert!(schedule.iter().any(|(_, timestamp)| *timestamp == name));
+        assert!(schedule_for_category("NOT_A_CATEGORY", &rules).is_empty());
+    }
+
+    #[test]
+    fn collisions_report_names_sharing_a_slot() {
+        let rules = TimestampRules {
+            slots_per_category: 1,
+            ..TimestampRules::default()
+        };
+        let names = vec![
+            "APP_FIRST".to_string(),
+            "EMU_OTHER".to_string(),
+            "APP_SECOND".to_string(),
+        ];
+
+        let collisions = rules.find_collisions(&names);
+        let expected = planned_timestamp_for_name("APP_FIRST", &rules).expect("timestamp");
+        assert_eq!(
+            collisions,
+            vec![("APP_FIRST".to_string(), "APP_SECOND".to_string(), expected)]
+        );
+        assert!(TimestampRules::default()
+            .find_collisions(&names[..2])
+            .is_empty());
+    }
+
+    #[test]
+    fn sanitize_reports_rounded_interval() {
+        let mut rules = TimestampRules {
+            seconds_between_items: 7,
+            ..TimestampRules::default()
+        };
+        let adjustments = rules.sanitize();
+        assert_eq!(rules.seconds_between_items, 8);
+        assert_eq!(adjustments, vec!["Rounded interval from 7 to 8 seconds"]);
+
+        assert!(
+            rules.sanitize().is_empty(),
+            "sanitized rules need no changes"
+        );
+    }
+
+    #[test]
+    fn all_category_keys_cover_default_rules() {
+        let keys = all_category_keys();
+        for category in TimestampRules::default().categories {
+            assert!(
+                keys.contains(&category.key.as_str()),
+                "missing category {}",
+                category.key
+            );
+            assert_eq!(
+                canonical_aliases_for_category(&category.key),
+                category.aliases.as_slice()
+            );
+        }
+        assert!(canonical_aliases_for_category("NOT_A_CATEGORY").is_empty());
+    }
+
     #[test]
     fn handles_aliases() {
         let mut rules = TimestampRules::default();
@@ -473,4 +732,58 @@ mod tests {
         let reparsed: SharedSasData = serde_json::from_str(&json).expect("reparse shared data");
         assert_eq!(*shared, reparsed);
     }
+
+    #[test]
+    fn presets_produce_sanitized_rules() {
+        for preset in TIMESTAMP_RULES_PRESETS {
+            let rules = preset.rules();
+            let mut sanitized = rules.clone();
+            sanitized.sanitize();
+
+            assert_eq!(
+                rules.categories.len(),
+                sanitized.categories.len(),
+                "preset {} should already be sanitized",
+                preset.id
+            );
+            assert!(rules
+                .categories
+                .iter()
+                .any(|category| category.key == "DEFAULT"));
+        }
+    }
+
+    #[test]
+    fn next_free_slot_skips_occupied_slots() {
+        let rules = find_timestamp_rules_preset("chronological_by_name")
+            .expect("preset")
+            .rules();
+
+        let (slot, timestamp) = rules.next_free_slot(&[], "DEFAULT").expect("free slot");
+        assert_eq!(slot, 0);
+        assert_eq!(timestamp.time(), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+        // Dashes are dropped from the payload, so "-" occupies slot 0.
+        assert_eq!(slot_index_within_category("-", &rules), 0);
+        let (slot, timestamp) = rules.next_free_slot(&["-"], "default").expect("free slot");
+        assert_eq!(slot, 1);
+        assert_eq!(
+            timestamp.time(),
+            NaiveTime::from_hms_opt(0, 0, rules.seconds_between_items).unwrap()
+        );
+
+        assert!(rules.next_free_slot(&[], "APP_").is_none());
+    }
+
+    #[test]
+    fn chronological_preset_orders_by_name() {
+        let rules = find_timestamp_rules_preset("chronological_by_name")
+            .expect("preset")
+            .rules();
+
+        let first = planned_timestamp_for_name("APP_ZZZ", &rules).expect("first timestamp");
+        let second = planned_timestamp_for_name("BOOT", &rules).expect("second timestamp");
+        assert!(first < second);
+        assert_eq!(first.date(), second.date());
+    }
 }
@@ -0,0 +1,360 @@
+use std::path::Path;
+
+use ps2_filetypes::{IconSys, PSUEntryKind};
+
+use crate::batch::split_prefix;
+use crate::{plan_pack, shift_jis_byte_length, ColorFConfig, Config};
+
+const TOP_LEVEL_KEYS: &[&str] = &["config", "icon_sys", "timestamps"];
+const ENTRY_TIMESTAMP_KEYS: &[&str] = &["created", "modified"];
+const CONFIG_KEYS: &[&str] = &[
+    "name",
+    "timestamp",
+    "include",
+    "exclude",
+    "root_size_excludes_dot_entries",
+    "preserve_include_order",
+    "include_from_manifest",
+    "verify_after_pack",
+    "root_dir_name",
+    "generate_icon_sys",
+];
+/// `default_variant` must stay first; variant tables accept every key after it.
+pub(crate) const ICON_SYS_KEYS: &[&str] = &[
+    "default_variant",
+    "flags",
+    "title",
+    "linebreak_pos",
+    "preset",
+    "background_transparency",
+    "background_colors",
+    "light_directions",
+    "light_colors",
+    "ambient_color",
+];
+
+/// Non-fatal issues found while loading `psu.toml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigWarning {
+    /// A key that psu-packer does not understand and will ignore.
+    UnknownKey { section: String, key: String },
+    /// The configured name starts or ends with whitespace.
+    NameWhitespace,
+    /// The name contains `z`, `Z` or `9` after its SAS prefix, which releases before the name
+    /// check was fixed rejected even though the name is valid.
+    LegacyNameRejection { characters: String },
+    /// An icon.sys value that is accepted but outside the range the console expects.
+    IconSysOutOfRange { field: String, detail: String },
+    /// A `[timestamps]` entry names a file that is not packed, so it has no effect.
+    UnpackedTimestamp { name: String },
+    /// A name listed in both `include` and `exclude`. Exclude wins, so the file is not packed.
+    IncludedAndExcluded { name: String },
+    /// icon.sys names an icon file that is not among the packed files.
+    MissingIconFile { name: String },
+}
+
+impl std::fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConfigWarning::UnknownKey { section, key } if section.is_empty() => {
+                write!(f, "Unknown top-level key `{key}` is ignored")
+            }
+            ConfigWarning::UnknownKey { section, key } => {
+                write!(f, "Unknown key `{key}` in [{section}] is ignored")
+            }
+            ConfigWarning::NameWhitespace => {
+                write!(f, "The name has leading or trailing whitespace")
+            }
+            ConfigWarning::LegacyNameRejection { characters } => write!(
+                f,
+                "The name is valid, but older releases rejected names containing {characters}"
+            ),
+            ConfigWarning::IconSysOutOfRange { field, detail } => {
+                write!(f, "icon_sys.{field} {detail}")
+            }
+            ConfigWarning::UnpackedTimestamp { name } => {
+                write!(
+                    f,
+                    "timestamps.\"{name}\" is ignored because {name} is not packed"
+                )
+            }
+            ConfigWarning::MissingIconFile { name } => {
+                write!(f, "icon.sys refers to {name}, which is not packed")
+            }
+            ConfigWarning::IncludedAndExcluded { name } => {
+                write!(
+                    f,
+                    "{name} is listed in both include and exclude, so it will not be packed"
+                )
+            }
+        }
+    }
+}
+
+/// The characters of `name` that the old name check refused. It used exclusive ranges
+/// (`'a'..'z'`, `'A'..'Z'`, `'0'..'9'`), so the last character of each range was rejected.
+/// The SAS prefix is skipped: `ZZY_` and `ZZZ_` are fixed category prefixes, so a note about
+/// them could never be acted on.
+fn legacy_rejected_characters(name: &str) -> Option<String> {
+    let (_, base) = split_prefix(name);
+    let rejected = ['z', 'Z', '9']
+        .into_iter()
+        .filter(|c| base.contains(*c))
+        .map(|c| format!("`{c}`"))
+        .collect::<Vec<_>>();
+    (!rejected.is_empty()).then(|| rejected.join(", "))
+}
+
+/// Warns about `[timestamps]` entries for files that packing `folder` would leave out. Nothing is
+/// reported when the pack cannot be planned; packing reports that error itself.
+pub(crate) fn unpacked_timestamp_warnings(folder: &Path, config: &Config) -> Vec<ConfigWarning> {
+    if config.file_timestamps.is_empty() {
+        return Vec::new();
+    }
+    let Ok(planned) = plan_pack(folder, config) else {
+        return Vec::new();
+    };
+
+    config
+        .file_timestamps
+        .keys()
+        .filter(|name| {
+            !planned
+                .iter()
+                .any(|entry| matches!(entry.kind, PSUEntryKind::File) && &entry.name == *name)
+        })
+        .map(|name| ConfigWarning::UnpackedTimestamp { name: name.clone() })
+        .collect()
+}
+
+/// Warns about names listed in both `include` and `exclude`, compared case-insensitively like
+/// exclude matching. Each name is reported once, in include order.
+pub fn include_exclude_overlap_warnings(config: &Config) -> Vec<ConfigWarning> {
+    let (Some(include), Some(exclude)) = (&config.include, &config.exclude) else {
+        return Vec::new();
+    };
+
+    let mut warnings: Vec<ConfigWarning> = Vec::new();
+    for name in include {
+        let excluded = exclude
+            .iter()
+            .any(|excluded| excluded.eq_ignore_ascii_case(name));
+        let reported = warnings.iter().any(|warning| {
+            matches!(warning, ConfigWarning::IncludedAndExcluded { name: reported }
+                if reported.eq_ignore_ascii_case(name))
+        });
+        if excluded && !reported {
+            warnings.push(ConfigWarning::IncludedAndExcluded { name: name.clone() });
+        }
+    }
+    warnings
+}
+
+/// Warns about icon files named by the folder's own icon.sys that are not among the packed
+/// files. Names are compared case-insensitively. Nothing is reported when packing generates the
+/// icon.sys from the config, since that one always names the packer's icon file, or when the
+/// pack cannot be planned or there is no readable icon.sys.
+pub fn missing_icon_file_warnings(folder: &Path, config: &Config) -> Vec<ConfigWarning> {
+    if !matches!(config.generated_icon_sys(), Ok(None)) || !has_icon_sys(folder) {
+        return Vec::new();
+    }
+    let Ok(planned) = plan_pack(folder, config) else {
+        return Vec::new();
+    };
+    let packed = planned
+        .iter()
+        .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+        .map(|entry| entry.name.as_str())
+        .collect::<Vec<_>>();
+
+    let Some(icon_sys) = packed
+        .iter()
+        .find(|name| name.eq_ignore_ascii_case("icon.sys"))
+        .and_then(|name| std::fs::read(folder.join(name)).ok())
+        .and_then(|bytes| IconSys::try_new(bytes).ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut warnings: Vec<ConfigWarning> = Vec::new();
+    for name in [
+        &icon_sys.icon_file,
+        &icon_sys.icon_copy_file,
+        &icon_sys.icon_delete_file,
+    ] {
+        let missing = !packed
+            .iter()
+            .any(|packed| packed.eq_ignore_ascii_case(name));
+        let reported = warnings.iter().any(|warning| {
+            matches!(warning, ConfigWarning::MissingIconFile { name: reported }
+                if reported.eq_ignore_ascii_case(name))
+        });
+        if missing && !reported {
+            warnings.push(ConfigWarning::MissingIconFile { name: name.clone() });
+        }
+    }
+    warnings
+}
+
+/// Whether `folder` directly contains an icon.sys, checked before planning a whole pack.
+fn has_icon_sys(folder: &Path) -> bool {
+    std::fs::read_dir(folder).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.eq_ignore_ascii_case("icon.sys"))
+        })
+    })
+}
+
+pub(crate) fn collect_config_warnings(raw: &toml::Value, config: &Config) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+
+    if let Some(table) = raw.as_table() {
+        unknown_keys(&mut warnings, "", table, TOP_LEVEL_KEYS);
+        if let Some(section_table) = table.get("config").and_then(|value| value.as_table()) {
+            unknown_keys(&mut warnings, "config", section_table, CONFIG_KEYS);
+        }
+        if let Some(section_table) = table.get("icon_sys").and_then(|value| value.as_table()) {
+            // Table-valued keys that are not icon.sys fields are `[icon_sys.<label>]` variants.
+            let (variants, fields): (toml::value::Table, toml::value::Table) =
+                section_table.clone().into_iter().partition(|(key, value)| {
+                    value.is_table() && !ICON_SYS_KEYS.contains(&key.as_str())
+                });
+            unknown_keys(&mut warnings, "icon_sys", &fields, ICON_SYS_KEYS);
+            for (label, variant) in &variants {
+                if let Some(variant_table) = variant.as_table() {
+                    unknown_keys(
+                        &mut warnings,
+                        &format!("icon_sys.{label}"),
+                        variant_table,
+                        &ICON_SYS_KEYS[1..],
+                    );
+                }
+            }
+        }
+        if let Some(section_table) = table.get("timestamps").and_then(|value| value.as_table()) {
+            for (name, entry) in section_table {
+                if let Some(entry_table) = entry.as_table() {
+                    unknown_keys(
+                        &mut warnings,
+                        &format!("timestamps.\"{name}\""),
+                        entry_table,
+                        ENTRY_TIMESTAMP_KEYS,
+                    );
+                }
+            }
+        }
+    }
+
+    if config.name.trim() != config.name {
+        warnings.push(ConfigWarning::NameWhitespace);
+    }
+
+    if let Some(characters) = legacy_rejected_characters(&config.name) {
+        warnings.push(ConfigWarning::LegacyNameRejection { characters });
+    }
+
+    warnings.extend(include_exclude_overlap_warnings(config));
+
+    if let Some(icon_sys) = &config.icon_sys {
+        if let (Some(linebreak), Ok(title_len)) = (
+            icon_sys.linebreak_pos,
+            shift_jis_byte_length(&icon_sys.title),
+        ) {
+            if usize::from(linebreak) > title_len {
+                warnings.push(ConfigWarning::IconSysOutOfRange {
+                    field: "linebreak_pos".to_string(),
+                    detail: format!("is {linebreak} but the title is only {title_len} bytes long"),
+                });
+            }
+        }
+
+        let light_colors = icon_sys.light_colors.iter().flatten().copied().collect();
+        let ambient_color = icon_sys.ambient_color.into_iter().collect();
+        for (field, colors) in [
+            ("light_colors", light_colors),
+            ("ambient_color", ambient_color),
+        ] {
+            let colors: Vec<ColorFConfig> = colors;
+            if colors
+                .iter()
+                .flat_map(|color| [color.r, color.g, color.b, color.a])
+                .any(|value| !(0.0..=1.0).contains(&value))
+            {
+                warnings.push(ConfigWarning::IconSysOutOfRange {
+                    field: field.to_string(),
+                    detail: "contains components outside 0.0..=1.0".to_string(),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+fn unknown_keys(
+    warnings: &mut Vec<ConfigWarning>,
+    section: &str,
+    table: &toml::value::Table,
+    known: &[&str],
+) {
+    for key in table.keys() {
+        if !known.contains(&key.as_str()) {
+            warnings.push(ConfigWarning::UnknownKey {
+                section: section.to_string(),
+                key: key.clone(),
+            });
+        }
+    }
+}
+
+/// Whether `existing` contains any key that [`carry_over_unknown_keys`] would preserve.
+pub(crate) fn has_unknown_keys(existing: &toml::value::Table) -> bool {
+    let mut probe = toml::value::Table::new();
+    carry_over_unknown_keys(existing, &mut probe);
+    !probe.is_empty()
+}
+
+/// Copies keys psu-packer does not understand from `existing` into `target`.
+///
+/// Unknown top-level tables are copied whole; unknown keys inside `[config]` and `[icon_sys]`
+/// are copied into the matching section of `target`. icon.sys variant tables are understood
+/// and therefore never copied.
+pub(crate) fn carry_over_unknown_keys(
+    existing: &toml::value::Table,
+    target: &mut toml::value::Table,
+) {
+    for (key, value) in existing {
+        if !TOP_LEVEL_KEYS.contains(&key.as_str()) {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+
+    for (section, known) in [("config", CONFIG_KEYS), ("icon_sys", ICON_SYS_KEYS)] {
+        let Some(existing_section) = existing.get(section).and_then(|value| value.as_table())
+        else {
+            continue;
+        };
+        let unknown = existing_section
+            .iter()
+            .filter(|(key, value)| {
+                let variant_table = section == "icon_sys" && value.is_table();
+                !known.contains(&key.as_str()) && !variant_table
+            })
+            .collect::<Vec<_>>();
+        if unknown.is_empty() {
+            continue;
+        }
+
+        let target_section = target
+            .entry(section.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::value::Table::new()));
+        if let Some(target_section) = target_section.as_table_mut() {
+            for (key, value) in unknown {
+                target_section.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
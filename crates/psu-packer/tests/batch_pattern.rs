@@ -0,0 +1,136 @@
+use std::fs;
+use std::path::Path;
+
+use psu_packer::{pack_all, pack_all_with_pattern, Config, Error, OutputNamePattern};
+use tempfile::tempdir;
+
+fn write_project(root: &Path, folder: &str, name: &str, version: Option<&str>) {
+    let project = root.join(folder);
+    fs::create_dir_all(&project).expect("create project");
+    fs::write(
+        project.join("psu.toml"),
+        format!("[config]\nname = \"{name}\"\nexclude = [\"psu.toml\"]\n"),
+    )
+    .expect("write psu.toml");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write file");
+    if let Some(version) = version {
+        fs::write(
+            project.join("title.cfg"),
+            format!("title=Test\nVersion={version}\n"),
+        )
+        .expect("write title.cfg");
+    }
+}
+
+#[test]
+fn unknown_placeholder_is_rejected() {
+    let err = OutputNamePattern::parse("{name}_{region}.psu").expect_err("unknown placeholder");
+    assert!(matches!(err, Error::ConfigError(message) if message.contains("{region}")));
+
+    assert!(OutputNamePattern::parse("{name.psu").is_err());
+    assert!(OutputNamePattern::parse("name}.psu").is_err());
+    assert!(OutputNamePattern::parse("").is_err());
+}
+
+#[test]
+fn render_splits_prefix_and_base() {
+    let pattern = OutputNamePattern::parse("{base}-{prefix}{name}.psu").expect("parse");
+    let cfg = Config {
+        name: "APP_Launcher".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        pattern.render(Path::new("."), &cfg).expect("render"),
+        "Launcher-APP_APP_Launcher.psu"
+    );
+
+    let cfg = Config {
+        name: "Plain Save".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        pattern.render(Path::new("."), &cfg).expect("render"),
+        "Plain Save-Plain Save.psu"
+    );
+}
+
+#[test]
+fn render_rejects_dot_file_names() {
+    let cfg = Config {
+        name: "APP_Launcher".to_string(),
+        ..Default::default()
+    };
+    for pattern in [".", "..", "{prefix}/{base}.psu"] {
+        let pattern = OutputNamePattern::parse(pattern).expect("parse");
+        assert!(matches!(
+            pattern.render(Path::new("."), &cfg),
+            Err(Error::ConfigError(_))
+        ));
+    }
+}
+
+#[test]
+fn batch_pack_names_outputs_from_pattern() {
+    let workspace = tempdir().expect("temp dir");
+    let root = workspace.path().join("projects");
+    let output_dir = workspace.path().join("out");
+    fs::create_dir_all(&output_dir).expect("create output dir");
+    write_project(&root, "one", "APP_ONE", Some("1.2"));
+    write_project(&root, "two", "EMU_TWO", None);
+    fs::create_dir_all(root.join("not_a_project")).expect("create folder");
+
+    let pattern = OutputNamePattern::parse("{prefix}{base}_v{version}.psu").expect("parse");
+    let results = pack_all_with_pattern(&root, &output_dir, &pattern);
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, root.join("one"));
+    let written = results[0].1.as_ref().expect("first project packs");
+    assert_eq!(written, &output_dir.join("APP_ONE_v1.2.psu"));
+    assert!(written.is_file());
+
+    assert_eq!(results[1].0, root.join("two"));
+    assert!(
+        matches!(&results[1].1, Err(Error::ConfigError(message)) if message.contains("title.cfg"))
+    );
+    assert!(!output_dir.join("EMU_TWO_v.psu").exists());
+}
+
+#[test]
+fn pack_all_reports_every_project() {
+    let workspace = tempdir().expect("temp dir");
+    let root = workspace.path().join("library");
+    write_project(&root, "a", "APP_First", None);
+    write_project(&root, "b", "APP_Second", None);
+    write_project(&root, "c", "bad/name", None);
+    fs::create_dir_all(root.join("d")).expect("create folder without psu.toml");
+    let output_dir = workspace.path().join("out");
+    fs::create_dir_all(&output_dir).expect("create output dir");
+
+    let results = pack_all(&root, &output_dir);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, root.join("a"));
+    assert_eq!(
+        results[0].1.as_ref().expect("first packs"),
+        &output_dir.join("APP_First.psu")
+    );
+    assert_eq!(
+        results[1].1.as_ref().expect("second packs"),
+        &output_dir.join("APP_Second.psu")
+    );
+    assert_eq!(results[2].0, root.join("c"));
+    assert!(results[2].1.is_err());
+    assert!(output_dir.join("APP_Second.psu").is_file());
+}
+
+#[test]
+fn pack_all_reports_an_unreadable_root() {
+    let workspace = tempdir().expect("temp dir");
+    let root = workspace.path().join("missing");
+
+    let results = pack_all(&root, workspace.path());
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, root);
+    assert!(matches!(results[0].1, Err(Error::IOError(_))));
+}
@@ -0,0 +1,45 @@
+use psu_packer::{color_config_to_rgba, rgba_to_color_config, ColorConfig, ColorFConfig};
+
+#[test]
+fn color_config_parses_six_and_eight_digit_hex() {
+    let opaque = ColorConfig::from_hex("#102030").expect("six digits");
+    assert_eq!(color_config_to_rgba(opaque), [0x10, 0x20, 0x30, 0xFF]);
+
+    let translucent = ColorConfig::from_hex("a0b0c080").expect("eight digits without #");
+    assert_eq!(color_config_to_rgba(translucent), [0xA0, 0xB0, 0xC0, 0x80]);
+}
+
+#[test]
+fn color_config_rejects_invalid_hex() {
+    let err = ColorConfig::from_hex("#12345").expect_err("five digits");
+    assert!(err.contains("6 or 8"), "unexpected message: {err}");
+    assert!(ColorConfig::from_hex("#1234567890").is_err());
+    assert!(ColorConfig::from_hex("#GG0000").is_err());
+    assert!(ColorConfig::from_hex("").is_err());
+}
+
+#[test]
+fn color_config_hex_round_trips_through_rgba() {
+    let rgba = [0x01, 0x7F, 0x80, 0xFE];
+    let color = rgba_to_color_config(rgba);
+    let hex = color.to_hex();
+    assert_eq!(hex, "#017F80FE");
+    let parsed = ColorConfig::from_hex(&hex).expect("round trip");
+    assert_eq!(color_config_to_rgba(parsed), rgba);
+}
+
+#[test]
+fn color_f_config_hex_clamps_components() {
+    let color = ColorFConfig {
+        r: 1.5,
+        g: -0.25,
+        b: 0.5,
+        a: 1.0,
+    };
+    assert_eq!(color.to_hex(), "#FF0080FF");
+
+    let parsed = ColorFConfig::from_hex("#FF000080").expect("parse");
+    assert_eq!(parsed.r, 1.0);
+    assert_eq!(parsed.g, 0.0);
+    assert!((parsed.a - 128.0 / 255.0).abs() < f32::EPSILON);
+}
@@ -0,0 +1,14 @@
+//! Builds small project folders for the integration tests.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Creates `workspace/project` holding `files`.
+pub fn project(workspace: &Path, files: &[(&str, &[u8])]) -> PathBuf {
+    let project = workspace.join("project");
+    fs::create_dir_all(&project).expect("create project dir");
+    for (name, contents) in files {
+        fs::write(project.join(name), contents).expect("write project file");
+    }
+    project
+}
@@ -0,0 +1,53 @@
+use std::fs;
+
+use chrono::NaiveDate;
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{pack_with_config, Config, Error};
+use tempfile::tempdir;
+
+#[test]
+fn built_config_packs_project() {
+    let tempdir = tempdir().expect("temp dir");
+    let project = tempdir.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    for name in ["BOOT.ELF", "DATA.BIN", "NOTES.BAK"] {
+        fs::write(project.join(name), name).expect("write file");
+    }
+    let timestamp = NaiveDate::from_ymd_opt(2024, 5, 6)
+        .unwrap()
+        .and_hms_opt(7, 8, 9)
+        .unwrap();
+
+    let config = Config::builder()
+        .name("APP_BUILDER")
+        .timestamp(timestamp)
+        .exclude(["*.BAK"])
+        .build()
+        .expect("valid config");
+    assert_eq!(config.name, "APP_BUILDER");
+    assert_eq!(config.include, None);
+
+    let output = tempdir.path().join("builder.psu");
+    pack_with_config(&project, &output, config).expect("pack built config");
+
+    let archive = PSU::new(fs::read(&output).expect("read psu"));
+    let files = archive
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        files
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["BOOT.ELF", "DATA.BIN"]
+    );
+    assert!(files.iter().all(|entry| entry.modified == timestamp));
+}
+
+#[test]
+fn build_rejects_invalid_name() {
+    let result = Config::builder().name("BAD/NAME").build();
+    assert!(matches!(result, Err(Error::NameError)));
+}
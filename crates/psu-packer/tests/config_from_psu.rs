@@ -0,0 +1,51 @@
+use std::fs;
+
+use chrono::NaiveDate;
+use psu_packer::{config_from_psu, pack_with_config, Config, IconSysConfig, IconSysFlags};
+use tempfile::tempdir;
+
+#[test]
+fn config_from_psu_recovers_the_packed_config() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    for name in ["BOOT.ELF", "DATA.BIN", "title.cfg"] {
+        fs::write(project.join(name), name.as_bytes()).expect("write project file");
+    }
+
+    let timestamp = NaiveDate::from_ymd_opt(2024, 5, 6)
+        .and_then(|date| date.and_hms_opt(7, 8, 10))
+        .expect("valid timestamp");
+    let icon_sys = IconSysConfig::new(IconSysFlags::new(0), "SAVEGAME", 4);
+    let config = Config {
+        name: "APP_SAVE".to_string(),
+        timestamp: Some(timestamp),
+        include: Some(vec![
+            "BOOT.ELF".to_string(),
+            "DATA.BIN".to_string(),
+            "title.cfg".to_string(),
+        ]),
+        icon_sys: Some(icon_sys.clone()),
+        ..Default::default()
+    };
+    fs::write(
+        project.join("psu.toml"),
+        config.to_toml_string().expect("serialize config"),
+    )
+    .expect("write psu.toml");
+
+    let output = workspace.path().join("APP_SAVE.psu");
+    pack_with_config(&project, &output, config.clone()).expect("pack succeeds");
+
+    let rebuilt =
+        config_from_psu(&fs::read(&output).expect("read packed psu")).expect("rebuild config");
+    assert_eq!(rebuilt.name, config.name);
+    assert_eq!(rebuilt.timestamp, config.timestamp);
+    assert_eq!(rebuilt.include, config.include);
+    assert_eq!(rebuilt.icon_sys, Some(icon_sys));
+}
+
+#[test]
+fn config_from_psu_rejects_garbage() {
+    assert!(config_from_psu(b"not a psu").is_err());
+}
@@ -0,0 +1,178 @@
+use std::fs;
+
+use psu_packer::{load_config, load_config_with_warnings, ConfigWarning};
+use tempfile::tempdir;
+
+#[test]
+fn load_config_with_warnings_reports_non_fatal_issues() {
+    let workspace = tempdir().expect("temp dir");
+    fs::write(
+        workspace.path().join("psu.toml"),
+        r#"[config]
+name = "APP_SAVE "
+compression = true
+
+[icon_sys]
+flags = 0
+title = "SAVE"
+linebreak_pos = 12
+light_colors = [
+    { r = 2.0, g = 0.5, b = 0.5, a = 1.0 },
+    { r = 0.5, g = 0.5, b = 0.5, a = 1.0 },
+    { r = 0.5, g = 0.5, b = 0.5, a = 1.0 },
+]
+
+[extra]
+value = 1
+"#,
+    )
+    .expect("write psu.toml");
+
+    let (config, warnings) = load_config_with_warnings(workspace.path()).expect("config loads");
+    assert_eq!(config.name, "APP_SAVE ");
+
+    assert!(warnings.contains(&ConfigWarning::UnknownKey {
+        section: "config".to_string(),
+        key: "compression".to_string(),
+    }));
+    assert!(warnings.contains(&ConfigWarning::UnknownKey {
+        section: String::new(),
+        key: "extra".to_string(),
+    }));
+    assert!(warnings.contains(&ConfigWarning::NameWhitespace));
+    assert!(warnings.iter().any(|warning| matches!(
+        warning,
+        ConfigWarning::IconSysOutOfRange { field, .. } if field == "linebreak_pos"
+    )));
+    assert!(warnings.iter().any(|warning| matches!(
+        warning,
+        ConfigWarning::IconSysOutOfRange { field, .. } if field == "light_colors"
+    )));
+
+    let plain = load_config(workspace.path()).expect("plain load succeeds");
+    assert_eq!(plain.name, config.name);
+}
+
+#[test]
+fn clean_config_has_no_warnings() {
+    let workspace = tempdir().expect("temp dir");
+    fs::write(
+        workspace.path().join("psu.toml"),
+        "[config]\nname = \"APP_SAVE\"\ninclude = [\"BOOT.ELF\"]\n",
+    )
+    .expect("write psu.toml");
+
+    let (_, warnings) = load_config_with_warnings(workspace.path()).expect("config loads");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn load_config_accepts_leading_byte_order_mark() {
+    let workspace = tempdir().expect("temp dir");
+    fs::write(
+        workspace.path().join("psu.toml"),
+        "\u{feff}[config]\nname = \"APP_BOM\"\n",
+    )
+    .expect("write psu.toml");
+
+    let config = load_config(workspace.path()).expect("BOM-prefixed config loads");
+    assert_eq!(config.name, "APP_BOM");
+}
+
+#[test]
+fn load_config_reports_byte_order_mark_inside_file() {
+    let workspace = tempdir().expect("temp dir");
+    fs::write(
+        workspace.path().join("psu.toml"),
+        "[config]\n\u{feff}name = \"APP_BOM\"\n",
+    )
+    .expect("write psu.toml");
+
+    let err = load_config(workspace.path()).expect_err("mid-file BOM is rejected");
+    assert!(
+        matches!(&err, psu_packer::Error::ConfigError(message) if message.contains("byte order mark on line 2")),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn names_rejected_by_the_old_check_load_with_a_note() {
+    for (name, characters) in [
+        ("APP_ZOO", "`Z`"),
+        ("APP_lazy", "`z`"),
+        ("SAVE9", "`9`"),
+        ("Zz9", "`z`, `Z`, `9`"),
+    ] {
+        let workspace = tempdir().expect("temp dir");
+        fs::write(
+            workspace.path().join("psu.toml"),
+            format!("[config]\nname = \"{name}\"\n"),
+        )
+        .expect("write psu.toml");
+
+        let (config, warnings) = load_config_with_warnings(workspace.path()).expect("config loads");
+        assert_eq!(config.name, name);
+        assert_eq!(
+            warnings,
+            vec![ConfigWarning::LegacyNameRejection {
+                characters: characters.to_string(),
+            }],
+            "{name}"
+        );
+
+        let output = workspace.path().join("out.psu");
+        psu_packer::pack_with_config(workspace.path(), &output, config)
+            .unwrap_or_else(|err| panic!("{name} should pack: {err}"));
+    }
+}
+
+#[test]
+fn sas_prefixes_are_not_reported_as_legacy_rejections() {
+    for (name, expected) in [
+        ("ZZY_Tools", None),
+        ("ZZZ_Cheats", None),
+        ("ZZY_Puzzle", Some("`z`")),
+    ] {
+        let workspace = tempdir().expect("temp dir");
+        fs::write(
+            workspace.path().join("psu.toml"),
+            format!("[config]\nname = \"{name}\"\n"),
+        )
+        .expect("write psu.toml");
+
+        let (_, warnings) = load_config_with_warnings(workspace.path()).expect("config loads");
+        let expected = expected
+            .map(|characters| ConfigWarning::LegacyNameRejection {
+                characters: characters.to_string(),
+            })
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(warnings, expected, "{name}");
+    }
+}
+
+#[test]
+fn names_in_both_include_and_exclude_are_reported() {
+    let workspace = tempdir().expect("temp dir");
+    fs::write(
+        workspace.path().join("psu.toml"),
+        r#"[config]
+name = "APP_SAVE"
+include = ["BOOT.ELF", "DATA.BIN"]
+exclude = ["data.bin", "NOTES.TXT"]
+"#,
+    )
+    .expect("write psu.toml");
+
+    let (_, warnings) = load_config_with_warnings(workspace.path()).expect("config loads");
+    assert_eq!(
+        warnings,
+        vec![ConfigWarning::IncludedAndExcluded {
+            name: "DATA.BIN".to_string()
+        }]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "DATA.BIN is listed in both include and exclude, so it will not be packed"
+    );
+}
@@ -0,0 +1,57 @@
+use std::fs;
+
+use psu_packer::{check_duplicate_file_names, pack_merged, pack_with_config, Config, Error};
+use tempfile::tempdir;
+
+fn config() -> Config {
+    Config {
+        name: "APP_DUPES".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn names_differing_only_in_case_are_reported() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir_all(&project).expect("create project");
+    fs::write(project.join("DATA.BIN"), b"upper").expect("write file");
+    fs::write(project.join("data.bin"), b"lower").expect("write file");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write file");
+
+    let err = check_duplicate_file_names(&project, &[], &config()).expect_err("duplicate");
+    let Error::ConfigError(message) = err else {
+        panic!("expected a config error");
+    };
+    assert!(message.contains("DATA.BIN") && message.contains("data.bin"));
+    assert!(!message.contains("BOOT.ELF"));
+
+    let output = workspace.path().join("out.psu");
+    assert!(matches!(
+        pack_with_config(&project, &output, config()),
+        Err(Error::ConfigError(_))
+    ));
+    assert!(!output.exists());
+}
+
+#[test]
+fn merged_folders_resolve_case_insensitive_conflicts() {
+    let workspace = tempdir().expect("temp dir");
+    let primary = workspace.path().join("primary");
+    let secondary = workspace.path().join("secondary");
+    fs::create_dir_all(&primary).expect("create primary");
+    fs::create_dir_all(&secondary).expect("create secondary");
+    fs::write(primary.join("DATA.BIN"), b"primary").expect("write file");
+    fs::write(secondary.join("data.bin"), b"secondary").expect("write file");
+
+    let secondaries = [secondary];
+    check_duplicate_file_names(&primary, &secondaries, &config()).expect("primary wins");
+    pack_merged(
+        &primary,
+        &secondaries,
+        &workspace.path().join("out.psu"),
+        config(),
+        Default::default(),
+    )
+    .expect("pack merged");
+}
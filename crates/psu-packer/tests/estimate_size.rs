@@ -0,0 +1,37 @@
+use std::fs;
+
+use psu_packer::{estimate_packed_size, pack_with_config, Config};
+use tempfile::tempdir;
+
+#[test]
+fn estimate_matches_packed_output_length() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+
+    fs::write(project.join("EMPTY.BIN"), b"").expect("write EMPTY.BIN");
+    fs::write(project.join("SMALL.BIN"), vec![1u8; 10]).expect("write SMALL.BIN");
+    fs::write(project.join("PAGE.BIN"), vec![2u8; 1024]).expect("write PAGE.BIN");
+    fs::write(project.join("LARGE.BIN"), vec![3u8; 3000]).expect("write LARGE.BIN");
+
+    let config = Config {
+        name: "Size Estimate".to_string(),
+        timestamp: None,
+        include: None,
+        exclude: None,
+        icon_sys: None,
+        ..Default::default()
+    };
+
+    let estimate = estimate_packed_size(&project, &config).expect("estimate succeeds");
+    // Three directory headers plus four file headers and their aligned contents.
+    assert_eq!(estimate, 512 * 3 + 512 * 4 + 1024 + 1024 + 3072);
+
+    let output = workspace.path().join("estimate.psu");
+    pack_with_config(&project, &output, config).expect("pack succeeds");
+    let actual = fs::metadata(&output).expect("packed psu metadata").len();
+    assert!(
+        estimate.abs_diff(actual) < 1024,
+        "estimate {estimate} differs from packed length {actual}"
+    );
+}
@@ -31,6 +31,7 @@ fn psu_toml_is_never_packed() {
         include: None,
         exclude: None,
         icon_sys: None,
+        ..Default::default()
     };
     let output_include_all = project.join("include-all.psu");
     pack_with_config(project, &output_include_all, config_include_all)
@@ -51,6 +52,7 @@ fn psu_toml_is_never_packed() {
         include: Some(vec!["DATA.BIN".to_string(), "psu.toml".to_string()]),
         exclude: None,
         icon_sys: None,
+        ..Default::default()
     };
     let output_with_explicit = project.join("explicit.psu");
     pack_with_config(project, &output_with_explicit, config_with_explicit_include)
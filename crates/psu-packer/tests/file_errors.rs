@@ -0,0 +1,77 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use psu_packer::{
+    pack_with_config, pack_with_config_and_metadata_reader, Config, Error, FileTimes,
+    FsMetadataReader, MetadataReader,
+};
+use tempfile::tempdir;
+
+fn config() -> Config {
+    Config {
+        name: "APP_ERRORS".to_string(),
+        ..Default::default()
+    }
+}
+
+struct DeniedMetadata(&'static str);
+
+impl MetadataReader for DeniedMetadata {
+    fn file_times(&self, path: &Path) -> io::Result<FileTimes> {
+        if path.file_name().and_then(|name| name.to_str()) == Some(self.0) {
+            return Err(io::Error::from(io::ErrorKind::PermissionDenied));
+        }
+        FsMetadataReader.file_times(path)
+    }
+}
+
+#[test]
+fn metadata_error_names_the_file() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("A.BIN"), b"a").expect("write file");
+    fs::write(project.join("SECRET.BIN"), b"secret").expect("write file");
+
+    let err = pack_with_config_and_metadata_reader(
+        &project,
+        &workspace.path().join("out.psu"),
+        config(),
+        &DeniedMetadata("SECRET.BIN"),
+    )
+    .expect_err("metadata error");
+
+    match &err {
+        Error::FileError { path, source } => {
+            assert_eq!(path, &project.join("SECRET.BIN"));
+            assert_eq!(source.kind(), io::ErrorKind::PermissionDenied);
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+    assert!(err.to_string().contains("SECRET.BIN"));
+}
+
+#[cfg(unix)]
+#[test]
+fn unreadable_file_error_names_the_file() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    let secret = project.join("SECRET.BIN");
+    fs::write(&secret, b"secret").expect("write file");
+    fs::set_permissions(&secret, fs::Permissions::from_mode(0o000)).expect("chmod");
+    if fs::read(&secret).is_ok() {
+        // Running with privileges that ignore file modes; nothing to test.
+        return;
+    }
+
+    let err = pack_with_config(&project, &workspace.path().join("out.psu"), config())
+        .expect_err("unreadable file");
+    assert!(
+        matches!(&err, Error::FileError { path, .. } if *path == secret),
+        "unexpected error: {err}"
+    );
+}
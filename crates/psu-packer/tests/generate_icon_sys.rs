@@ -0,0 +1,58 @@
+use std::fs;
+
+use ps2_filetypes::PSU;
+use psu_packer::{load_config, pack_with_config, Config, IconSysConfig, IconSysFlags};
+use tempfile::tempdir;
+
+#[test]
+fn existing_icon_sys_is_packed_as_is_when_generation_is_off() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("DATA.BIN"), b"payload").expect("write DATA.BIN");
+
+    let on_disk = IconSysConfig::new(IconSysFlags::new(0), "On disk", 7)
+        .to_bytes()
+        .expect("encode on-disk icon.sys");
+    fs::write(project.join("icon.sys"), &on_disk).expect("write icon.sys");
+
+    let config = Config {
+        name: "APP_External".to_string(),
+        include: Some(vec!["DATA.BIN".to_string(), "icon.sys".to_string()]),
+        icon_sys: Some(IconSysConfig::new(IconSysFlags::new(0), "Generated", 9)),
+        generate_icon_sys: false,
+        ..Default::default()
+    };
+    let output = workspace.path().join("APP_External.psu");
+    pack_with_config(&project, &output, config).expect("pack succeeds");
+
+    let psu = PSU::new(fs::read(&output).expect("read packed psu"));
+    let icon_sys = psu
+        .entries
+        .iter()
+        .find(|entry| entry.name == "icon.sys")
+        .expect("icon.sys packed");
+    assert_eq!(icon_sys.contents.as_deref(), Some(on_disk.as_slice()));
+}
+
+#[test]
+fn generate_icon_sys_defaults_on_and_round_trips() {
+    assert!(Config::default().generate_icon_sys);
+
+    let workspace = tempdir().expect("temp dir");
+    let config = Config {
+        name: "APP_External".to_string(),
+        generate_icon_sys: false,
+        ..Default::default()
+    };
+    let toml = config.to_toml_string().expect("serialize config");
+    assert!(toml.contains("generate_icon_sys = false"));
+    fs::write(workspace.path().join("psu.toml"), toml).expect("write psu.toml");
+
+    let loaded = load_config(workspace.path()).expect("load psu.toml");
+    assert!(!loaded.generate_icon_sys);
+    assert!(!Config::default()
+        .to_toml_string()
+        .expect("serialize default config")
+        .contains("generate_icon_sys"));
+}
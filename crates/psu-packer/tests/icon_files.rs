@@ -0,0 +1,56 @@
+use std::fs;
+
+use psu_packer::{missing_icon_file_warnings, Config, ConfigWarning, IconSysConfig, IconSysFlags};
+use tempfile::tempdir;
+
+#[test]
+fn icon_sys_naming_an_unpacked_icon_is_reported() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("COPY.ICN"), b"icon").expect("write COPY.ICN");
+    fs::write(project.join("del.icn"), b"icon").expect("write del.icn");
+
+    let mut icon_sys = IconSysConfig::new(IconSysFlags::new(0), "SAVE", 4)
+        .build_icon_sys()
+        .expect("build icon.sys");
+    icon_sys.icon_file = "list.icn".to_string();
+    icon_sys.icon_copy_file = "copy.icn".to_string();
+    icon_sys.icon_delete_file = "del.icn".to_string();
+    fs::write(
+        project.join("ICON.SYS"),
+        icon_sys.to_bytes().expect("serialize icon.sys"),
+    )
+    .expect("write ICON.SYS");
+
+    let config = Config {
+        name: "APP_SAVE".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(
+        missing_icon_file_warnings(&project, &config),
+        vec![ConfigWarning::MissingIconFile {
+            name: "list.icn".to_string()
+        }]
+    );
+
+    fs::write(project.join("LIST.ICN"), b"icon").expect("write LIST.ICN");
+    assert!(missing_icon_file_warnings(&project, &config).is_empty());
+}
+
+#[test]
+fn generated_icon_sys_is_not_checked_against_the_packed_files() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    for name in ["list.icn", "copy.icn", "del.icn"] {
+        fs::write(project.join(name), b"icon").expect("write icon");
+    }
+
+    let config = Config {
+        name: "APP_SAVE".to_string(),
+        icon_sys: Some(IconSysConfig::new(IconSysFlags::new(0), "SAVE", 4)),
+        ..Default::default()
+    };
+    assert!(missing_icon_file_warnings(&project, &config).is_empty());
+}
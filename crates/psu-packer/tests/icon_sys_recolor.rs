@@ -0,0 +1,81 @@
+use std::fs;
+
+use ps2_filetypes::IconSys;
+use psu_packer::{
+    color_to_rgba, recolor_icon_sys_projects, IconSysConfig, IconSysFlags, IconSysPresetFile,
+    ICON_SYS_PRESETS,
+};
+use tempfile::tempdir;
+
+fn write_icon_sys(folder: &std::path::Path, title: &str, flags: u16) {
+    fs::create_dir_all(folder).expect("create project");
+    let config = IconSysConfig {
+        flags: IconSysFlags::new(flags),
+        title: title.to_string(),
+        linebreak_pos: None,
+        preset: None,
+        background_transparency: None,
+        background_colors: None,
+        light_directions: None,
+        light_colors: None,
+        ambient_color: None,
+    };
+    let mut icon_sys = config.build_icon_sys().expect("build icon.sys");
+    icon_sys.icon_file = "custom.icn".to_string();
+    let bytes = icon_sys.to_bytes().expect("encode icon.sys");
+    fs::write(folder.join("icon.sys"), bytes).expect("write icon.sys");
+}
+
+#[test]
+fn recolor_updates_colors_and_keeps_titles() {
+    let workspace = tempdir().expect("temp dir");
+    write_icon_sys(&workspace.path().join("APP_ONE"), "First Save", 0);
+    write_icon_sys(&workspace.path().join("APP_TWO"), "Second Save", 1);
+    fs::create_dir_all(workspace.path().join("APP_EMPTY")).expect("create project");
+    fs::write(workspace.path().join("notes.txt"), b"not a project").expect("write file");
+
+    let preset = ICON_SYS_PRESETS
+        .iter()
+        .find(|preset| preset.id == "cool_blue")
+        .map(IconSysPresetFile::from)
+        .expect("cool blue preset");
+    let report = recolor_icon_sys_projects(workspace.path(), &preset).expect("recolor");
+
+    assert_eq!(
+        report.updated,
+        vec![
+            workspace.path().join("APP_ONE"),
+            workspace.path().join("APP_TWO")
+        ]
+    );
+    assert_eq!(
+        report.missing_icon_sys,
+        vec![workspace.path().join("APP_EMPTY")]
+    );
+    assert!(report.failed.is_empty());
+
+    let bytes = fs::read(workspace.path().join("APP_TWO/icon.sys")).expect("read icon.sys");
+    let icon_sys = IconSys::new(bytes);
+    assert_eq!(icon_sys.title, "Second Save");
+    assert_eq!(icon_sys.flags, 1);
+    assert_eq!(icon_sys.icon_file, "custom.icn");
+    assert_eq!(
+        color_to_rgba(icon_sys.background_colors[0]),
+        psu_packer::color_config_to_rgba(preset.background_colors[0])
+    );
+}
+
+#[test]
+fn recolor_reports_unreadable_icon_sys() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("APP_BROKEN");
+    fs::create_dir_all(&project).expect("create project");
+    fs::write(project.join("icon.sys"), b"PS2D").expect("write truncated icon.sys");
+
+    let preset = IconSysPresetFile::from(&ICON_SYS_PRESETS[0]);
+    let report = recolor_icon_sys_projects(workspace.path(), &preset).expect("recolor");
+
+    assert!(report.updated.is_empty());
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, project);
+}
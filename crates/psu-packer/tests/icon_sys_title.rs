@@ -0,0 +1,99 @@
+use std::fs;
+
+use psu_packer::{pack_with_config, Config, Error, IconSysConfig, IconSysFlags};
+use tempfile::tempdir;
+
+fn icon_sys_with_title(title: &str, linebreak_pos: u16) -> IconSysConfig {
+    IconSysConfig {
+        flags: IconSysFlags::new(0),
+        title: title.to_string(),
+        linebreak_pos: Some(linebreak_pos),
+        preset: None,
+        background_transparency: None,
+        background_colors: None,
+        light_directions: None,
+        light_colors: None,
+        ambient_color: None,
+    }
+}
+
+#[test]
+fn unencodable_second_line_is_reported_by_name() {
+    let icon_sys = icon_sys_with_title("SAVEDATA😀", 8);
+    let err = icon_sys.to_bytes().expect_err("emoji cannot be encoded");
+    match err {
+        Error::ConfigError(message) => {
+            assert!(message.contains('😀'), "unexpected message: {message}");
+            assert!(message.contains("U+1F600"), "unexpected message: {message}");
+            assert!(
+                message.contains("character 9"),
+                "unexpected message: {message}"
+            );
+        }
+        other => panic!("expected a config error, got {other:?}"),
+    }
+}
+
+#[test]
+fn packing_an_unencodable_title_fails_without_writing_output() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+
+    let config = Config {
+        name: "APP_TITLE".to_string(),
+        icon_sys: Some(icon_sys_with_title("GoodLine🎮", 8)),
+        ..Default::default()
+    };
+    let output = workspace.path().join("title.psu");
+    let err = pack_with_config(&project, &output, config).expect_err("pack must fail");
+
+    assert!(matches!(err, Error::ConfigError(ref message) if message.contains('🎮')));
+    assert!(!output.exists());
+    assert!(!project.join("icon.sys").exists());
+}
+
+#[test]
+fn packing_embeds_generated_icon_sys_without_touching_the_folder() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+    let hand_crafted = b"PS2D hand-crafted icon.sys".to_vec();
+    fs::write(project.join("icon.sys"), &hand_crafted).expect("write icon.sys");
+
+    let icon_sys = icon_sys_with_title("GENERATED", 9);
+    let generated = icon_sys.to_bytes().expect("encode icon.sys");
+    let config = Config {
+        name: "APP_TITLE".to_string(),
+        icon_sys: Some(icon_sys),
+        ..Default::default()
+    };
+    let output = workspace.path().join("title.psu");
+    pack_with_config(&project, &output, config).expect("pack");
+
+    assert_eq!(
+        fs::read(project.join("icon.sys")).expect("read icon.sys"),
+        hand_crafted
+    );
+    let archive = ps2_filetypes::PSU::new(fs::read(&output).expect("read psu"));
+    assert_eq!(
+        archive.entry_contents("icon.sys"),
+        Some(generated.as_slice())
+    );
+
+    fs::remove_file(project.join("icon.sys")).expect("remove icon.sys");
+    let config = Config {
+        name: "APP_TITLE".to_string(),
+        icon_sys: Some(icon_sys_with_title("GENERATED", 9)),
+        ..Default::default()
+    };
+    pack_with_config(&project, &output, config).expect("pack without icon.sys");
+    assert!(!project.join("icon.sys").exists());
+    let archive = ps2_filetypes::PSU::new(fs::read(&output).expect("read psu"));
+    assert_eq!(
+        archive.entry_contents("icon.sys"),
+        Some(generated.as_slice())
+    );
+}
@@ -0,0 +1,137 @@
+use std::fs;
+use std::path::Path;
+
+use ps2_filetypes::{IconSys, PSU};
+use psu_packer::{load_config, load_config_with_warnings, pack_with_config, Error};
+use tempfile::tempdir;
+
+const VARIANT_TOML: &str = r#"[config]
+name = "APP_VARIANTS"
+
+[icon_sys]
+default_variant = "jp"
+flags = 0
+title = "Base"
+
+[icon_sys.us]
+flags = 0
+title = "Americas"
+
+[icon_sys.jp]
+flags = 0
+title = "Japan"
+"#;
+
+fn write_project(root: &Path, psu_toml: &str) -> std::path::PathBuf {
+    let project = root.join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("psu.toml"), psu_toml).expect("write psu.toml");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+    project
+}
+
+fn packed_icon_sys_title(output: &Path) -> String {
+    let archive = PSU::new(fs::read(output).expect("read packed psu"));
+    let entry = archive
+        .entries
+        .iter()
+        .find(|entry| entry.name == "icon.sys")
+        .expect("icon.sys packed");
+    IconSys::new(entry.contents.clone().expect("icon.sys contents")).title
+}
+
+#[test]
+fn variants_are_loaded_alongside_base_icon_sys() {
+    let workspace = tempdir().expect("temp dir");
+    let project = write_project(workspace.path(), VARIANT_TOML);
+
+    let (config, warnings) = load_config_with_warnings(&project).expect("load psu.toml");
+    assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
+    assert_eq!(
+        config.icon_sys.as_ref().map(|cfg| cfg.title.as_str()),
+        Some("Base")
+    );
+    assert_eq!(
+        config.icon_sys_variants.keys().collect::<Vec<_>>(),
+        vec!["jp", "us"]
+    );
+    assert_eq!(config.icon_sys_variant.as_deref(), Some("jp"));
+    assert_eq!(
+        config
+            .selected_icon_sys()
+            .expect("selected")
+            .map(|cfg| cfg.title.as_str()),
+        Some("Japan")
+    );
+}
+
+#[test]
+fn packing_embeds_only_the_selected_variant() {
+    let workspace = tempdir().expect("temp dir");
+    let project = write_project(workspace.path(), VARIANT_TOML);
+
+    let mut config = load_config(&project).expect("load psu.toml");
+    let output = workspace.path().join("jp.psu");
+    config.icon_sys_variant = Some("jp".to_string());
+    pack_with_config(&project, &output, config).expect("pack jp");
+    assert_eq!(packed_icon_sys_title(&output), "Japan");
+
+    let mut config = load_config(&project).expect("load psu.toml");
+    config.icon_sys_variant = None;
+    let output = workspace.path().join("base.psu");
+    pack_with_config(&project, &output, config).expect("pack base");
+    assert_eq!(packed_icon_sys_title(&output), "Base");
+}
+
+#[test]
+fn variants_round_trip_through_toml() {
+    let workspace = tempdir().expect("temp dir");
+    let project = write_project(workspace.path(), VARIANT_TOML);
+    let config = load_config(&project).expect("load psu.toml");
+
+    let serialized = config.to_toml_string().expect("serialize");
+    fs::write(project.join("psu.toml"), &serialized).expect("rewrite psu.toml");
+    let reloaded = load_config(&project).expect("reload psu.toml");
+
+    assert_eq!(reloaded.icon_sys_variant.as_deref(), Some("jp"));
+    assert_eq!(
+        reloaded.icon_sys.map(|cfg| cfg.title),
+        Some("Base".to_string())
+    );
+    assert_eq!(
+        reloaded
+            .icon_sys_variants
+            .iter()
+            .map(|(label, cfg)| (label.as_str(), cfg.title.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("jp", "Japan"), ("us", "Americas")]
+    );
+}
+
+#[test]
+fn variants_without_base_icon_sys_are_supported() {
+    let workspace = tempdir().expect("temp dir");
+    let project = write_project(
+        workspace.path(),
+        "[config]\nname = \"APP_VARIANTS\"\n\n[icon_sys]\ndefault_variant = \"us\"\n\n[icon_sys.us]\nflags = 0\ntitle = \"Americas\"\n",
+    );
+
+    let config = load_config(&project).expect("load psu.toml");
+    assert!(config.icon_sys.is_none());
+    let output = workspace.path().join("us.psu");
+    pack_with_config(&project, &output, config).expect("pack us");
+    assert_eq!(packed_icon_sys_title(&output), "Americas");
+}
+
+#[test]
+fn unknown_variant_is_rejected_at_pack_time() {
+    let workspace = tempdir().expect("temp dir");
+    let project = write_project(workspace.path(), VARIANT_TOML);
+
+    let mut config = load_config(&project).expect("load psu.toml");
+    config.icon_sys_variant = Some("eu".to_string());
+    let output = workspace.path().join("eu.psu");
+    let err = pack_with_config(&project, &output, config).expect_err("unknown variant");
+    assert!(matches!(err, Error::ConfigError(message) if message.contains("eu")));
+    assert!(!output.exists());
+}
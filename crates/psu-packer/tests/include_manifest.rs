@@ -0,0 +1,43 @@
+use std::fs;
+
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{load_config, pack_with_config};
+use tempfile::tempdir;
+
+#[test]
+fn manifest_lists_the_files_to_pack() {
+    let tempdir = tempdir().expect("temp dir");
+    let project = tempdir.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    for name in ["BOOT.ELF", "DATA.BIN", "NOTES.TXT"] {
+        fs::write(project.join(name), name).expect("write file");
+    }
+    fs::write(
+        project.join("manifest.txt"),
+        "# files to ship\nBOOT.ELF\n\n  DATA.BIN  \n",
+    )
+    .expect("write manifest");
+    fs::write(
+        project.join("psu.toml"),
+        "[config]\nname = \"APP_MANIFEST\"\ninclude_from_manifest = \"manifest.txt\"\n",
+    )
+    .expect("write psu.toml");
+
+    let config = load_config(&project).expect("load config");
+    assert_eq!(
+        config.include_from_manifest.as_deref(),
+        Some(std::path::Path::new("manifest.txt"))
+    );
+
+    let output = tempdir.path().join("manifest.psu");
+    pack_with_config(&project, &output, config).expect("pack with manifest");
+
+    let archive = PSU::new(fs::read(&output).expect("read psu"));
+    let names = archive
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+        .map(|entry| entry.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["BOOT.ELF", "DATA.BIN"]);
+}
@@ -0,0 +1,52 @@
+use std::fs;
+
+use chrono::NaiveDate;
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{list_psu_entries, pack_with_config, Config};
+use tempfile::tempdir;
+
+#[test]
+fn listing_matches_packed_entries() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), vec![1u8; 1500]).expect("write BOOT.ELF");
+    fs::write(project.join("DATA.BIN"), vec![2u8; 10]).expect("write DATA.BIN");
+
+    let timestamp = NaiveDate::from_ymd_opt(2024, 10, 10)
+        .and_then(|date| date.and_hms_opt(10, 30, 0))
+        .expect("timestamp");
+    let output = workspace.path().join("APP_LIST.psu");
+    let config = Config {
+        name: "APP_LIST".to_string(),
+        timestamp: Some(timestamp),
+        ..Default::default()
+    };
+    pack_with_config(&project, &output, config).expect("pack");
+
+    let bytes = fs::read(&output).expect("read psu");
+    let listed = list_psu_entries(&bytes).expect("list entries");
+    let packed = PSU::try_new(bytes.clone()).expect("parse psu").entries;
+
+    assert_eq!(listed.len(), packed.len());
+    for (listed, packed) in listed.iter().zip(&packed) {
+        assert_eq!(listed.name, packed.name);
+        assert_eq!(listed.size, packed.size);
+        assert_eq!(listed.created, packed.created);
+        assert_eq!(listed.modified, packed.modified);
+        assert_eq!(
+            matches!(listed.kind, PSUEntryKind::File),
+            matches!(packed.kind, PSUEntryKind::File)
+        );
+    }
+    assert_eq!(
+        listed
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["APP_LIST", ".", "..", "BOOT.ELF", "DATA.BIN"]
+    );
+
+    // Cut off inside the BOOT.ELF data.
+    assert!(list_psu_entries(&bytes[..3000]).is_err());
+}
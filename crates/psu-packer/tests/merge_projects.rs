@@ -0,0 +1,78 @@
+use std::fs;
+
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{pack_merged, Config, Error, MergeConflictPolicy};
+use tempfile::tempdir;
+
+fn config() -> Config {
+    Config {
+        name: "APP_MERGED".to_string(),
+        exclude: Some(vec!["psu.toml".to_string()]),
+        ..Default::default()
+    }
+}
+
+fn packed_files(path: &std::path::Path) -> Vec<(String, Vec<u8>)> {
+    let psu = PSU::new(fs::read(path).expect("read psu"));
+    psu.entries()
+        .into_iter()
+        .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+        .map(|entry| (entry.name, entry.contents.unwrap_or_default()))
+        .collect()
+}
+
+#[test]
+fn merged_pack_contains_union_with_primary_winning() {
+    let workspace = tempdir().expect("temp dir");
+    let primary = workspace.path().join("primary");
+    let secondary = workspace.path().join("secondary");
+    fs::create_dir_all(&primary).expect("create primary");
+    fs::create_dir_all(&secondary).expect("create secondary");
+    fs::write(primary.join("BOOT.ELF"), b"primary boot").expect("write file");
+    fs::write(primary.join("psu.toml"), b"[config]").expect("write file");
+    fs::write(secondary.join("BOOT.ELF"), b"secondary boot").expect("write file");
+    fs::write(secondary.join("DATA.BIN"), b"data").expect("write file");
+    fs::write(secondary.join("psu.toml"), b"[config]").expect("write file");
+
+    let output = workspace.path().join("merged.psu");
+    pack_merged(
+        &primary,
+        &[secondary],
+        &output,
+        config(),
+        MergeConflictPolicy::PrimaryWins,
+    )
+    .expect("pack merged");
+
+    assert_eq!(
+        packed_files(&output),
+        vec![
+            ("BOOT.ELF".to_string(), b"primary boot".to_vec()),
+            ("DATA.BIN".to_string(), b"data".to_vec()),
+        ]
+    );
+}
+
+#[test]
+fn merged_pack_errors_on_conflict_when_requested() {
+    let workspace = tempdir().expect("temp dir");
+    let primary = workspace.path().join("primary");
+    let secondary = workspace.path().join("secondary");
+    fs::create_dir_all(&primary).expect("create primary");
+    fs::create_dir_all(&secondary).expect("create secondary");
+    fs::write(primary.join("BOOT.ELF"), b"primary").expect("write file");
+    fs::write(secondary.join("BOOT.ELF"), b"secondary").expect("write file");
+
+    let output = workspace.path().join("merged.psu");
+    let err = pack_merged(
+        &primary,
+        &[secondary],
+        &output,
+        config(),
+        MergeConflictPolicy::Error,
+    )
+    .expect_err("conflict");
+
+    assert!(matches!(err, Error::ConfigError(message) if message.contains("BOOT.ELF")));
+    assert!(!output.exists());
+}
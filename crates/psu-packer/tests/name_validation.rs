@@ -0,0 +1,80 @@
+use std::fs;
+
+use psu_packer::{
+    pack_merged, pack_with_config, plan_merged_pack, Config, Error, MergeConflictPolicy,
+};
+use tempfile::tempdir;
+
+fn pack_named(name: &str) -> Result<(), Error> {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("DATA.BIN"), b"payload").expect("write DATA.BIN");
+
+    let config = Config {
+        name: name.to_string(),
+        timestamp: None,
+        include: None,
+        exclude: None,
+        icon_sys: None,
+        ..Default::default()
+    };
+    pack_with_config(&project, &workspace.path().join("out.psu"), config)
+}
+
+#[test]
+fn names_accept_range_endpoints() {
+    pack_named("APP_zZ9").expect("z, Z and 9 are valid name characters");
+}
+
+#[test]
+fn names_may_contain_dots() {
+    pack_named("APP_Game v1.2").expect("dots are valid name characters");
+}
+
+#[test]
+fn names_reject_other_punctuation() {
+    assert!(matches!(pack_named("APP_Game!"), Err(Error::NameError)));
+}
+
+#[test]
+fn names_reject_file_matching_root_directory() {
+    assert!(matches!(
+        pack_named("data.bin"),
+        Err(Error::ReservedEntryName(name)) if name == "DATA.BIN"
+    ));
+}
+
+#[test]
+fn merged_folders_reject_file_matching_root_directory() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    let secondary = workspace.path().join("secondary");
+    fs::create_dir(&project).expect("create project dir");
+    fs::create_dir(&secondary).expect("create secondary dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    fs::write(secondary.join("APP_MERGED"), b"payload").expect("write APP_MERGED");
+    let secondaries = [secondary];
+
+    let config = Config {
+        name: "APP_MERGED".to_string(),
+        ..Default::default()
+    };
+    assert!(matches!(
+        plan_merged_pack(&project, &secondaries, &config, MergeConflictPolicy::default()),
+        Err(Error::ReservedEntryName(name)) if name == "APP_MERGED"
+    ));
+
+    let output = workspace.path().join("out.psu");
+    assert!(matches!(
+        pack_merged(
+            &project,
+            &secondaries,
+            &output,
+            config,
+            MergeConflictPolicy::default()
+        ),
+        Err(Error::ReservedEntryName(name)) if name == "APP_MERGED"
+    ));
+    assert!(!output.exists());
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::Path;
+
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{file_name_matches, pack_with_config, Config};
+use tempfile::tempdir;
+
+fn packed_file_names(output: &Path) -> Vec<String> {
+    let archive = PSU::new(fs::read(output).expect("read packed psu"));
+    archive
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.kind, PSUEntryKind::File))
+        .map(|entry| entry.name.clone())
+        .collect()
+}
+
+fn pack(project: &Path, output: &Path, include: Option<&[&str]>, exclude: Option<&[&str]>) {
+    let to_vec = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+    let config = Config {
+        name: "Pattern Save".to_string(),
+        timestamp: None,
+        include: include.map(to_vec),
+        exclude: exclude.map(to_vec),
+        icon_sys: None,
+        ..Default::default()
+    };
+    pack_with_config(project, output, config).expect("pack succeeds");
+}
+
+#[test]
+fn matcher_supports_wildcards_and_ignores_case() {
+    assert!(file_name_matches("*.tmp", "scratch.TMP"));
+    assert!(file_name_matches("DATA?.BIN", "data1.bin"));
+    assert!(!file_name_matches("DATA?.BIN", "DATA10.BIN"));
+    assert!(file_name_matches("Thumbs.db", "THUMBS.DB"));
+    assert!(file_name_matches("*", "anything"));
+    assert!(!file_name_matches("*.tmp", "tmp.bin"));
+}
+
+#[test]
+fn exclude_accepts_glob_patterns() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+
+    fs::write(project.join("KEEP.BIN"), b"keep").expect("write KEEP.BIN");
+    fs::write(project.join("a.tmp"), b"tmp").expect("write a.tmp");
+    fs::write(project.join("B.TMP"), b"tmp").expect("write B.TMP");
+    fs::write(project.join("DATA1.BIN"), b"one").expect("write DATA1.BIN");
+    fs::write(project.join("DATA10.BIN"), b"ten").expect("write DATA10.BIN");
+    fs::write(project.join("THUMBS.DB"), b"thumbs").expect("write THUMBS.DB");
+
+    let output = workspace.path().join("excluded.psu");
+    pack(
+        &project,
+        &output,
+        None,
+        Some(&["*.tmp", "DATA?.BIN", "Thumbs.db"]),
+    );
+
+    assert_eq!(packed_file_names(&output), vec!["DATA10.BIN", "KEEP.BIN"]);
+}
+
+#[test]
+fn include_accepts_glob_patterns() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    fs::write(project.join("DATA1.BIN"), b"one").expect("write DATA1.BIN");
+    fs::write(project.join("DATA2.BIN"), b"two").expect("write DATA2.BIN");
+    fs::write(project.join("NOTES.TXT"), b"notes").expect("write NOTES.TXT");
+
+    let output = workspace.path().join("included.psu");
+    pack(&project, &output, Some(&["boot.elf", "DATA?.BIN"]), None);
+
+    assert_eq!(
+        packed_file_names(&output),
+        vec!["BOOT.ELF", "DATA1.BIN", "DATA2.BIN"]
+    );
+}
@@ -0,0 +1,230 @@
+use std::fs;
+
+use chrono::NaiveDate;
+use ps2_filetypes::{PSUWriter, PSU};
+use psu_packer::{
+    pack_merged, pack_with_config, pending_changes, pending_merged_changes,
+    update_merged_psu_in_place, update_psu_in_place, ChangeKind, Config, Error,
+    MergeConflictPolicy, PendingChange,
+};
+use tempfile::tempdir;
+
+fn change(name: &str, kind: ChangeKind) -> PendingChange {
+    PendingChange {
+        name: name.to_string(),
+        kind,
+    }
+}
+
+#[test]
+fn reports_added_removed_and_modified_files() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+    fs::write(project.join("OLD.BIN"), b"old").expect("write OLD.BIN");
+
+    let timestamp = NaiveDate::from_ymd_opt(2024, 10, 10)
+        .and_then(|date| date.and_hms_opt(10, 30, 0))
+        .expect("timestamp");
+    let config = Config {
+        name: "APP_DIFF".to_string(),
+        timestamp: Some(timestamp),
+        ..Default::default()
+    };
+    let output = workspace.path().join("APP_DIFF.psu");
+    pack_with_config(&project, &output, config.clone()).expect("pack");
+
+    assert!(pending_changes(&project, &config, &output)
+        .expect("compare")
+        .is_empty());
+
+    fs::write(project.join("DATA.BIN"), b"DATA").expect("rewrite DATA.BIN");
+    fs::remove_file(project.join("OLD.BIN")).expect("remove OLD.BIN");
+    fs::write(project.join("NEW.BIN"), b"new").expect("write NEW.BIN");
+
+    let changes = pending_changes(&project, &config, &output).expect("compare");
+    assert_eq!(
+        changes,
+        vec![
+            change("DATA.BIN", ChangeKind::Modified),
+            change("NEW.BIN", ChangeKind::Added),
+            change("OLD.BIN", ChangeKind::Removed),
+        ]
+    );
+}
+
+#[test]
+fn timestamp_changes_count_as_modifications() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+
+    let packed_at = NaiveDate::from_ymd_opt(2024, 10, 10)
+        .and_then(|date| date.and_hms_opt(10, 30, 0))
+        .expect("timestamp");
+    let mut config = Config {
+        name: "APP_DIFF".to_string(),
+        timestamp: Some(packed_at),
+        ..Default::default()
+    };
+    let output = workspace.path().join("APP_DIFF.psu");
+    pack_with_config(&project, &output, config.clone()).expect("pack");
+
+    config.timestamp = packed_at.checked_add_signed(chrono::Duration::hours(1));
+    assert_eq!(
+        pending_changes(&project, &config, &output).expect("compare"),
+        vec![change("BOOT.ELF", ChangeKind::Modified)]
+    );
+}
+
+#[test]
+fn update_in_place_keeps_unchanged_entries() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+    fs::write(project.join("OLD.BIN"), b"old").expect("write OLD.BIN");
+
+    let timestamp = NaiveDate::from_ymd_opt(2024, 10, 10)
+        .and_then(|date| date.and_hms_opt(10, 30, 0))
+        .expect("timestamp");
+    let config = Config {
+        name: "APP_DIFF".to_string(),
+        timestamp: Some(timestamp),
+        ..Default::default()
+    };
+    let packed = workspace.path().join("packed.psu");
+    pack_with_config(&project, &packed, config.clone()).expect("pack");
+
+    // Give the original archive quirks a full repack would normalise: a non-sorted entry
+    // order and a non-zero sector.
+    let mut original = PSU::new(fs::read(&packed).expect("read psu"));
+    original.entries.swap(3, 5);
+    original.entries[5].sector = 7;
+    let original_path = workspace.path().join("original.psu");
+    let original_bytes = PSUWriter::new(original).to_bytes().expect("write psu");
+    fs::write(&original_path, &original_bytes).expect("save original");
+
+    let unchanged = workspace.path().join("unchanged.psu");
+    let changes =
+        update_psu_in_place(&project, &config, &original_path, &unchanged).expect("update");
+    assert!(changes.is_empty());
+    assert_eq!(fs::read(&unchanged).expect("read update"), original_bytes);
+
+    fs::write(project.join("BOOT.ELF"), b"BOOT").expect("rewrite BOOT.ELF");
+    fs::remove_file(project.join("DATA.BIN")).expect("remove DATA.BIN");
+    fs::write(project.join("NEW.BIN"), b"new").expect("write NEW.BIN");
+
+    let updated = workspace.path().join("updated.psu");
+    let changes = update_psu_in_place(&project, &config, &original_path, &updated).expect("update");
+    assert_eq!(
+        changes,
+        vec![
+            change("BOOT.ELF", ChangeKind::Modified),
+            change("NEW.BIN", ChangeKind::Added),
+            change("DATA.BIN", ChangeKind::Removed),
+        ]
+    );
+
+    let archive = PSU::new(fs::read(&updated).expect("read update"));
+    let names = archive
+        .entries
+        .iter()
+        .map(|entry| entry.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["APP_DIFF", ".", "..", "OLD.BIN", "BOOT.ELF", "NEW.BIN"]
+    );
+    assert_eq!(archive.entries[0].size, 5);
+    assert_eq!(archive.entries[4].sector, 7);
+    assert_eq!(archive.entries[4].contents.as_deref(), Some(&b"BOOT"[..]));
+}
+
+#[test]
+fn pending_changes_and_update_in_place_include_merged_folders() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    let secondary = workspace.path().join("secondary");
+    fs::create_dir(&secondary).expect("create secondary dir");
+    fs::write(secondary.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+    let secondaries = [secondary.clone()];
+
+    let timestamp = NaiveDate::from_ymd_opt(2024, 10, 10)
+        .and_then(|date| date.and_hms_opt(10, 30, 0))
+        .expect("timestamp");
+    let config = Config {
+        name: "APP_MERGED".to_string(),
+        timestamp: Some(timestamp),
+        ..Default::default()
+    };
+    let packed = workspace.path().join("packed.psu");
+    pack_merged(
+        &project,
+        &secondaries,
+        &packed,
+        config.clone(),
+        MergeConflictPolicy::default(),
+    )
+    .expect("pack merged");
+
+    fs::write(secondary.join("DATA.BIN"), b"DATA").expect("rewrite DATA.BIN");
+    assert_eq!(
+        pending_merged_changes(
+            &project,
+            &secondaries,
+            &config,
+            MergeConflictPolicy::default(),
+            &packed,
+        )
+        .expect("compare"),
+        vec![change("DATA.BIN", ChangeKind::Modified)]
+    );
+    assert_eq!(
+        pending_changes(&project, &config, &packed).expect("compare"),
+        vec![change("DATA.BIN", ChangeKind::Removed)]
+    );
+
+    let updated = workspace.path().join("updated.psu");
+    let changes = update_merged_psu_in_place(
+        &project,
+        &secondaries,
+        &config,
+        MergeConflictPolicy::default(),
+        &packed,
+        &updated,
+    )
+    .expect("update");
+
+    assert_eq!(changes, vec![change("DATA.BIN", ChangeKind::Modified)]);
+    let archive = PSU::new(fs::read(&updated).expect("read update"));
+    assert_eq!(archive.entry_contents("DATA.BIN"), Some(&b"DATA"[..]));
+}
+
+#[test]
+fn update_in_place_validates_the_root_directory_name() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    let mut config = Config {
+        name: "APP_ROOT".to_string(),
+        ..Default::default()
+    };
+    let packed = workspace.path().join("packed.psu");
+    pack_with_config(&project, &packed, config.clone()).expect("pack");
+
+    config.root_dir_name = Some("..".to_string());
+    let updated = workspace.path().join("updated.psu");
+    assert!(matches!(
+        update_psu_in_place(&project, &config, &packed, &updated),
+        Err(Error::NameError)
+    ));
+    assert!(!updated.exists());
+}
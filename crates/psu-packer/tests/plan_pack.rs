@@ -0,0 +1,68 @@
+use std::fs;
+
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{pack_with_config, plan_pack, Config, IconSysConfig, IconSysFlags};
+use tempfile::tempdir;
+
+fn build_config() -> Config {
+    Config {
+        name: "APP_PLAN".to_string(),
+        timestamp: None,
+        include: None,
+        exclude: Some(vec!["*.tmp".to_string()]),
+        icon_sys: Some(IconSysConfig {
+            flags: IconSysFlags::new(0),
+            title: "Plan".to_string(),
+            linebreak_pos: None,
+            preset: None,
+            background_transparency: None,
+            background_colors: None,
+            light_directions: None,
+            light_colors: None,
+            ambient_color: None,
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn planned_entries_match_packed_archive() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::create_dir(project.join("SUBDIR")).expect("create subdir");
+
+    fs::write(project.join("b.dat"), b"second").expect("write b.dat");
+    fs::write(project.join("A.DAT"), b"first").expect("write A.DAT");
+    fs::write(project.join("list.icn"), vec![7u8; 2048]).expect("write list.icn");
+    fs::write(project.join("scratch.tmp"), b"skip").expect("write scratch.tmp");
+    fs::write(project.join("psu.toml"), b"[config]\nname = \"APP_PLAN\"\n").expect("write toml");
+
+    let planned = plan_pack(&project, &build_config()).expect("plan succeeds");
+    assert!(
+        !project.join("icon.sys").exists(),
+        "planning must not write icon.sys"
+    );
+
+    let output = workspace.path().join("plan.psu");
+    pack_with_config(&project, &output, build_config()).expect("pack succeeds");
+    let archive = PSU::new(fs::read(&output).expect("read psu"));
+
+    let planned_layout: Vec<(String, u32, bool)> = planned
+        .iter()
+        .map(|entry| {
+            let is_file = matches!(entry.kind, PSUEntryKind::File);
+            (entry.name.clone(), entry.size, is_file)
+        })
+        .collect();
+    let packed_layout: Vec<(String, u32, bool)> = archive
+        .entries
+        .iter()
+        .map(|entry| {
+            let is_file = matches!(entry.kind, PSUEntryKind::File);
+            (entry.name.clone(), entry.size, is_file)
+        })
+        .collect();
+
+    assert_eq!(planned_layout, packed_layout);
+}
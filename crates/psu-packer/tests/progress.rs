@@ -0,0 +1,41 @@
+use std::fs;
+
+use psu_packer::{pack_with_config_with_progress, Config, PackProgressEvent};
+use tempfile::tempdir;
+
+#[test]
+fn progress_callback_fires_once_per_packed_file() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+
+    fs::write(project.join("A.DAT"), b"first").expect("write A.DAT");
+    fs::write(project.join("B.DAT"), b"second").expect("write B.DAT");
+    fs::write(project.join("C.DAT"), b"third").expect("write C.DAT");
+    fs::write(project.join("psu.toml"), b"[config]\nname = \"Test\"\n").expect("write psu.toml");
+
+    let config = Config {
+        name: "Progress Save".to_string(),
+        timestamp: None,
+        include: None,
+        exclude: None,
+        icon_sys: None,
+        ..Default::default()
+    };
+
+    let output = workspace.path().join("progress.psu");
+    let mut events: Vec<PackProgressEvent> = Vec::new();
+    pack_with_config_with_progress(&project, &output, config, &mut |event| events.push(event))
+        .expect("pack succeeds");
+
+    let names: Vec<&str> = events.iter().map(|event| event.name.as_str()).collect();
+    assert_eq!(names, vec!["A.DAT", "B.DAT", "C.DAT"]);
+
+    for (position, event) in events.iter().enumerate() {
+        assert_eq!(event.index, position);
+        assert_eq!(event.total, 3);
+    }
+
+    let last = events.last().expect("at least one event");
+    assert!((last.fraction() - 1.0).abs() < f32::EPSILON);
+}
@@ -0,0 +1,63 @@
+use std::fs;
+
+use chrono::NaiveDate;
+use ps2_filetypes::PSUEntryKind;
+use psu_packer::{pack_with_config, Config, PsuEntryInfo, PsuInfo};
+use tempfile::tempdir;
+
+#[test]
+fn csv_lists_packed_entries_in_archive_order() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), vec![0u8; 1500]).expect("write BOOT.ELF");
+
+    let timestamp = NaiveDate::from_ymd_opt(2024, 10, 10)
+        .and_then(|date| date.and_hms_opt(10, 30, 0))
+        .expect("timestamp");
+    let config = || Config {
+        name: "APP_CSV".to_string(),
+        timestamp: Some(timestamp),
+        ..Default::default()
+    };
+    let output = workspace.path().join("APP_CSV.psu");
+    pack_with_config(&project, &output, config()).expect("pack");
+
+    let packed = PsuInfo::from_psu_bytes(&fs::read(&output).expect("read psu")).expect("inspect");
+    let csv = packed.to_csv();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            "name,kind,size,created,modified",
+            "APP_CSV,directory,3,2024-10-10 10:30:00,2024-10-10 10:30:00",
+            ".,directory,0,2024-10-10 10:30:00,2024-10-10 10:30:00",
+            "..,directory,0,2024-10-10 10:30:00,2024-10-10 10:30:00",
+            "BOOT.ELF,file,1500,2024-10-10 10:30:00,2024-10-10 10:30:00",
+        ]
+    );
+
+    let planned = PsuInfo::from_project(&project, &config()).expect("plan");
+    assert_eq!(planned.to_csv(), csv);
+}
+
+#[test]
+fn csv_quotes_names_and_keeps_utf8() {
+    let timestamp = NaiveDate::from_ymd_opt(2024, 1, 2)
+        .and_then(|date| date.and_hms_opt(3, 4, 5))
+        .expect("timestamp");
+    let info = PsuInfo {
+        entries: vec![PsuEntryInfo {
+            name: "セーブ,\"1\"".to_string(),
+            kind: PSUEntryKind::File,
+            size: 4,
+            created: timestamp,
+            modified: timestamp,
+        }],
+    };
+
+    assert_eq!(
+        info.to_csv().lines().nth(1),
+        Some("\"セーブ,\"\"1\"\"\",file,4,2024-01-02 03:04:05,2024-01-02 03:04:05")
+    );
+}
@@ -0,0 +1,62 @@
+use std::fs;
+
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{pack_with_config, rename_root, Config, Error};
+use tempfile::tempdir;
+
+fn packed_sample() -> Vec<u8> {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("DATA.BIN"), b"payload").expect("write DATA.BIN");
+
+    let output = workspace.path().join("sample.psu");
+    let config = Config {
+        name: "APP_Tpyo".to_string(),
+        timestamp: None,
+        include: None,
+        exclude: None,
+        icon_sys: None,
+        ..Default::default()
+    };
+    pack_with_config(&project, &output, config).expect("pack succeeds");
+    fs::read(output).expect("read packed psu")
+}
+
+#[test]
+fn rename_root_changes_only_the_root_name() {
+    let original = packed_sample();
+    let renamed = rename_root(&original, "APP_Typo").expect("rename succeeds");
+
+    assert_eq!(original.len(), renamed.len());
+
+    let before = PSU::new(original);
+    let after = PSU::new(renamed);
+    assert_eq!(after.entries[0].name, "APP_Typo");
+    assert_eq!(before.entries.len(), after.entries.len());
+
+    for (old, new) in before.entries.iter().zip(&after.entries).skip(1) {
+        assert_eq!(old.name, new.name);
+        assert_eq!(old.size, new.size);
+        if matches!(old.kind, PSUEntryKind::File) {
+            assert_eq!(old.contents, new.contents);
+        }
+    }
+}
+
+#[test]
+fn rename_root_rejects_invalid_names() {
+    let original = packed_sample();
+    assert!(matches!(
+        rename_root(&original, "APP_Bad/Name"),
+        Err(Error::NameError)
+    ));
+}
+
+#[test]
+fn rename_root_reports_malformed_archives() {
+    assert!(matches!(
+        rename_root(&[0u8; 100], "APP_Name"),
+        Err(Error::IOError(_))
+    ));
+}
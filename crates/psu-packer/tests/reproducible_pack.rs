@@ -0,0 +1,71 @@
+use std::{
+    fs,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use ps2_filetypes::PSU;
+use psu_packer::{
+    pack_with_config_and_metadata_reader, Config, FixedTimeMetadataReader, IconSysConfig,
+    IconSysFlags,
+};
+use tempfile::tempdir;
+
+#[test]
+fn fixed_reader_packs_identical_bytes() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), vec![1u8; 1500]).expect("write BOOT.ELF");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+
+    let reader = FixedTimeMetadataReader::at(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    let config = || Config {
+        name: "APP_REPRO".to_string(),
+        ..Default::default()
+    };
+
+    let first = workspace.path().join("first.psu");
+    pack_with_config_and_metadata_reader(&project, &first, config(), &reader).expect("pack");
+
+    // Touch a file so its real modification time changes between the two packs.
+    fs::write(project.join("DATA.BIN"), b"data").expect("rewrite DATA.BIN");
+    let second = workspace.path().join("second.psu");
+    pack_with_config_and_metadata_reader(&project, &second, config(), &reader).expect("pack");
+
+    assert_eq!(
+        fs::read(&first).expect("read first"),
+        fs::read(&second).expect("read second")
+    );
+}
+
+#[test]
+fn fixed_reader_stamps_the_generated_icon_sys() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write DATA.BIN");
+
+    let reader = FixedTimeMetadataReader::at(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    let config = || Config {
+        name: "APP_REPRO".to_string(),
+        icon_sys: Some(IconSysConfig::new(IconSysFlags::new(0), "Repro", 5)),
+        ..Default::default()
+    };
+
+    let first = workspace.path().join("first.psu");
+    pack_with_config_and_metadata_reader(&project, &first, config(), &reader).expect("pack");
+    let second = workspace.path().join("second.psu");
+    pack_with_config_and_metadata_reader(&project, &second, config(), &reader).expect("pack");
+
+    let first = fs::read(&first).expect("read first");
+    assert_eq!(first, fs::read(&second).expect("read second"));
+    let psu = PSU::new(first);
+    let data = psu.entries.iter().find(|entry| entry.name == "DATA.BIN");
+    let icon_sys = psu.entries.iter().find(|entry| entry.name == "icon.sys");
+    let (data, icon_sys) = (
+        data.expect("DATA.BIN packed"),
+        icon_sys.expect("icon.sys packed"),
+    );
+    assert_eq!(icon_sys.modified, data.modified);
+    assert_eq!(icon_sys.created, data.created);
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::fs;
+
+use common::project;
+use ps2_filetypes::{PSUEntryKind, PSU};
+use psu_packer::{load_config, pack_with_config, Config, Error};
+use tempfile::tempdir;
+
+fn project_with_data(workspace: &std::path::Path) -> std::path::PathBuf {
+    project(workspace, &[("DATA.BIN", b"payload")])
+}
+
+#[test]
+fn root_dir_name_overrides_the_root_entry() {
+    let workspace = tempdir().expect("temp dir");
+    let project = project_with_data(workspace.path());
+    let output = workspace.path().join("APP_Export.psu");
+
+    let config = Config {
+        name: "APP_Export".to_string(),
+        root_dir_name: Some("BESLES-12345".to_string()),
+        ..Default::default()
+    };
+    pack_with_config(&project, &output, config).expect("pack succeeds");
+
+    let psu = PSU::new(fs::read(&output).expect("read packed psu"));
+    assert!(matches!(psu.entries[0].kind, PSUEntryKind::Directory));
+    assert_eq!(psu.entries[0].name, "BESLES-12345");
+    assert!(psu.entries.iter().all(|entry| entry.name != "APP_Export"));
+}
+
+#[test]
+fn root_dir_name_is_validated() {
+    let workspace = tempdir().expect("temp dir");
+    let project = project_with_data(workspace.path());
+
+    let config = Config {
+        name: "APP_Export".to_string(),
+        root_dir_name: Some("BAD/NAME".to_string()),
+        ..Default::default()
+    };
+    assert!(matches!(
+        pack_with_config(&project, &workspace.path().join("out.psu"), config),
+        Err(Error::NameError)
+    ));
+}
+
+#[test]
+fn root_dir_name_round_trips_through_psu_toml() {
+    let workspace = tempdir().expect("temp dir");
+    let project = project_with_data(workspace.path());
+
+    let config = Config {
+        name: "APP_Export".to_string(),
+        root_dir_name: Some("BESLES-12345".to_string()),
+        ..Default::default()
+    };
+    fs::write(
+        project.join("psu.toml"),
+        config.to_toml_string().expect("serialize config"),
+    )
+    .expect("write psu.toml");
+
+    let loaded = load_config(&project).expect("load psu.toml");
+    assert_eq!(loaded.name, "APP_Export");
+    assert_eq!(loaded.root_dir_name.as_deref(), Some("BESLES-12345"));
+}
+
+#[test]
+fn invalid_root_dir_names_are_rejected_on_load_and_build() {
+    for root_dir_name in ["..", ".", "SAVE/DATA", "SAVE\\DATA"] {
+        let workspace = tempdir().expect("temp dir");
+        let config = Config {
+            name: "APP_Export".to_string(),
+            root_dir_name: Some(root_dir_name.to_string()),
+            ..Default::default()
+        };
+        assert!(
+            matches!(config.validate_names(), Err(Error::NameError)),
+            "{root_dir_name}"
+        );
+
+        fs::write(
+            workspace.path().join("psu.toml"),
+            config.to_toml_string().expect("serialize config"),
+        )
+        .expect("write psu.toml");
+        let err = load_config(workspace.path()).expect_err(root_dir_name);
+        assert!(
+            matches!(&err, Error::ConfigError(message) if message.contains("root_dir_name")),
+            "{root_dir_name}: {err}"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::fs;
+
+use ps2_filetypes::PSU;
+use psu_packer::{load_config, pack_with_config, psu_root_dir_size, Config};
+use tempfile::tempdir;
+
+fn packed_root_size(root_size_excludes_dot_entries: bool) -> u32 {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir_all(&project).expect("create project");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write file");
+    fs::write(project.join("DATA.BIN"), b"data").expect("write file");
+
+    let output = workspace.path().join("out.psu");
+    let config = Config {
+        name: "APP_SIZE".to_string(),
+        root_size_excludes_dot_entries,
+        ..Default::default()
+    };
+    pack_with_config(&project, &output, config).expect("pack");
+
+    let psu = PSU::new(fs::read(&output).expect("read psu"));
+    psu.entries()[0].size
+}
+
+#[test]
+fn root_dir_size_counts_dot_entries_by_default() {
+    assert_eq!(psu_root_dir_size(2, false), 4);
+    assert_eq!(packed_root_size(false), 4);
+}
+
+#[test]
+fn root_dir_size_can_use_plain_file_count() {
+    assert_eq!(psu_root_dir_size(2, true), 2);
+    assert_eq!(packed_root_size(true), 2);
+}
+
+#[test]
+fn root_dir_size_flag_round_trips_through_psu_toml() {
+    let workspace = tempdir().expect("temp dir");
+    fs::write(
+        workspace.path().join("psu.toml"),
+        "[config]\nname = \"APP_SIZE\"\nroot_size_excludes_dot_entries = true\n",
+    )
+    .expect("write psu.toml");
+
+    let config = load_config(workspace.path()).expect("load config");
+    assert!(config.root_size_excludes_dot_entries);
+    assert!(config
+        .to_toml_string()
+        .expect("serialize")
+        .contains("root_size_excludes_dot_entries = true"));
+
+    let default = Config::default().to_toml_string().expect("serialize");
+    assert!(!default.contains("root_size_excludes_dot_entries"));
+}
@@ -0,0 +1,59 @@
+use std::fs;
+
+use psu_packer::{load_config, save_config, Config};
+use tempfile::tempdir;
+
+#[test]
+fn save_config_writes_loadable_psu_toml() {
+    let workspace = tempdir().expect("temp dir");
+    let config = Config {
+        name: "APP_SAVED".to_string(),
+        include: Some(vec!["BOOT.ELF".to_string()]),
+        ..Default::default()
+    };
+
+    let path = save_config(workspace.path(), &config).expect("save psu.toml");
+    assert_eq!(path, workspace.path().join("psu.toml"));
+
+    let reloaded = load_config(workspace.path()).expect("reload psu.toml");
+    assert_eq!(reloaded.name, "APP_SAVED");
+    assert_eq!(reloaded.include, Some(vec!["BOOT.ELF".to_string()]));
+}
+
+#[test]
+fn verify_after_pack_round_trips_through_psu_toml() {
+    let workspace = tempdir().expect("temp dir");
+    let config = Config {
+        name: "APP_VERIFY".to_string(),
+        verify_after_pack: true,
+        ..Default::default()
+    };
+
+    save_config(workspace.path(), &config).expect("save psu.toml");
+
+    let reloaded = load_config(workspace.path()).expect("reload psu.toml");
+    assert!(reloaded.verify_after_pack);
+}
+
+#[test]
+fn save_config_preserves_unknown_keys() {
+    let workspace = tempdir().expect("temp dir");
+    fs::write(
+        workspace.path().join("psu.toml"),
+        "notes = \"keep me\"\n\n[config]\nname = \"Old\"\nauthor = \"me\"\n\n[build]\nscript = \"make\"\n",
+    )
+    .expect("write psu.toml");
+
+    let config = Config {
+        name: "APP_NEW".to_string(),
+        ..Default::default()
+    };
+    save_config(workspace.path(), &config).expect("save psu.toml");
+
+    let written = fs::read_to_string(workspace.path().join("psu.toml")).expect("read psu.toml");
+    let table: toml::Table = toml::from_str(&written).expect("valid toml");
+    assert_eq!(table["notes"].as_str(), Some("keep me"));
+    assert_eq!(table["build"]["script"].as_str(), Some("make"));
+    assert_eq!(table["config"]["author"].as_str(), Some("me"));
+    assert_eq!(table["config"]["name"].as_str(), Some("APP_NEW"));
+}
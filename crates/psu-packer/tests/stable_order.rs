@@ -59,6 +59,7 @@ fn packing_same_directory_twice_is_stable() {
         include: None,
         exclude: None,
         icon_sys: Some(build_icon_config()),
+        ..Default::default()
     };
     pack_with_config(project, &output_first, config_first).expect("first pack succeeds");
 
@@ -69,6 +70,7 @@ fn packing_same_directory_twice_is_stable() {
         include: None,
         exclude: None,
         icon_sys: Some(build_icon_config()),
+        ..Default::default()
     };
     pack_with_config(project, &output_second, config_second).expect("second pack succeeds");
 
@@ -95,3 +97,47 @@ fn packing_same_directory_twice_is_stable() {
         "packing should produce identical archives"
     );
 }
+
+#[test]
+fn preserve_include_order_packs_files_in_listed_order() {
+    let tempdir = tempdir().expect("temp dir");
+    let project = tempdir.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    for name in ["BOOT.ELF", "A.DAT", "C.DAT", "Z.DAT"] {
+        fs::write(project.join(name), name).expect("write file");
+    }
+    let include = vec![
+        "Z.DAT".to_string(),
+        "BOOT.ELF".to_string(),
+        "?.DAT".to_string(),
+    ];
+
+    let output = tempdir.path().join("ordered.psu");
+    let config = Config {
+        name: "APP_ORDER".to_string(),
+        include: Some(include.clone()),
+        icon_sys: Some(build_icon_config()),
+        preserve_include_order: true,
+        ..Default::default()
+    };
+    pack_with_config(&project, &output, config).expect("pack ordered");
+    let archive = PSU::new(fs::read(&output).expect("read psu"));
+    assert_eq!(
+        file_entry_names(&archive),
+        vec!["Z.DAT", "BOOT.ELF", "A.DAT", "C.DAT", "icon.sys"],
+        "listed order is kept, wildcard matches are sorted, icon.sys goes last"
+    );
+
+    let sorted_output = tempdir.path().join("sorted.psu");
+    let config = Config {
+        name: "APP_ORDER".to_string(),
+        include: Some(include),
+        ..Default::default()
+    };
+    pack_with_config(&project, &sorted_output, config).expect("pack sorted");
+    let archive = PSU::new(fs::read(&sorted_output).expect("read psu"));
+    assert_eq!(
+        file_entry_names(&archive),
+        vec!["A.DAT", "BOOT.ELF", "C.DAT", "Z.DAT"]
+    );
+}
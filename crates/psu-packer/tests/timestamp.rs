@@ -4,7 +4,8 @@ use std::path::Path;
 use chrono::{NaiveDate, NaiveDateTime};
 use ps2_filetypes::{PSUEntryKind, PSU};
 use psu_packer::{
-    pack_with_config, pack_with_config_and_metadata_reader, Config, FileTimes, MetadataReader,
+    load_config, load_config_with_warnings, pack_with_config, pack_with_config_and_metadata_reader,
+    Config, ConfigWarning, EntryTimestamps, FileTimes, MetadataReader,
 };
 use tempfile::tempdir;
 
@@ -45,6 +46,7 @@ fn pack_with_or_without_timestamp_controls_entry_times() {
         include: None,
         exclude: None,
         icon_sys: None,
+        ..Default::default()
     };
     let output_with_timestamp = output_dir.join("with-timestamp.psu");
     pack_with_config(folder, &output_with_timestamp, config_with_timestamp)
@@ -70,6 +72,7 @@ fn pack_with_or_without_timestamp_controls_entry_times() {
         include: None,
         exclude: None,
         icon_sys: None,
+        ..Default::default()
     };
     pack_with_config(folder, &output_without_timestamp, legacy_config)
         .expect("pack without timestamp");
@@ -108,6 +111,7 @@ fn pack_without_birth_time_support_falls_back_to_modified_time() {
         include: None,
         exclude: None,
         icon_sys: None,
+        ..Default::default()
     };
 
     let metadata_reader = UnsupportedCreatedMetadata::default();
@@ -126,3 +130,164 @@ fn pack_without_birth_time_support_falls_back_to_modified_time() {
     assert_eq!(file_entry.created, file_entry.modified);
     assert_ne!(file_entry.created, NaiveDateTime::default());
 }
+
+fn at(hour: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 1, 2)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+#[test]
+fn per_file_overrides_set_created_and_modified_independently() {
+    let tempdir = tempdir().expect("temp dir");
+    let project = tempdir.path().join("project");
+    fs::create_dir(&project).expect("create project");
+    create_sample_file(&project.join("BOOT.ELF"));
+    create_sample_file(&project.join("DATA.BIN"));
+    create_sample_file(&project.join("ICON.ICN"));
+
+    let mut config = Config {
+        name: "Test Save".to_string(),
+        timestamp: Some(at(1)),
+        ..Default::default()
+    };
+    config.file_timestamps.insert(
+        "BOOT.ELF".to_string(),
+        EntryTimestamps {
+            created: Some(at(2)),
+            modified: Some(at(3)),
+        },
+    );
+    config.file_timestamps.insert(
+        "DATA.BIN".to_string(),
+        EntryTimestamps {
+            created: None,
+            modified: Some(at(4)),
+        },
+    );
+    let output = tempdir.path().join("out.psu");
+    pack_with_config(&project, &output, config).expect("pack");
+
+    let packed = PSU::new(fs::read(&output).expect("read output"));
+    let times = |name: &str| {
+        packed
+            .entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| (entry.created, entry.modified))
+            .expect("packed entry")
+    };
+    assert_eq!(times("BOOT.ELF"), (at(2), at(3)));
+    assert_eq!(times("DATA.BIN"), (at(1), at(4)));
+    assert_eq!(times("ICON.ICN"), (at(1), at(1)));
+}
+
+#[test]
+fn per_file_overrides_round_trip_through_psu_toml() {
+    let tempdir = tempdir().expect("temp dir");
+    fs::write(
+        tempdir.path().join("psu.toml"),
+        r#"[config]
+name = "Test Save"
+
+[timestamps."BOOT.ELF"]
+created = "2024-01-02 02:00:00"
+modified = "2024-01-02 03:00:00"
+"#,
+    )
+    .expect("write psu.toml");
+
+    let config = load_config(tempdir.path()).expect("load config");
+    let expected = EntryTimestamps {
+        created: Some(at(2)),
+        modified: Some(at(3)),
+    };
+    assert_eq!(config.file_timestamps.get("BOOT.ELF"), Some(&expected));
+
+    let serialized = config.to_toml_string().expect("serialize");
+    assert!(
+        serialized.contains("[timestamps.\"BOOT.ELF\"]"),
+        "{serialized}"
+    );
+    fs::write(tempdir.path().join("psu.toml"), serialized).expect("rewrite psu.toml");
+    let reloaded = load_config(tempdir.path()).expect("reload config");
+    assert_eq!(reloaded.file_timestamps, config.file_timestamps);
+}
+
+#[test]
+fn plain_timestamp_entry_overrides_one_file() {
+    let tempdir = tempdir().expect("temp dir");
+    let project = tempdir.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    create_sample_file(&project.join("BOOT.ELF"));
+    create_sample_file(&project.join("DATA.BIN"));
+    fs::write(
+        project.join("psu.toml"),
+        r#"[config]
+name = "Test Save"
+timestamp = "2024-01-02 01:00:00"
+exclude = ["psu.toml"]
+
+[timestamps]
+"BOOT.ELF" = "2024-01-02 05:00:00"
+"MISSING.BIN" = "2024-01-02 06:00:00"
+"#,
+    )
+    .expect("write psu.toml");
+
+    let (config, warnings) = load_config_with_warnings(&project).expect("load config");
+    assert_eq!(
+        warnings,
+        vec![ConfigWarning::UnpackedTimestamp {
+            name: "MISSING.BIN".to_string()
+        }]
+    );
+
+    let output = tempdir.path().join("out.psu");
+    pack_with_config(&project, &output, config).expect("pack");
+    let packed = PSU::new(fs::read(&output).expect("read psu"));
+    let times = |name: &str| {
+        packed
+            .entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| (entry.created, entry.modified))
+            .expect("packed entry")
+    };
+    assert_eq!(times("BOOT.ELF"), (at(5), at(5)));
+    assert_eq!(times("DATA.BIN"), (at(1), at(1)));
+}
+
+#[test]
+fn explicit_no_timestamp_round_trips_through_psu_toml() {
+    let workspace = tempdir().expect("temp dir");
+    let config = Config {
+        name: "APP_SAVE".to_string(),
+        no_timestamp: true,
+        ..Default::default()
+    };
+    let serialized = config.to_toml_string().expect("serialize config");
+    assert!(serialized.contains("timestamp = false"), "{serialized}");
+
+    fs::write(workspace.path().join("psu.toml"), serialized).expect("write psu.toml");
+    let loaded = load_config(workspace.path()).expect("load psu.toml");
+    assert!(loaded.no_timestamp);
+    assert_eq!(loaded.timestamp, None);
+
+    fs::write(
+        workspace.path().join("psu.toml"),
+        "[config]\nname = \"APP_SAVE\"\ntimestamp = \"none\"\n",
+    )
+    .expect("write psu.toml");
+    assert!(load_config(workspace.path()).expect("load").no_timestamp);
+
+    fs::write(
+        workspace.path().join("psu.toml"),
+        "[config]\nname = \"APP_SAVE\"\n",
+    )
+    .expect("write psu.toml");
+    let unset = load_config(workspace.path()).expect("load psu.toml");
+    assert!(!unset.no_timestamp);
+    assert_eq!(unset.timestamp, None);
+}
@@ -0,0 +1,139 @@
+use std::fs;
+
+use ps2_filetypes::{PSUWriter, PSU};
+use psu_packer::{extract_metadata_only, pack_with_config, unpack_psu, Config, Error};
+use tempfile::tempdir;
+
+#[test]
+fn pack_then_unpack_round_trips_files() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+
+    let files: [(&str, &[u8]); 3] = [
+        ("BOOT.ELF", b"boot"),
+        ("DATA.BIN", &[0u8, 1, 2, 3, 255]),
+        ("title.cfg", b"title=Round Trip\n"),
+    ];
+    for (name, contents) in files {
+        fs::write(project.join(name), contents).expect("write project file");
+    }
+    fs::write(
+        project.join("psu.toml"),
+        b"[config]\nname = \"APP_ROUND\"\n",
+    )
+    .expect("write toml");
+
+    let psu_path = workspace.path().join("round.psu");
+    let config = Config {
+        name: "APP_ROUND".to_string(),
+        timestamp: None,
+        include: None,
+        exclude: None,
+        icon_sys: None,
+        ..Default::default()
+    };
+    pack_with_config(&project, &psu_path, config).expect("pack succeeds");
+
+    let destination = workspace.path().join("unpacked");
+    fs::create_dir(&destination).expect("create destination");
+    let bytes = fs::read(&psu_path).expect("read psu");
+    let root = unpack_psu(&bytes, &destination).expect("unpack succeeds");
+
+    assert_eq!(root, destination.join("APP_ROUND"));
+    for (name, contents) in files {
+        assert_eq!(
+            fs::read(root.join(name)).expect("read unpacked file"),
+            contents
+        );
+    }
+    assert!(!root.join("psu.toml").exists());
+    assert_eq!(fs::read_dir(&root).expect("list root").count(), files.len());
+}
+
+#[test]
+fn unpack_rejects_truncated_archives() {
+    let workspace = tempdir().expect("temp dir");
+    assert!(unpack_psu(&[0u8; 64], workspace.path()).is_err());
+}
+
+#[test]
+fn unpack_rejects_a_parent_directory_root_name() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+
+    let psu_path = workspace.path().join("escape.psu");
+    let config = Config {
+        name: "APP_ESCAPE".to_string(),
+        ..Default::default()
+    };
+    pack_with_config(&project, &psu_path, config).expect("pack succeeds");
+
+    let mut psu = PSU::new(fs::read(&psu_path).expect("read psu"));
+    psu.entries[0].name = "..".to_string();
+    let bytes = PSUWriter::new(psu).to_bytes().expect("write psu");
+
+    let destination = workspace.path().join("nested").join("unpacked");
+    fs::create_dir_all(&destination).expect("create destination");
+    let result = unpack_psu(&bytes, &destination);
+
+    assert!(matches!(result, Err(Error::NameError)));
+    assert!(!workspace.path().join("nested").join("BOOT.ELF").exists());
+}
+
+#[test]
+fn extract_metadata_only_writes_title_cfg_and_icon_sys() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    fs::write(project.join("title.cfg"), b"title=Metadata\n").expect("write title.cfg");
+    fs::write(project.join("icon.sys"), b"PS2D-icon").expect("write icon.sys");
+
+    let psu_path = workspace.path().join("meta.psu");
+    let config = Config {
+        name: "APP_META".to_string(),
+        ..Default::default()
+    };
+    pack_with_config(&project, &psu_path, config).expect("pack succeeds");
+
+    let destination = workspace.path().join("metadata");
+    let bytes = fs::read(&psu_path).expect("read psu");
+    extract_metadata_only(&bytes, &destination).expect("extract metadata");
+
+    assert_eq!(
+        fs::read(destination.join("title.cfg")).expect("read title.cfg"),
+        b"title=Metadata\n"
+    );
+    assert_eq!(
+        fs::read(destination.join("icon.sys")).expect("read icon.sys"),
+        b"PS2D-icon"
+    );
+    assert_eq!(fs::read_dir(&destination).expect("list").count(), 2);
+}
+
+#[test]
+fn extract_metadata_only_skips_missing_files() {
+    let workspace = tempdir().expect("temp dir");
+    let project = workspace.path().join("project");
+    fs::create_dir(&project).expect("create project dir");
+    fs::write(project.join("BOOT.ELF"), b"boot").expect("write BOOT.ELF");
+    fs::write(project.join("title.cfg"), b"title=Only\n").expect("write title.cfg");
+
+    let psu_path = workspace.path().join("meta.psu");
+    let config = Config {
+        name: "APP_META".to_string(),
+        ..Default::default()
+    };
+    pack_with_config(&project, &psu_path, config).expect("pack succeeds");
+
+    let destination = workspace.path().join("metadata");
+    let bytes = fs::read(&psu_path).expect("read psu");
+    extract_metadata_only(&bytes, &destination).expect("extract metadata");
+
+    assert!(destination.join("title.cfg").is_file());
+    assert!(!destination.join("icon.sys").exists());
+    assert!(!destination.join("BOOT.ELF").exists());
+}
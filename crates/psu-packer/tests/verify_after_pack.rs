@@ -0,0 +1,63 @@
+mod common;
+
+use std::fs;
+
+use common::project;
+use psu_packer::{
+    list_psu_entries, pack_with_config, verify_psu_file, Config, Error, PsuEntryInfo,
+};
+use tempfile::tempdir;
+
+fn project_with_data(workspace: &std::path::Path) -> std::path::PathBuf {
+    project(
+        workspace,
+        &[("BOOT.ELF", &[1u8; 1500]), ("DATA.BIN", &[2u8; 3000])],
+    )
+}
+
+#[test]
+fn verified_pack_succeeds() {
+    let workspace = tempdir().expect("temp dir");
+    let project = project_with_data(workspace.path());
+    let output = workspace.path().join("APP_VERIFY.psu");
+    let config = Config {
+        name: "APP_VERIFY".to_string(),
+        verify_after_pack: true,
+        ..Default::default()
+    };
+
+    pack_with_config(&project, &output, config).expect("pack with verification");
+}
+
+#[test]
+fn short_write_is_reported() {
+    let workspace = tempdir().expect("temp dir");
+    let project = project_with_data(workspace.path());
+    let output = workspace.path().join("APP_VERIFY.psu");
+    let config = Config {
+        name: "APP_VERIFY".to_string(),
+        ..Default::default()
+    };
+    pack_with_config(&project, &output, config).expect("pack");
+
+    let bytes = fs::read(&output).expect("read psu");
+    let expected: Vec<PsuEntryInfo> = list_psu_entries(&bytes).expect("list entries");
+    verify_psu_file(&output, &expected).expect("untouched archive verifies");
+
+    // Drop the last file's data, as a full disk would.
+    fs::write(&output, &bytes[..bytes.len() - 2048]).expect("truncate psu");
+    let err = verify_psu_file(&output, &expected).expect_err("truncated archive");
+    let Error::ConfigError(message) = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert!(message.contains("Verification of"), "{message}");
+
+    // A header-only truncation parses cleanly but loses an entry.
+    let header_end = bytes.len() - 3072 - 512;
+    fs::write(&output, &bytes[..header_end]).expect("truncate psu");
+    let err = verify_psu_file(&output, &expected).expect_err("missing entry");
+    assert!(
+        err.to_string().contains("expected 5 entries but found 4"),
+        "{err}"
+    );
+}
@@ -60,6 +60,7 @@ fn config_from_state(state: &AppState, name: String) -> PsuConfig {
         include,
         exclude: None,
         icon_sys: None,
+        ..PsuConfig::default()
     }
 }
 
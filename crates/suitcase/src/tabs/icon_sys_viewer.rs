@@ -1,21 +1,25 @@
+use crate::components::dialogs::Dialogs;
 use crate::tabs::Tab;
 use crate::{AppState, VirtualFile};
 use eframe::egui;
-use eframe::egui::{CornerRadius, Grid, Id, PopupCloseBehavior, Response, Ui};
+use eframe::egui::{Color32, CornerRadius, Grid, Id, PopupCloseBehavior, Response, Ui};
 use icon_sys_ui::{
     background_editor, flag_selector, lighting_editor, preset_selector, title_editor,
     BackgroundSectionState, FlagSectionState, IconSysState, LightingSectionState,
     PresetPreviewData, PresetSectionState, PresetSelection, TitleSectionIds, TitleSectionState,
 };
 use ps2_filetypes::IconSys;
-use psu_packer::{shift_jis_byte_length, split_icon_sys_title};
+use psu_packer::{
+    shift_jis_byte_length, split_icon_sys_title, IconSysPresetFile, ICON_SYS_TITLE_CHAR_LIMIT,
+};
 use relative_path::PathExt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct IconSysViewer {
     title_line1: String,
     title_line2: String,
     file: String,
+    preset_error: Option<String>,
     pub icon_file: String,
     pub icon_copy_file: String,
     pub icon_delete_file: String,
@@ -50,6 +54,7 @@ impl IconSysViewer {
                 .relative_to(state.opened_folder.clone().unwrap())
                 .unwrap()
                 .to_string(),
+            preset_error: None,
         }
     }
 
@@ -90,6 +95,7 @@ impl IconSysViewer {
                     TitleSectionState {
                         line1: &mut self.title_line1,
                         line2: &mut self.title_line2,
+                        char_limit: ICON_SYS_TITLE_CHAR_LIMIT,
                     },
                 );
             });
@@ -123,6 +129,7 @@ impl IconSysViewer {
                     },
                     PresetPreviewData {
                         background_colors: &self.icon_state.background_colors,
+                        background_transparency: self.icon_state.background_transparency,
                         light_colors: &self.icon_state.light_colors,
                         ambient_color: &self.icon_state.ambient_color,
                     },
@@ -138,6 +145,48 @@ impl IconSysViewer {
                             self.icon_state.apply_preset(preset);
                             pending_selected = Some(self.icon_state.selected_preset.clone());
                         }
+                        PresetSelection::Import => {
+                            if let Some(path) = ui.ctx().open_file_filter(&["json"]) {
+                                match std::fs::read_to_string(&path)
+                                    .map_err(|err| err.to_string())
+                                    .and_then(|json| {
+                                        IconSysPresetFile::from_json(&json)
+                                            .map_err(|err| err.to_string())
+                                    }) {
+                                    Ok(preset) => {
+                                        self.preset_error = None;
+                                        self.icon_state.apply_preset_file(&preset);
+                                        pending_selected =
+                                            Some(self.icon_state.selected_preset.clone());
+                                    }
+                                    Err(err) => {
+                                        self.preset_error = Some(format!(
+                                            "Failed to import {}: {err}",
+                                            path.display()
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                        PresetSelection::Export => {
+                            let stem = Path::new(&self.file)
+                                .file_stem()
+                                .and_then(|stem| stem.to_str())
+                                .unwrap_or("icon");
+                            if let Some(path) = ui.ctx().save_as(format!("{stem}.json")) {
+                                let preset = self.icon_state.to_preset_file(&self.file);
+                                self.preset_error = preset
+                                    .to_json()
+                                    .map_err(|err| err.to_string())
+                                    .and_then(|json| {
+                                        std::fs::write(&path, json).map_err(|err| err.to_string())
+                                    })
+                                    .err()
+                                    .map(|err| {
+                                        format!("Failed to export {}: {err}", path.display())
+                                    });
+                            }
+                        }
                     }
                 }
 
@@ -146,6 +195,10 @@ impl IconSysViewer {
                 }
 
                 self.icon_state.selected_preset = selected_preset;
+
+                if let Some(error) = &self.preset_error {
+                    ui.colored_label(Color32::RED, error);
+                }
             });
 
             ui.add_space(8.0);